@@ -1,7 +1,7 @@
 import 'dart:async';
-import 'dart:typed_data';
 import 'dart:ui' as ui;
 import 'package:flutter/material.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge.dart';
 import 'package:flutter_rust_cam_test/effect_list.dart';
 import 'package:flutter_rust_cam_test/effets_model.dart';
 // import 'package:flutter_rust_cam_test/src/rust/api/simple.dart';
@@ -18,7 +18,7 @@ class CamArea extends StatefulWidget {
 class _CamAreaState extends State<CamArea> {
   List<DropdownMenuEntry<String>> _dropdownList = [];
   String? _selectedItem;
-  Stream<Uint8List>? _camStream;
+  Stream<ProcessedFrame>? _camStream;
 
   ui.Image? _currentImage;
   StreamSubscription? _streamSubscription; // Pour gérer la souscription
@@ -59,23 +59,26 @@ class _CamAreaState extends State<CamArea> {
     _startListening(stream);
   }
 
-  Future<ui.Image> _createImage(Uint8List rgba) async {
+  Future<ui.Image> _createImage(ProcessedFrame frame) async {
     final completer = Completer<ui.Image>();
     ui.decodeImageFromPixels(
-      rgba,
-      640,
-      480,
-      ui.PixelFormat.rgba8888,
+      frame.pixels,
+      frame.info.width,
+      frame.info.height,
+      frame.info.pixelFormat == PixelFormat.bgra8888
+          ? ui.PixelFormat.bgra8888
+          : ui.PixelFormat.rgba8888,
       completer.complete,
+      rowBytes: frame.info.stride,
     );
     return completer.future;
   }
 
-  void _startListening(Stream<Uint8List> stream) {
+  void _startListening(Stream<ProcessedFrame> stream) {
     _streamSubscription?.cancel();
 
-    _streamSubscription = stream.listen((Uint8List rgbaData) async {
-      final newImage = await _createImage(rgbaData);
+    _streamSubscription = stream.listen((ProcessedFrame frame) async {
+      final newImage = await _createImage(frame);
 
       // call setState only if the image has changed.
       if (newImage != _currentImage) {
@@ -98,15 +101,22 @@ class _CamAreaState extends State<CamArea> {
     });
   }
 
-  void _changeMask(EffectsModel effects, int e) {
+  Future<void> _changeMask(EffectsModel effects, int e) async {
     setState(() {
       _hasMask = effects.hasMask;
       selectedEffect = e;
     });
     if (effects.background == null) {
       setMask(mask: _hasMask);
-    } else {
-      setBackground(background: effects.background!);
+      return;
+    }
+    try {
+      await setBackground(background: effects.background!);
+    } on AnyhowException catch (error) {
+      if (!mounted) return;
+      ScaffoldMessenger.of(context).showSnackBar(
+        SnackBar(content: Text("Can't use this background: ${error.message}")),
+      );
     }
   }
 
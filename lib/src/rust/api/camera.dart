@@ -6,26 +6,240 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CameraState`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CameraState`, `Delivered`, `FrameDelivery`, `StreamGuard`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`, `from`
 
 Future<void> initCams() => RustLib.instance.api.crateApiCameraInitCams();
 
+/// Load the segmentation models and run a first inference on them,
+/// so starting a stream doesn't stall on the first frames.
+Future<void> preloadModels() =>
+    RustLib.instance.api.crateApiCameraPreloadModels();
+
+Future<MaskBenchmark> benchmarkMask({required int iterations}) =>
+    RustLib.instance.api.crateApiCameraBenchmarkMask(iterations: iterations);
+
+/// Number of threads used to composite frames, 0 for one per core.
+Future<void> setCompositingThreads({required int threads}) =>
+    RustLib.instance.api.crateApiCameraSetCompositingThreads(threads: threads);
+
 Future<List<Cameras>> checkForCameras() =>
     RustLib.instance.api.crateApiCameraCheckForCameras();
 
 Future<void> setMask({required bool mask}) =>
     RustLib.instance.api.crateApiCameraSetMask(mask: mask);
 
+/// Fails without changing the effects when the image can't be decoded.
 Future<void> setBackground({required List<int> background}) =>
     RustLib.instance.api.crateApiCameraSetBackground(background: background);
 
 Future<void> setDebug({required bool debug}) =>
     RustLib.instance.api.crateApiCameraSetDebug(debug: debug);
 
-Stream<Uint8List> streamCamera({required int id}) =>
+/// Run inference on a crop around the person instead of the whole frame.
+/// Gives a finer mask when the person is small in the frame.
+Future<void> setRoiMode({required bool enabled}) =>
+    RustLib.instance.api.crateApiCameraSetRoiMode(enabled: enabled);
+
+/// Smooth the mask edges, off by default. Costs a few milliseconds per frame at 640x480.
+Future<void> setMaskRefinement({required bool enabled}) =>
+    RustLib.instance.api.crateApiCameraSetMaskRefinement(enabled: enabled);
+
+/// Average the mask over the last frames, off by default.
+/// Steadies flickering edges, but the mask lags a little behind fast motion.
+Future<void> setTemporalSmoothing({required bool enabled}) =>
+    RustLib.instance.api.crateApiCameraSetTemporalSmoothing(enabled: enabled);
+
+/// Whether the crate was built with a lite model, see `set_adaptive_quality`.
+Future<bool> hasLiteModel() =>
+    RustLib.instance.api.crateApiCameraHasLiteModel();
+
+/// Format of the frames sent by the streams, encoded on the processing thread.
+/// `quality` goes from 1 to 100 and is only used by JPEG.
+Future<void> setOutputFormat({
+  required PixelFormat format,
+  required int quality,
+}) => RustLib.instance.api.crateApiCameraSetOutputFormat(
+  format: format,
+  quality: quality,
+);
+
+/// Lower the quality when frames take longer to process than `target_fps` allows,
+/// and raise it back once there is headroom. The level in use is in `FrameInfo`.
+///
+/// Levels go from 0, full quality, to 4:
+/// 1 stops smoothing the mask edges when `set_mask_refinement` turned it on,
+/// 2 runs the segmentation every other frame with an approximated blur,
+/// 3 switches to the lite model, when built with one, and a single pass blur,
+/// and 4 runs the segmentation every third frame and sends frames at half resolution.
+Future<void> setAdaptiveQuality({
+  required bool enabled,
+  required int targetFps,
+}) => RustLib.instance.api.crateApiCameraSetAdaptiveQuality(
+  enabled: enabled,
+  targetFps: targetFps,
+);
+
+/// Frames waiting between capture and processing, for streams started afterwards.
+/// By default only the latest frame is kept, so a slow processing drops frames
+/// instead of adding latency. With `drop_frames` off, capture waits for room in
+/// the queue instead, for consumers like recording that need every frame.
+Future<void> setFrameQueue({required int depth, required bool dropFrames}) =>
+    RustLib.instance.api.crateApiCameraSetFrameQueue(
+      depth: depth,
+      dropFrames: dropFrames,
+    );
+
+Future<void> setPowerMode({required PowerMode mode}) =>
+    RustLib.instance.api.crateApiCameraSetPowerMode(mode: mode);
+
+/// Process at most `fps` frames per second, 0 to follow the camera.
+/// Frames over the limit are skipped before being decoded.
+Future<void> setTargetFps({required int fps}) =>
+    RustLib.instance.api.crateApiCameraSetTargetFps(fps: fps);
+
+/// Frame buffers allocated by the stream of a camera, `None` before its first stream.
+/// Once running, `last_frame_allocations` should stay at 0. With `stream_camera` the
+/// pixels are still copied into the message sent to Dart, that copy belongs to the bridge.
+Future<FrameAllocationStats?> frameAllocationStats({required int id}) =>
+    RustLib.instance.api.crateApiCameraFrameAllocationStats(id: id);
+
+/// Take the next processed frame of the running stream on the camera, with the current effects.
+Future<Snapshot> takeSnapshot({
+  required int id,
+  required SnapshotOptions options,
+}) => RustLib.instance.api.crateApiCameraTakeSnapshot(id: id, options: options);
+
+/// Keep the last processed frames of the running stream on the camera, so bursts
+/// can start before they are requested. 0 stops keeping them, at most 30.
+/// Each kept frame is a full RGBA copy, and while frames are kept the mask is computed
+/// for every frame even when no effect needs it.
+Future<void> setBurstHistory({required int id, required int frames}) =>
+    RustLib.instance.api.crateApiCameraSetBurstHistory(id: id, frames: frames);
+
+/// Grab consecutive processed frames around now and rank them by sharpness,
+/// exposure and mask quality, best first.
+Future<List<BurstFrame>> captureBurst({
+  required int id,
+  required BurstOptions options,
+}) => RustLib.instance.api.crateApiCameraCaptureBurst(id: id, options: options);
+
+/// Give back a slot received from `stream_camera_shared` once it has been read.
+/// Frames are dropped while Dart holds every slot.
+Future<void> releaseFrameSlot({
+  required BigInt generation,
+  required int index,
+}) => RustLib.instance.api.crateApiCameraReleaseFrameSlot(
+  generation: generation,
+  index: index,
+);
+
+Stream<ProcessedFrame> streamCamera({required int id}) =>
     RustLib.instance.api.crateApiCameraStreamCamera(id: id);
 
+/// Same as `stream_camera`, but frames stay in a ring of `slot_count` shared buffers
+/// and only their slot goes through the bridge. Native platforms only.
+/// Slots stay valid until released, even once the stream stopped.
+Stream<FrameSlot> streamCameraShared({
+  required int id,
+  required int slotCount,
+}) => RustLib.instance.api.crateApiCameraStreamCameraShared(
+  id: id,
+  slotCount: slotCount,
+);
+
+/// A processed frame of a burst with its scores, all from 0 to 1.
+class BurstFrame {
+  final int width;
+  final int height;
+  final Uint8List image;
+  /// Microseconds since the Unix epoch.
+  final BigInt timestampUs;
+  /// Edge contrast relative to the sharpest frame of the burst, low for motion blur.
+  final double sharpness;
+  /// High for a balanced brightness without clipped highlights or shadows.
+  final double exposure;
+  /// How clearly the mask separates the person, 0 when nobody is found.
+  final double maskQuality;
+  final double score;
+
+  const BurstFrame({
+    required this.width,
+    required this.height,
+    required this.image,
+    required this.timestampUs,
+    required this.sharpness,
+    required this.exposure,
+    required this.maskQuality,
+    required this.score,
+  });
+
+  @override
+  int get hashCode =>
+      width.hashCode ^
+      height.hashCode ^
+      image.hashCode ^
+      timestampUs.hashCode ^
+      sharpness.hashCode ^
+      exposure.hashCode ^
+      maskQuality.hashCode ^
+      score.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BurstFrame &&
+          runtimeType == other.runtimeType &&
+          width == other.width &&
+          height == other.height &&
+          image == other.image &&
+          timestampUs == other.timestampUs &&
+          sharpness == other.sharpness &&
+          exposure == other.exposure &&
+          maskQuality == other.maskQuality &&
+          score == other.score;
+}
+
+class BurstOptions {
+  /// Frames in the burst, 0 for 8.
+  final int frames;
+  /// How many of them are taken from before the call, out of the frames kept
+  /// by `set_burst_history`. The rest are the next frames of the stream.
+  final int framesBefore;
+  final SnapshotFormat format;
+  /// From 1 to 100, only used by JPEG.
+  final int quality;
+  /// Return every frame best first, instead of only the best one.
+  final bool returnAll;
+
+  const BurstOptions({
+    required this.frames,
+    required this.framesBefore,
+    required this.format,
+    required this.quality,
+    required this.returnAll,
+  });
+
+  @override
+  int get hashCode =>
+      frames.hashCode ^
+      framesBefore.hashCode ^
+      format.hashCode ^
+      quality.hashCode ^
+      returnAll.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BurstOptions &&
+          runtimeType == other.runtimeType &&
+          frames == other.frames &&
+          framesBefore == other.framesBefore &&
+          format == other.format &&
+          quality == other.quality &&
+          returnAll == other.returnAll;
+}
+
 class Cameras {
   final String id;
   final String name;
@@ -43,3 +257,323 @@ class Cameras {
           id == other.id &&
           name == other.name;
 }
+
+class FrameAllocationStats {
+  final BigInt frames;
+  final BigInt allocations;
+  final BigInt lastFrameAllocations;
+
+  const FrameAllocationStats({
+    required this.frames,
+    required this.allocations,
+    required this.lastFrameAllocations,
+  });
+
+  @override
+  int get hashCode =>
+      frames.hashCode ^ allocations.hashCode ^ lastFrameAllocations.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FrameAllocationStats &&
+          runtimeType == other.runtimeType &&
+          frames == other.frames &&
+          allocations == other.allocations &&
+          lastFrameAllocations == other.lastFrameAllocations;
+}
+
+class FrameEffects {
+  final bool blur;
+  final bool backgroundReplacement;
+  final bool maskOverlay;
+  final bool roi;
+
+  const FrameEffects({
+    required this.blur,
+    required this.backgroundReplacement,
+    required this.maskOverlay,
+    required this.roi,
+  });
+
+  @override
+  int get hashCode =>
+      blur.hashCode ^
+      backgroundReplacement.hashCode ^
+      maskOverlay.hashCode ^
+      roi.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FrameEffects &&
+          runtimeType == other.runtimeType &&
+          blur == other.blur &&
+          backgroundReplacement == other.backgroundReplacement &&
+          maskOverlay == other.maskOverlay &&
+          roi == other.roi;
+}
+
+class FrameInfo {
+  final int width;
+  final int height;
+  /// Bytes per row of the first plane, 0 for JPEG and PNG.
+  final int stride;
+  final PixelFormat pixelFormat;
+  /// Microseconds since the Unix epoch.
+  final BigInt captureTimestampUs;
+  /// Increases by one for each frame out of the camera, gaps mean dropped
+  /// frames or frames skipped by the frame rate limit.
+  final BigInt sequence;
+  /// From capture to the frame being sent, in microseconds.
+  final BigInt latencyUs;
+  final FrameEffects effects;
+  /// Adaptive quality level the frame was made with, 0 for full quality.
+  final int qualityLevel;
+
+  const FrameInfo({
+    required this.width,
+    required this.height,
+    required this.stride,
+    required this.pixelFormat,
+    required this.captureTimestampUs,
+    required this.sequence,
+    required this.latencyUs,
+    required this.effects,
+    required this.qualityLevel,
+  });
+
+  @override
+  int get hashCode =>
+      width.hashCode ^
+      height.hashCode ^
+      stride.hashCode ^
+      pixelFormat.hashCode ^
+      captureTimestampUs.hashCode ^
+      sequence.hashCode ^
+      latencyUs.hashCode ^
+      effects.hashCode ^
+      qualityLevel.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FrameInfo &&
+          runtimeType == other.runtimeType &&
+          width == other.width &&
+          height == other.height &&
+          stride == other.stride &&
+          pixelFormat == other.pixelFormat &&
+          captureTimestampUs == other.captureTimestampUs &&
+          sequence == other.sequence &&
+          latencyUs == other.latencyUs &&
+          effects == other.effects &&
+          qualityLevel == other.qualityLevel;
+}
+
+/// A processed frame left in shared memory, see `stream_camera_shared`.
+class FrameSlot {
+  /// Ring the slot belongs to, to give back with `release_frame_slot`.
+  final BigInt generation;
+  final int index;
+  /// Address of the pixels, to read in place with dart:ffi.
+  final BigInt address;
+  final int len;
+  final FrameInfo info;
+
+  const FrameSlot({
+    required this.generation,
+    required this.index,
+    required this.address,
+    required this.len,
+    required this.info,
+  });
+
+  @override
+  int get hashCode =>
+      generation.hashCode ^
+      index.hashCode ^
+      address.hashCode ^
+      len.hashCode ^
+      info.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FrameSlot &&
+          runtimeType == other.runtimeType &&
+          generation == other.generation &&
+          index == other.index &&
+          address == other.address &&
+          len == other.len &&
+          info == other.info;
+}
+
+/// Average mask pre/post processing times in microseconds,
+/// for the previous image crate based path and the fused one.
+class MaskBenchmark {
+  final BigInt referencePreprocessUs;
+  final BigInt referencePostprocessUs;
+  final BigInt fusedPreprocessUs;
+  final BigInt fusedPostprocessUs;
+
+  const MaskBenchmark({
+    required this.referencePreprocessUs,
+    required this.referencePostprocessUs,
+    required this.fusedPreprocessUs,
+    required this.fusedPostprocessUs,
+  });
+
+  @override
+  int get hashCode =>
+      referencePreprocessUs.hashCode ^
+      referencePostprocessUs.hashCode ^
+      fusedPreprocessUs.hashCode ^
+      fusedPostprocessUs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MaskBenchmark &&
+          runtimeType == other.runtimeType &&
+          referencePreprocessUs == other.referencePreprocessUs &&
+          referencePostprocessUs == other.referencePostprocessUs &&
+          fusedPreprocessUs == other.fusedPreprocessUs &&
+          fusedPostprocessUs == other.fusedPostprocessUs;
+}
+
+enum PixelFormat {
+  rgba8888,
+  bgra8888,
+  rgb888,
+
+  /// Y plane then interleaved UV at half resolution.
+  nv12,
+
+  /// Y plane then U and V planes at half resolution.
+  i420,
+  jpeg,
+  png,
+  ;
+}
+
+enum PowerMode {
+  normal,
+
+  /// At most 15 fps, and the mask is reused while the scene doesn't move.
+  /// Inference runs on the smaller input of the lite model, when the crate was built with
+  /// one, see `has_lite_model`. Otherwise the full model runs as in the normal mode.
+  lowPower,
+
+  /// Camera closed and stream threads asleep, for when the app window is hidden.
+  /// Streams pick up where they were when leaving this mode.
+  idle,
+  ;
+}
+
+class ProcessedFrame {
+  final FrameInfo info;
+  final Uint8List pixels;
+
+  const ProcessedFrame({required this.info, required this.pixels});
+
+  @override
+  int get hashCode => info.hashCode ^ pixels.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProcessedFrame &&
+          runtimeType == other.runtimeType &&
+          info == other.info &&
+          pixels == other.pixels;
+}
+
+/// Images encoded in the requested format.
+class Snapshot {
+  final int width;
+  final int height;
+  final Uint8List image;
+  final Uint8List? raw;
+  final Uint8List? mask;
+  final FrameEffects effects;
+
+  const Snapshot({
+    required this.width,
+    required this.height,
+    required this.image,
+    required this.raw,
+    required this.mask,
+    required this.effects,
+  });
+
+  @override
+  int get hashCode =>
+      width.hashCode ^
+      height.hashCode ^
+      image.hashCode ^
+      raw.hashCode ^
+      mask.hashCode ^
+      effects.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Snapshot &&
+          runtimeType == other.runtimeType &&
+          width == other.width &&
+          height == other.height &&
+          image == other.image &&
+          raw == other.raw &&
+          mask == other.mask &&
+          effects == other.effects;
+}
+
+enum SnapshotFormat {
+  png,
+  jpeg,
+
+  /// Lossless.
+  webP,
+  ;
+}
+
+class SnapshotOptions {
+  final SnapshotFormat format;
+  /// From 1 to 100, only used by JPEG.
+  final int quality;
+  /// Also return the frame before effects.
+  final bool includeRaw;
+  /// Also return the person mask as a grayscale image.
+  final bool includeMask;
+  /// Briefly switch the camera to its highest resolution for the snapshot.
+  /// The stream skips the frames meanwhile.
+  final bool fullResolution;
+
+  const SnapshotOptions({
+    required this.format,
+    required this.quality,
+    required this.includeRaw,
+    required this.includeMask,
+    required this.fullResolution,
+  });
+
+  @override
+  int get hashCode =>
+      format.hashCode ^
+      quality.hashCode ^
+      includeRaw.hashCode ^
+      includeMask.hashCode ^
+      fullResolution.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SnapshotOptions &&
+          runtimeType == other.runtimeType &&
+          format == other.format &&
+          quality == other.quality &&
+          includeRaw == other.includeRaw &&
+          includeMask == other.includeMask &&
+          fullResolution == other.fullResolution;
+}
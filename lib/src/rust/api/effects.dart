@@ -0,0 +1,46 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `default`, `fmt`
+
+/// Apply the stream effects to an encoded image, at its own resolution.
+/// The image is turned upright following its EXIF orientation, the result has none.
+/// The result is in the same format as the input for PNG, JPEG and WebP, PNG otherwise.
+Future<Uint8List> processImage({
+  required List<int> bytes,
+  required ImageEffects effects,
+}) => RustLib.instance.api.crateApiEffectsProcessImage(
+  bytes: bytes,
+  effects: effects,
+);
+
+class ImageEffects {
+  final bool blur;
+  /// Encoded image replacing the background, resized to the image. Ignored when blurring.
+  final Uint8List? background;
+  /// Show the mask over the image instead of applying the other effects.
+  final bool debug;
+
+  const ImageEffects({
+    required this.blur,
+    required this.background,
+    required this.debug,
+  });
+
+  @override
+  int get hashCode => blur.hashCode ^ background.hashCode ^ debug.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ImageEffects &&
+          runtimeType == other.runtimeType &&
+          blur == other.blur &&
+          background == other.background &&
+          debug == other.debug;
+}
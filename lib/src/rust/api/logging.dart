@@ -0,0 +1,61 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Forwarder`, `Logger`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `clone`, `eq`, `fmt`, `from`
+
+/// Only keep records at `level` or more severe, for Dart and the console of debug builds.
+void setLogLevel({required LogLevel level}) =>
+    RustLib.instance.api.crateApiLoggingSetLogLevel(level: level);
+
+/// Forward log records to Dart, starting with the recent ones.
+/// Replaces any previous subscription.
+Stream<LogEntry> subscribeLogs() =>
+    RustLib.instance.api.crateApiLoggingSubscribeLogs();
+
+class LogEntry {
+  final LogLevel level;
+  /// Module the record comes from, like `rust_lib_flutter_rust_cam_test::api::camera`.
+  final String target;
+  final String message;
+  /// Microseconds since the Unix epoch.
+  final BigInt timestampUs;
+
+  const LogEntry({
+    required this.level,
+    required this.target,
+    required this.message,
+    required this.timestampUs,
+  });
+
+  @override
+  int get hashCode =>
+      level.hashCode ^
+      target.hashCode ^
+      message.hashCode ^
+      timestampUs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LogEntry &&
+          runtimeType == other.runtimeType &&
+          level == other.level &&
+          target == other.target &&
+          message == other.message &&
+          timestampUs == other.timestampUs;
+}
+
+enum LogLevel {
+  error,
+  warn,
+  info,
+  debug,
+  trace,
+  ;
+}
@@ -0,0 +1,135 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `from`
+
+/// Stats of the last stream started on the camera, `None` if there was none.
+Future<PipelineStats?> pipelineStats({required int id}) =>
+    RustLib.instance.api.crateApiMetricsPipelineStats(id: id);
+
+/// Send the stats of the camera stream every `interval_ms`, until Dart closes the stream.
+/// Follows the new stream when the camera is restarted, and sends zeros until the first one
+/// starts.
+Stream<PipelineStats> streamPipelineStats({
+  required int id,
+  required int intervalMs,
+}) => RustLib.instance.api.crateApiMetricsStreamPipelineStats(
+  id: id,
+  intervalMs: intervalMs,
+);
+
+/// Counters and stage timings of a camera stream.
+/// Stages skipped on a frame, like inference without any effect, keep their previous values.
+class PipelineStats {
+  final BigInt capturedFrames;
+  final BigInt processedFrames;
+  /// Frames replaced by a newer one before being processed, or not sent because
+  /// every shared slot was still held.
+  final BigInt droppedFrames;
+  final double captureFps;
+  final double outputFps;
+  final StageStats decode;
+  final StageStats preprocess;
+  final StageStats inference;
+  final StageStats postprocess;
+  final StageStats composite;
+  final StageStats encode;
+  final StageStats sink;
+  final StageStats endToEnd;
+
+  const PipelineStats({
+    required this.capturedFrames,
+    required this.processedFrames,
+    required this.droppedFrames,
+    required this.captureFps,
+    required this.outputFps,
+    required this.decode,
+    required this.preprocess,
+    required this.inference,
+    required this.postprocess,
+    required this.composite,
+    required this.encode,
+    required this.sink,
+    required this.endToEnd,
+  });
+
+  @override
+  int get hashCode =>
+      capturedFrames.hashCode ^
+      processedFrames.hashCode ^
+      droppedFrames.hashCode ^
+      captureFps.hashCode ^
+      outputFps.hashCode ^
+      decode.hashCode ^
+      preprocess.hashCode ^
+      inference.hashCode ^
+      postprocess.hashCode ^
+      composite.hashCode ^
+      encode.hashCode ^
+      sink.hashCode ^
+      endToEnd.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PipelineStats &&
+          runtimeType == other.runtimeType &&
+          capturedFrames == other.capturedFrames &&
+          processedFrames == other.processedFrames &&
+          droppedFrames == other.droppedFrames &&
+          captureFps == other.captureFps &&
+          outputFps == other.outputFps &&
+          decode == other.decode &&
+          preprocess == other.preprocess &&
+          inference == other.inference &&
+          postprocess == other.postprocess &&
+          composite == other.composite &&
+          encode == other.encode &&
+          sink == other.sink &&
+          endToEnd == other.endToEnd;
+}
+
+/// Timings of one pipeline stage over the last frames, in microseconds.
+class StageStats {
+  final BigInt lastUs;
+  final BigInt meanUs;
+  final BigInt p50Us;
+  final BigInt p95Us;
+  final BigInt p99Us;
+  final BigInt maxUs;
+
+  const StageStats({
+    required this.lastUs,
+    required this.meanUs,
+    required this.p50Us,
+    required this.p95Us,
+    required this.p99Us,
+    required this.maxUs,
+  });
+
+  @override
+  int get hashCode =>
+      lastUs.hashCode ^
+      meanUs.hashCode ^
+      p50Us.hashCode ^
+      p95Us.hashCode ^
+      p99Us.hashCode ^
+      maxUs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is StageStats &&
+          runtimeType == other.runtimeType &&
+          lastUs == other.lastUs &&
+          meanUs == other.meanUs &&
+          p50Us == other.p50Us &&
+          p95Us == other.p95Us &&
+          p99Us == other.p99Us &&
+          maxUs == other.maxUs;
+}
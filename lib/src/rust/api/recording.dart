@@ -0,0 +1,530 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'camera.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `clone`, `eq`, `fmt`
+
+/// Record the processed frames of the streams on the camera, without the output
+/// format and resolution changes. Frames of another size than the first are dropped.
+Future<void> startRecording({
+  required int id,
+  required RecordingOptions options,
+}) => RustLib.instance.api.crateApiRecordingStartRecording(
+  id: id,
+  options: options,
+);
+
+Future<void> pauseRecording({required int id}) =>
+    RustLib.instance.api.crateApiRecordingPauseRecording(id: id);
+
+Future<void> resumeRecording({required int id}) =>
+    RustLib.instance.api.crateApiRecordingResumeRecording(id: id);
+
+/// Finish writing the file. Also returns the error that stopped a recording early.
+Future<RecordingSummary> stopRecording({required int id}) =>
+    RustLib.instance.api.crateApiRecordingStopRecording(id: id);
+
+/// Record the raw frames of the streams on the camera into a `.camsession` archive, with the
+/// effect settings and processing state of each. The `run_pipeline` tool replays it with the
+/// same output as the stream. Frames are compressed losslessly, and the stream slows down
+/// rather than leave frames out when the disk can't keep up.
+Future<void> startSessionCapture({required int id, required String path}) =>
+    RustLib.instance.api.crateApiRecordingStartSessionCapture(
+      id: id,
+      path: path,
+    );
+
+/// Finish writing the archive. Also returns the error that stopped a capture early.
+Future<SessionCaptureSummary> stopSessionCapture({required int id}) =>
+    RustLib.instance.api.crateApiRecordingStopSessionCapture(id: id);
+
+/// Keep the last seconds of processed frames of the running stream on the camera.
+/// Changing the options empties the buffer.
+Future<void> setReplayBuffer({
+  required int id,
+  required ReplayOptions options,
+}) => RustLib.instance.api.crateApiRecordingSetReplayBuffer(
+  id: id,
+  options: options,
+);
+
+/// Write the replay buffer of the camera stream, returns the number of frames written.
+/// The buffer keeps filling meanwhile.
+Future<BigInt> saveReplay({
+  required int id,
+  required ReplaySaveOptions options,
+}) => RustLib.instance.api.crateApiRecordingSaveReplay(
+  id: id,
+  options: options,
+);
+
+/// Capture the next seconds of the camera stream and write them as a looping animation,
+/// in the background. Progress goes to the sink, ending with `Done` or `Failed`.
+Stream<ClipProgress> exportClip({
+  required int id,
+  required ClipOptions options,
+}) => RustLib.instance.api.crateApiRecordingExportClip(
+  id: id,
+  options: options,
+);
+
+/// Save one processed frame of the running stream on the camera every interval,
+/// until `stop_timelapse`, the stream ends or `max_frames` are saved.
+Future<void> startTimelapse({
+  required int id,
+  required TimelapseOptions options,
+}) => RustLib.instance.api.crateApiRecordingStartTimelapse(
+  id: id,
+  options: options,
+);
+
+/// Stop the time-lapse, or get the summary of one that ended by itself.
+/// Also returns the error that stopped it early.
+Future<TimelapseSummary> stopTimelapse({required int id}) =>
+    RustLib.instance.api.crateApiRecordingStopTimelapse(id: id);
+
+/// Save camera frames of the streams on the camera with their predicted masks and effect
+/// settings, to collect segmentation training data. Frames are picked by `options`, and
+/// `flag_dataset_frame` saves the next one. Runs until `stop_dataset_capture` or `max_frames`.
+Future<void> startDatasetCapture({
+  required int id,
+  required DatasetOptions options,
+}) => RustLib.instance.api.crateApiRecordingStartDatasetCapture(
+  id: id,
+  options: options,
+);
+
+/// Save the next frame of the dataset capture, for a hard case spotted by the user.
+Future<void> flagDatasetFrame({required int id}) =>
+    RustLib.instance.api.crateApiRecordingFlagDatasetFrame(id: id);
+
+/// Stop the dataset capture, or get the summary of one that ended by itself.
+/// Also returns the error that stopped it early.
+Future<DatasetSummary> stopDatasetCapture({required int id}) =>
+    RustLib.instance.api.crateApiRecordingStopDatasetCapture(id: id);
+
+enum ClipFormat {
+  gif,
+
+  /// Lossless animated WebP.
+  webP,
+  ;
+}
+
+class ClipOptions {
+  final String path;
+  final ClipFormat format;
+  final double seconds;
+  /// Frames per second of the clip, 0 for 10.
+  final int fps;
+  /// Width of the clip, the height follows the stream aspect ratio. 0 for 320.
+  final int width;
+  /// GIF palette quality, from 1 to 100. Higher is slower to encode.
+  final int quality;
+
+  const ClipOptions({
+    required this.path,
+    required this.format,
+    required this.seconds,
+    required this.fps,
+    required this.width,
+    required this.quality,
+  });
+
+  @override
+  int get hashCode =>
+      path.hashCode ^
+      format.hashCode ^
+      seconds.hashCode ^
+      fps.hashCode ^
+      width.hashCode ^
+      quality.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ClipOptions &&
+          runtimeType == other.runtimeType &&
+          path == other.path &&
+          format == other.format &&
+          seconds == other.seconds &&
+          fps == other.fps &&
+          width == other.width &&
+          quality == other.quality;
+}
+
+class ClipProgress {
+  final ClipStage stage;
+  /// Progress of the stage, from 0 to 1.
+  final double fraction;
+  final int frames;
+  /// Size of the file once done.
+  final BigInt bytes;
+  final String? error;
+
+  const ClipProgress({
+    required this.stage,
+    required this.fraction,
+    required this.frames,
+    required this.bytes,
+    required this.error,
+  });
+
+  @override
+  int get hashCode =>
+      stage.hashCode ^
+      fraction.hashCode ^
+      frames.hashCode ^
+      bytes.hashCode ^
+      error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ClipProgress &&
+          runtimeType == other.runtimeType &&
+          stage == other.stage &&
+          fraction == other.fraction &&
+          frames == other.frames &&
+          bytes == other.bytes &&
+          error == other.error;
+}
+
+enum ClipStage {
+  capturing,
+  encoding,
+  done,
+  failed,
+  ;
+}
+
+class DatasetOptions {
+  /// Created if needed, and must be empty. Frames go to `images/`, masks to `masks/` and
+  /// annotations to `annotations/`, all named `000000.png` or `.json` and so on, with the
+  /// COCO annotations of every frame in `annotations.json`.
+  final String directory;
+  /// Save one frame out of this many, 0 to only save low confidence and flagged frames.
+  final int everyFrames;
+  /// Save frames where less than this fraction of the mask is confident, from 0 to 1.
+  /// 0 to turn it off, otherwise the mask is computed for every frame even without effects.
+  final double maxConfidence;
+  /// Frames skipped after saving a low confidence frame.
+  final int lowConfidenceSpacing;
+  /// Stop after this many frames, 0 for no limit.
+  final int maxFrames;
+
+  const DatasetOptions({
+    required this.directory,
+    required this.everyFrames,
+    required this.maxConfidence,
+    required this.lowConfidenceSpacing,
+    required this.maxFrames,
+  });
+
+  @override
+  int get hashCode =>
+      directory.hashCode ^
+      everyFrames.hashCode ^
+      maxConfidence.hashCode ^
+      lowConfidenceSpacing.hashCode ^
+      maxFrames.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DatasetOptions &&
+          runtimeType == other.runtimeType &&
+          directory == other.directory &&
+          everyFrames == other.everyFrames &&
+          maxConfidence == other.maxConfidence &&
+          lowConfidenceSpacing == other.lowConfidenceSpacing &&
+          maxFrames == other.maxFrames;
+}
+
+class DatasetSummary {
+  final int framesSaved;
+  final int intervalFrames;
+  final int lowConfidenceFrames;
+  final int flaggedFrames;
+  /// Frames left out because the disk couldn't keep up.
+  final int framesDropped;
+
+  const DatasetSummary({
+    required this.framesSaved,
+    required this.intervalFrames,
+    required this.lowConfidenceFrames,
+    required this.flaggedFrames,
+    required this.framesDropped,
+  });
+
+  @override
+  int get hashCode =>
+      framesSaved.hashCode ^
+      intervalFrames.hashCode ^
+      lowConfidenceFrames.hashCode ^
+      flaggedFrames.hashCode ^
+      framesDropped.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DatasetSummary &&
+          runtimeType == other.runtimeType &&
+          framesSaved == other.framesSaved &&
+          intervalFrames == other.intervalFrames &&
+          lowConfidenceFrames == other.lowConfidenceFrames &&
+          flaggedFrames == other.flaggedFrames &&
+          framesDropped == other.framesDropped;
+}
+
+enum RecordingFormat {
+  /// Uncompressed YUV 4:2:0, large files but no quality loss.
+  y4m,
+
+  /// Motion JPEG in AVI, limited to 4 GiB.
+  mjpegAvi,
+  ;
+}
+
+class RecordingOptions {
+  final String path;
+  final RecordingFormat format;
+  /// Frame rate of the file, 0 for 30. Camera frames are placed by their capture time,
+  /// repeated or skipped to match it.
+  final int fps;
+  /// From 1 to 100, only used by MJPEG.
+  final int quality;
+  /// Frames waiting to be written, the recording drops frames once it is full.
+  final int queueDepth;
+
+  const RecordingOptions({
+    required this.path,
+    required this.format,
+    required this.fps,
+    required this.quality,
+    required this.queueDepth,
+  });
+
+  @override
+  int get hashCode =>
+      path.hashCode ^
+      format.hashCode ^
+      fps.hashCode ^
+      quality.hashCode ^
+      queueDepth.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RecordingOptions &&
+          runtimeType == other.runtimeType &&
+          path == other.path &&
+          format == other.format &&
+          fps == other.fps &&
+          quality == other.quality &&
+          queueDepth == other.queueDepth;
+}
+
+class RecordingSummary {
+  final BigInt framesWritten;
+  final BigInt framesDropped;
+  final BigInt durationUs;
+
+  const RecordingSummary({
+    required this.framesWritten,
+    required this.framesDropped,
+    required this.durationUs,
+  });
+
+  @override
+  int get hashCode =>
+      framesWritten.hashCode ^ framesDropped.hashCode ^ durationUs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RecordingSummary &&
+          runtimeType == other.runtimeType &&
+          framesWritten == other.framesWritten &&
+          framesDropped == other.framesDropped &&
+          durationUs == other.durationUs;
+}
+
+enum ReplayFormat {
+  y4m,
+  mjpegAvi,
+
+  /// One file per frame in a directory, named after the frame index and capture timestamp.
+  pngSequence,
+  jpegSequence,
+  ;
+}
+
+class ReplayOptions {
+  /// Length of the buffer, 0 to turn it off.
+  final int seconds;
+  /// Older frames are dropped once the buffer takes more memory than this, 0 for no limit.
+  final int maxMemoryMb;
+  /// Keep frames as JPEG of this quality, from 1 to 100, or 0 to keep them uncompressed.
+  final int jpegQuality;
+
+  const ReplayOptions({
+    required this.seconds,
+    required this.maxMemoryMb,
+    required this.jpegQuality,
+  });
+
+  @override
+  int get hashCode =>
+      seconds.hashCode ^ maxMemoryMb.hashCode ^ jpegQuality.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReplayOptions &&
+          runtimeType == other.runtimeType &&
+          seconds == other.seconds &&
+          maxMemoryMb == other.maxMemoryMb &&
+          jpegQuality == other.jpegQuality;
+}
+
+class ReplaySaveOptions {
+  /// File for videos, directory for image sequences.
+  final String path;
+  final ReplayFormat format;
+  /// Save only the last seconds, 0 for the whole buffer.
+  final int seconds;
+  /// Frame rate of videos, 0 for 30.
+  final int fps;
+  /// From 1 to 100, for MJPEG and JPEG.
+  final int quality;
+
+  const ReplaySaveOptions({
+    required this.path,
+    required this.format,
+    required this.seconds,
+    required this.fps,
+    required this.quality,
+  });
+
+  @override
+  int get hashCode =>
+      path.hashCode ^
+      format.hashCode ^
+      seconds.hashCode ^
+      fps.hashCode ^
+      quality.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReplaySaveOptions &&
+          runtimeType == other.runtimeType &&
+          path == other.path &&
+          format == other.format &&
+          seconds == other.seconds &&
+          fps == other.fps &&
+          quality == other.quality;
+}
+
+class SessionCaptureSummary {
+  final BigInt frames;
+  final BigInt settingsChanges;
+  final BigInt bytes;
+
+  const SessionCaptureSummary({
+    required this.frames,
+    required this.settingsChanges,
+    required this.bytes,
+  });
+
+  @override
+  int get hashCode =>
+      frames.hashCode ^ settingsChanges.hashCode ^ bytes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SessionCaptureSummary &&
+          runtimeType == other.runtimeType &&
+          frames == other.frames &&
+          settingsChanges == other.settingsChanges &&
+          bytes == other.bytes;
+}
+
+class TimelapseOptions {
+  /// Created if needed. Frames are named `000000.png` and so on, next to `manifest.jsonl`
+  /// with a header line then a line per frame. A directory used before keeps its frames,
+  /// the new ones are numbered after them.
+  final String directory;
+  /// Time between saved frames, used when `every_frames` is 0.
+  final BigInt intervalMs;
+  /// Save one frame out of this many instead of going by time, 0 to use `interval_ms`.
+  final int everyFrames;
+  final SnapshotFormat format;
+  /// From 1 to 100, only used by JPEG.
+  final int quality;
+  /// Skip frames where no person is detected, the interval only counts frames with someone.
+  final bool pauseWithoutPerson;
+  /// Stop after this many frames, 0 for no limit.
+  final int maxFrames;
+
+  const TimelapseOptions({
+    required this.directory,
+    required this.intervalMs,
+    required this.everyFrames,
+    required this.format,
+    required this.quality,
+    required this.pauseWithoutPerson,
+    required this.maxFrames,
+  });
+
+  @override
+  int get hashCode =>
+      directory.hashCode ^
+      intervalMs.hashCode ^
+      everyFrames.hashCode ^
+      format.hashCode ^
+      quality.hashCode ^
+      pauseWithoutPerson.hashCode ^
+      maxFrames.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TimelapseOptions &&
+          runtimeType == other.runtimeType &&
+          directory == other.directory &&
+          intervalMs == other.intervalMs &&
+          everyFrames == other.everyFrames &&
+          format == other.format &&
+          quality == other.quality &&
+          pauseWithoutPerson == other.pauseWithoutPerson &&
+          maxFrames == other.maxFrames;
+}
+
+class TimelapseSummary {
+  final int framesSaved;
+  final BigInt framesWithoutPerson;
+
+  const TimelapseSummary({
+    required this.framesSaved,
+    required this.framesWithoutPerson,
+  });
+
+  @override
+  int get hashCode => framesSaved.hashCode ^ framesWithoutPerson.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TimelapseSummary &&
+          runtimeType == other.runtimeType &&
+          framesSaved == other.framesSaved &&
+          framesWithoutPerson == other.framesWithoutPerson;
+}
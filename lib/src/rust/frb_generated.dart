@@ -4,6 +4,10 @@
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
 import 'api/camera.dart';
+import 'api/effects.dart';
+import 'api/logging.dart';
+import 'api/metrics.dart';
+import 'api/recording.dart';
 import 'api/simple.dart';
 import 'dart:async';
 import 'dart:convert';
@@ -67,7 +71,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => 558903947;
+  int get rustContentHash => 1241920464;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -78,23 +82,152 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
+  Future<MaskBenchmark> crateApiCameraBenchmarkMask({required int iterations});
+
+  Future<List<BurstFrame>> crateApiCameraCaptureBurst({
+    required int id,
+    required BurstOptions options,
+  });
+
   Future<List<Cameras>> crateApiCameraCheckForCameras();
 
+  Stream<ClipProgress> crateApiRecordingExportClip({
+    required int id,
+    required ClipOptions options,
+  });
+
+  Future<void> crateApiRecordingFlagDatasetFrame({required int id});
+
+  Future<FrameAllocationStats?> crateApiCameraFrameAllocationStats({
+    required int id,
+  });
+
   Uint8List crateApiSimpleGetImage({required String file});
 
   String crateApiSimpleGreet({required String name});
 
+  Future<bool> crateApiCameraHasLiteModel();
+
   Future<void> crateApiSimpleInitApp();
 
   Future<void> crateApiCameraInitCams();
 
+  Future<void> crateApiRecordingPauseRecording({required int id});
+
+  Future<PipelineStats?> crateApiMetricsPipelineStats({required int id});
+
+  Future<void> crateApiCameraPreloadModels();
+
+  Future<Uint8List> crateApiEffectsProcessImage({
+    required List<int> bytes,
+    required ImageEffects effects,
+  });
+
+  Future<void> crateApiCameraReleaseFrameSlot({
+    required BigInt generation,
+    required int index,
+  });
+
+  Future<void> crateApiRecordingResumeRecording({required int id});
+
+  Future<BigInt> crateApiRecordingSaveReplay({
+    required int id,
+    required ReplaySaveOptions options,
+  });
+
+  Future<void> crateApiCameraSetAdaptiveQuality({
+    required bool enabled,
+    required int targetFps,
+  });
+
   Future<void> crateApiCameraSetBackground({required List<int> background});
 
+  Future<void> crateApiCameraSetBurstHistory({
+    required int id,
+    required int frames,
+  });
+
+  Future<void> crateApiCameraSetCompositingThreads({required int threads});
+
   Future<void> crateApiCameraSetDebug({required bool debug});
 
+  Future<void> crateApiCameraSetFrameQueue({
+    required int depth,
+    required bool dropFrames,
+  });
+
+  void crateApiLoggingSetLogLevel({required LogLevel level});
+
   Future<void> crateApiCameraSetMask({required bool mask});
 
-  Stream<Uint8List> crateApiCameraStreamCamera({required int id});
+  Future<void> crateApiCameraSetMaskRefinement({required bool enabled});
+
+  Future<void> crateApiCameraSetOutputFormat({
+    required PixelFormat format,
+    required int quality,
+  });
+
+  Future<void> crateApiCameraSetPowerMode({required PowerMode mode});
+
+  Future<void> crateApiRecordingSetReplayBuffer({
+    required int id,
+    required ReplayOptions options,
+  });
+
+  Future<void> crateApiCameraSetRoiMode({required bool enabled});
+
+  Future<void> crateApiCameraSetTargetFps({required int fps});
+
+  Future<void> crateApiCameraSetTemporalSmoothing({required bool enabled});
+
+  Future<void> crateApiRecordingStartDatasetCapture({
+    required int id,
+    required DatasetOptions options,
+  });
+
+  Future<void> crateApiRecordingStartRecording({
+    required int id,
+    required RecordingOptions options,
+  });
+
+  Future<void> crateApiRecordingStartSessionCapture({
+    required int id,
+    required String path,
+  });
+
+  Future<void> crateApiRecordingStartTimelapse({
+    required int id,
+    required TimelapseOptions options,
+  });
+
+  Future<DatasetSummary> crateApiRecordingStopDatasetCapture({required int id});
+
+  Future<RecordingSummary> crateApiRecordingStopRecording({required int id});
+
+  Future<SessionCaptureSummary> crateApiRecordingStopSessionCapture({
+    required int id,
+  });
+
+  Future<TimelapseSummary> crateApiRecordingStopTimelapse({required int id});
+
+  Stream<ProcessedFrame> crateApiCameraStreamCamera({required int id});
+
+  Stream<FrameSlot> crateApiCameraStreamCameraShared({
+    required int id,
+    required int slotCount,
+  });
+
+  Stream<PipelineStats> crateApiMetricsStreamPipelineStats({
+    required int id,
+    required int intervalMs,
+  });
+
+  Stream<LogEntry> crateApiLoggingSubscribeLogs();
+
+  Future<Snapshot> crateApiCameraTakeSnapshot({
+    required int id,
+    required SnapshotOptions options,
+  });
 }
 
 class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
@@ -106,11 +239,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   });
 
   @override
-  Future<List<Cameras>> crateApiCameraCheckForCameras() {
+  Future<MaskBenchmark> crateApiCameraBenchmarkMask({required int iterations}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(iterations, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -118,6 +252,71 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             port: port_,
           );
         },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_mask_benchmark,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCameraBenchmarkMaskConstMeta,
+        argValues: [iterations],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCameraBenchmarkMaskConstMeta =>
+      const TaskConstMeta(
+        debugName: "benchmark_mask",
+        argNames: ["iterations"],
+      );
+
+  @override
+  Future<List<BurstFrame>> crateApiCameraCaptureBurst({
+    required int id,
+    required BurstOptions options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(id, serializer);
+          sse_encode_box_autoadd_burst_options(options, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 2,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_burst_frame,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiCameraCaptureBurstConstMeta,
+        argValues: [id, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCameraCaptureBurstConstMeta =>
+      const TaskConstMeta(
+        debugName: "capture_burst",
+        argNames: ["id", "options"],
+      );
+
+  @override
+  Future<List<Cameras>> crateApiCameraCheckForCameras() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 3,
+            port: port_,
+          );
+        },
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_cameras,
           decodeErrorData: null,
@@ -132,6 +331,107 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiCameraCheckForCamerasConstMeta =>
       const TaskConstMeta(debugName: "check_for_cameras", argNames: []);
 
+  @override
+  Stream<ClipProgress> crateApiRecordingExportClip({
+    required int id,
+    required ClipOptions options,
+  }) {
+    final sink = RustStreamSink<ClipProgress>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_u_32(id, serializer);
+            sse_encode_box_autoadd_clip_options(options, serializer);
+            sse_encode_StreamSink_clip_progress_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 4,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiRecordingExportClipConstMeta,
+          argValues: [id, options, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiRecordingExportClipConstMeta =>
+      const TaskConstMeta(
+        debugName: "export_clip",
+        argNames: ["id", "options", "sink"],
+      );
+
+  @override
+  Future<void> crateApiRecordingFlagDatasetFrame({required int id}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 5,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiRecordingFlagDatasetFrameConstMeta,
+        argValues: [id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRecordingFlagDatasetFrameConstMeta =>
+      const TaskConstMeta(debugName: "flag_dataset_frame", argNames: ["id"]);
+
+  @override
+  Future<FrameAllocationStats?> crateApiCameraFrameAllocationStats({
+    required int id,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 6,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_frame_allocation_stats,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCameraFrameAllocationStatsConstMeta,
+        argValues: [id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCameraFrameAllocationStatsConstMeta =>
+      const TaskConstMeta(
+        debugName: "frame_allocation_stats",
+        argNames: ["id"],
+      );
+
   @override
   Uint8List crateApiSimpleGetImage({required String file}) {
     return handler.executeSync(
@@ -139,7 +439,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(file, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_prim_u_8_strict,
@@ -162,7 +462,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(name, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -178,6 +478,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
       const TaskConstMeta(debugName: "greet", argNames: ["name"]);
 
+  @override
+  Future<bool> crateApiCameraHasLiteModel() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 9,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCameraHasLiteModelConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCameraHasLiteModelConstMeta =>
+      const TaskConstMeta(debugName: "has_lite_model", argNames: []);
+
   @override
   Future<void> crateApiSimpleInitApp() {
     return handler.executeNormal(
@@ -187,7 +514,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 4,
+            funcId: 10,
             port: port_,
           );
         },
@@ -214,7 +541,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 5,
+            funcId: 11,
             port: port_,
           );
         },
@@ -233,16 +560,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       const TaskConstMeta(debugName: "init_cams", argNames: []);
 
   @override
-  Future<void> crateApiCameraSetBackground({required List<int> background}) {
+  Future<void> crateApiRecordingPauseRecording({required int id}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(background, serializer);
+          sse_encode_u_32(id, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 6,
+            funcId: 12,
             port: port_,
           );
         },
@@ -250,58 +577,54 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiCameraSetBackgroundConstMeta,
-        argValues: [background],
+        constMeta: kCrateApiRecordingPauseRecordingConstMeta,
+        argValues: [id],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCameraSetBackgroundConstMeta =>
-      const TaskConstMeta(
-        debugName: "set_background",
-        argNames: ["background"],
-      );
+  TaskConstMeta get kCrateApiRecordingPauseRecordingConstMeta =>
+      const TaskConstMeta(debugName: "pause_recording", argNames: ["id"]);
 
   @override
-  Future<void> crateApiCameraSetDebug({required bool debug}) {
+  Future<PipelineStats?> crateApiMetricsPipelineStats({required int id}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_bool(debug, serializer);
+          sse_encode_u_32(id, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 7,
+            funcId: 13,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_opt_box_autoadd_pipeline_stats,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiCameraSetDebugConstMeta,
-        argValues: [debug],
+        constMeta: kCrateApiMetricsPipelineStatsConstMeta,
+        argValues: [id],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCameraSetDebugConstMeta =>
-      const TaskConstMeta(debugName: "set_debug", argNames: ["debug"]);
+  TaskConstMeta get kCrateApiMetricsPipelineStatsConstMeta =>
+      const TaskConstMeta(debugName: "pipeline_stats", argNames: ["id"]);
 
   @override
-  Future<void> crateApiCameraSetMask({required bool mask}) {
+  Future<void> crateApiCameraPreloadModels() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_bool(mask, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 8,
+            funcId: 14,
             port: port_,
           );
         },
@@ -309,251 +632,2826 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiCameraSetMaskConstMeta,
-        argValues: [mask],
+        constMeta: kCrateApiCameraPreloadModelsConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCameraSetMaskConstMeta =>
-      const TaskConstMeta(debugName: "set_mask", argNames: ["mask"]);
+  TaskConstMeta get kCrateApiCameraPreloadModelsConstMeta =>
+      const TaskConstMeta(debugName: "preload_models", argNames: []);
 
   @override
-  Stream<Uint8List> crateApiCameraStreamCamera({required int id}) {
-    final sink = RustStreamSink<Uint8List>();
-    unawaited(
-      handler.executeNormal(
-        NormalTask(
-          callFfi: (port_) {
-            final serializer = SseSerializer(generalizedFrbRustBinding);
-            sse_encode_u_32(id, serializer);
-            sse_encode_StreamSink_list_prim_u_8_strict_Sse(sink, serializer);
-            pdeCallFfi(
-              generalizedFrbRustBinding,
-              serializer,
-              funcId: 9,
-              port: port_,
-            );
-          },
-          codec: SseCodec(
-            decodeSuccessData: sse_decode_unit,
-            decodeErrorData: sse_decode_AnyhowException,
-          ),
-          constMeta: kCrateApiCameraStreamCameraConstMeta,
-          argValues: [id, sink],
-          apiImpl: this,
+  Future<Uint8List> crateApiEffectsProcessImage({
+    required List<int> bytes,
+    required ImageEffects effects,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(bytes, serializer);
+          sse_encode_box_autoadd_image_effects(effects, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 15,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
+        constMeta: kCrateApiEffectsProcessImageConstMeta,
+        argValues: [bytes, effects],
+        apiImpl: this,
       ),
     );
-    return sink.stream;
   }
 
-  TaskConstMeta get kCrateApiCameraStreamCameraConstMeta =>
-      const TaskConstMeta(debugName: "stream_camera", argNames: ["id", "sink"]);
+  TaskConstMeta get kCrateApiEffectsProcessImageConstMeta =>
+      const TaskConstMeta(
+        debugName: "process_image",
+        argNames: ["bytes", "effects"],
+      );
+
+  @override
+  Future<void> crateApiCameraReleaseFrameSlot({
+    required BigInt generation,
+    required int index,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_64(generation, serializer);
+          sse_encode_u_32(index, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 16,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCameraReleaseFrameSlotConstMeta,
+        argValues: [generation, index],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCameraReleaseFrameSlotConstMeta =>
+      const TaskConstMeta(
+        debugName: "release_frame_slot",
+        argNames: ["generation", "index"],
+      );
+
+  @override
+  Future<void> crateApiRecordingResumeRecording({required int id}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 17,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiRecordingResumeRecordingConstMeta,
+        argValues: [id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRecordingResumeRecordingConstMeta =>
+      const TaskConstMeta(debugName: "resume_recording", argNames: ["id"]);
+
+  @override
+  Future<BigInt> crateApiRecordingSaveReplay({
+    required int id,
+    required ReplaySaveOptions options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(id, serializer);
+          sse_encode_box_autoadd_replay_save_options(options, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 18,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiRecordingSaveReplayConstMeta,
+        argValues: [id, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRecordingSaveReplayConstMeta =>
+      const TaskConstMeta(
+        debugName: "save_replay",
+        argNames: ["id", "options"],
+      );
+
+  @override
+  Future<void> crateApiCameraSetAdaptiveQuality({
+    required bool enabled,
+    required int targetFps,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(enabled, serializer);
+          sse_encode_u_32(targetFps, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 19,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCameraSetAdaptiveQualityConstMeta,
+        argValues: [enabled, targetFps],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCameraSetAdaptiveQualityConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_adaptive_quality",
+        argNames: ["enabled", "target_fps"],
+      );
+
+  @override
+  Future<void> crateApiCameraSetBackground({required List<int> background}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(background, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 20,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiCameraSetBackgroundConstMeta,
+        argValues: [background],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCameraSetBackgroundConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_background",
+        argNames: ["background"],
+      );
+
+  @override
+  Future<void> crateApiCameraSetBurstHistory({
+    required int id,
+    required int frames,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(id, serializer);
+          sse_encode_u_32(frames, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 21,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiCameraSetBurstHistoryConstMeta,
+        argValues: [id, frames],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCameraSetBurstHistoryConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_burst_history",
+        argNames: ["id", "frames"],
+      );
+
+  @override
+  Future<void> crateApiCameraSetCompositingThreads({required int threads}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(threads, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 22,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCameraSetCompositingThreadsConstMeta,
+        argValues: [threads],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCameraSetCompositingThreadsConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_compositing_threads",
+        argNames: ["threads"],
+      );
+
+  @override
+  Future<void> crateApiCameraSetDebug({required bool debug}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(debug, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 23,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCameraSetDebugConstMeta,
+        argValues: [debug],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCameraSetDebugConstMeta =>
+      const TaskConstMeta(debugName: "set_debug", argNames: ["debug"]);
+
+  @override
+  Future<void> crateApiCameraSetFrameQueue({
+    required int depth,
+    required bool dropFrames,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(depth, serializer);
+          sse_encode_bool(dropFrames, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 24,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCameraSetFrameQueueConstMeta,
+        argValues: [depth, dropFrames],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCameraSetFrameQueueConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_frame_queue",
+        argNames: ["depth", "drop_frames"],
+      );
+
+  @override
+  void crateApiLoggingSetLogLevel({required LogLevel level}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_log_level(level, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLoggingSetLogLevelConstMeta,
+        argValues: [level],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLoggingSetLogLevelConstMeta =>
+      const TaskConstMeta(debugName: "set_log_level", argNames: ["level"]);
+
+  @override
+  Future<void> crateApiCameraSetMask({required bool mask}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(mask, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 26,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCameraSetMaskConstMeta,
+        argValues: [mask],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCameraSetMaskConstMeta =>
+      const TaskConstMeta(debugName: "set_mask", argNames: ["mask"]);
+
+  @override
+  Future<void> crateApiCameraSetMaskRefinement({required bool enabled}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(enabled, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 27,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCameraSetMaskRefinementConstMeta,
+        argValues: [enabled],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCameraSetMaskRefinementConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_mask_refinement",
+        argNames: ["enabled"],
+      );
+
+  @override
+  Future<void> crateApiCameraSetOutputFormat({
+    required PixelFormat format,
+    required int quality,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_pixel_format(format, serializer);
+          sse_encode_u_8(quality, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 28,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCameraSetOutputFormatConstMeta,
+        argValues: [format, quality],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCameraSetOutputFormatConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_output_format",
+        argNames: ["format", "quality"],
+      );
+
+  @override
+  Future<void> crateApiCameraSetPowerMode({required PowerMode mode}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_power_mode(mode, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 29,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCameraSetPowerModeConstMeta,
+        argValues: [mode],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCameraSetPowerModeConstMeta =>
+      const TaskConstMeta(debugName: "set_power_mode", argNames: ["mode"]);
+
+  @override
+  Future<void> crateApiRecordingSetReplayBuffer({
+    required int id,
+    required ReplayOptions options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(id, serializer);
+          sse_encode_box_autoadd_replay_options(options, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 30,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiRecordingSetReplayBufferConstMeta,
+        argValues: [id, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRecordingSetReplayBufferConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_replay_buffer",
+        argNames: ["id", "options"],
+      );
+
+  @override
+  Future<void> crateApiCameraSetRoiMode({required bool enabled}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(enabled, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 31,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCameraSetRoiModeConstMeta,
+        argValues: [enabled],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCameraSetRoiModeConstMeta =>
+      const TaskConstMeta(debugName: "set_roi_mode", argNames: ["enabled"]);
+
+  @override
+  Future<void> crateApiCameraSetTargetFps({required int fps}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(fps, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 32,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCameraSetTargetFpsConstMeta,
+        argValues: [fps],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCameraSetTargetFpsConstMeta =>
+      const TaskConstMeta(debugName: "set_target_fps", argNames: ["fps"]);
+
+  @override
+  Future<void> crateApiCameraSetTemporalSmoothing({required bool enabled}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(enabled, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 33,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCameraSetTemporalSmoothingConstMeta,
+        argValues: [enabled],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCameraSetTemporalSmoothingConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_temporal_smoothing",
+        argNames: ["enabled"],
+      );
+
+  @override
+  Future<void> crateApiRecordingStartDatasetCapture({
+    required int id,
+    required DatasetOptions options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(id, serializer);
+          sse_encode_box_autoadd_dataset_options(options, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 34,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiRecordingStartDatasetCaptureConstMeta,
+        argValues: [id, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRecordingStartDatasetCaptureConstMeta =>
+      const TaskConstMeta(
+        debugName: "start_dataset_capture",
+        argNames: ["id", "options"],
+      );
+
+  @override
+  Future<void> crateApiRecordingStartRecording({
+    required int id,
+    required RecordingOptions options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(id, serializer);
+          sse_encode_box_autoadd_recording_options(options, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 35,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiRecordingStartRecordingConstMeta,
+        argValues: [id, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRecordingStartRecordingConstMeta =>
+      const TaskConstMeta(
+        debugName: "start_recording",
+        argNames: ["id", "options"],
+      );
+
+  @override
+  Future<void> crateApiRecordingStartSessionCapture({
+    required int id,
+    required String path,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(id, serializer);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 36,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiRecordingStartSessionCaptureConstMeta,
+        argValues: [id, path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRecordingStartSessionCaptureConstMeta =>
+      const TaskConstMeta(
+        debugName: "start_session_capture",
+        argNames: ["id", "path"],
+      );
+
+  @override
+  Future<void> crateApiRecordingStartTimelapse({
+    required int id,
+    required TimelapseOptions options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(id, serializer);
+          sse_encode_box_autoadd_timelapse_options(options, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 37,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiRecordingStartTimelapseConstMeta,
+        argValues: [id, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRecordingStartTimelapseConstMeta =>
+      const TaskConstMeta(
+        debugName: "start_timelapse",
+        argNames: ["id", "options"],
+      );
+
+  @override
+  Future<DatasetSummary> crateApiRecordingStopDatasetCapture({
+    required int id,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 38,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_dataset_summary,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiRecordingStopDatasetCaptureConstMeta,
+        argValues: [id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRecordingStopDatasetCaptureConstMeta =>
+      const TaskConstMeta(debugName: "stop_dataset_capture", argNames: ["id"]);
+
+  @override
+  Future<RecordingSummary> crateApiRecordingStopRecording({required int id}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 39,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_recording_summary,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiRecordingStopRecordingConstMeta,
+        argValues: [id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRecordingStopRecordingConstMeta =>
+      const TaskConstMeta(debugName: "stop_recording", argNames: ["id"]);
+
+  @override
+  Future<SessionCaptureSummary> crateApiRecordingStopSessionCapture({
+    required int id,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 40,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_session_capture_summary,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiRecordingStopSessionCaptureConstMeta,
+        argValues: [id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRecordingStopSessionCaptureConstMeta =>
+      const TaskConstMeta(debugName: "stop_session_capture", argNames: ["id"]);
+
+  @override
+  Future<TimelapseSummary> crateApiRecordingStopTimelapse({required int id}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 41,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_timelapse_summary,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiRecordingStopTimelapseConstMeta,
+        argValues: [id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRecordingStopTimelapseConstMeta =>
+      const TaskConstMeta(debugName: "stop_timelapse", argNames: ["id"]);
+
+  @override
+  Stream<ProcessedFrame> crateApiCameraStreamCamera({required int id}) {
+    final sink = RustStreamSink<ProcessedFrame>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_u_32(id, serializer);
+            sse_encode_StreamSink_processed_frame_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 42,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiCameraStreamCameraConstMeta,
+          argValues: [id, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiCameraStreamCameraConstMeta =>
+      const TaskConstMeta(debugName: "stream_camera", argNames: ["id", "sink"]);
+
+  @override
+  Stream<FrameSlot> crateApiCameraStreamCameraShared({
+    required int id,
+    required int slotCount,
+  }) {
+    final sink = RustStreamSink<FrameSlot>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_u_32(id, serializer);
+            sse_encode_u_32(slotCount, serializer);
+            sse_encode_StreamSink_frame_slot_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 43,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiCameraStreamCameraSharedConstMeta,
+          argValues: [id, slotCount, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiCameraStreamCameraSharedConstMeta =>
+      const TaskConstMeta(
+        debugName: "stream_camera_shared",
+        argNames: ["id", "slot_count", "sink"],
+      );
+
+  @override
+  Stream<PipelineStats> crateApiMetricsStreamPipelineStats({
+    required int id,
+    required int intervalMs,
+  }) {
+    final sink = RustStreamSink<PipelineStats>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_u_32(id, serializer);
+            sse_encode_u_32(intervalMs, serializer);
+            sse_encode_StreamSink_pipeline_stats_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 44,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiMetricsStreamPipelineStatsConstMeta,
+          argValues: [id, intervalMs, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiMetricsStreamPipelineStatsConstMeta =>
+      const TaskConstMeta(
+        debugName: "stream_pipeline_stats",
+        argNames: ["id", "interval_ms", "sink"],
+      );
+
+  @override
+  Stream<LogEntry> crateApiLoggingSubscribeLogs() {
+    final sink = RustStreamSink<LogEntry>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_log_entry_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 45,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiLoggingSubscribeLogsConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiLoggingSubscribeLogsConstMeta =>
+      const TaskConstMeta(debugName: "subscribe_logs", argNames: ["sink"]);
+
+  @override
+  Future<Snapshot> crateApiCameraTakeSnapshot({
+    required int id,
+    required SnapshotOptions options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(id, serializer);
+          sse_encode_box_autoadd_snapshot_options(options, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 46,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_snapshot,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiCameraTakeSnapshotConstMeta,
+        argValues: [id, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCameraTakeSnapshotConstMeta =>
+      const TaskConstMeta(
+        debugName: "take_snapshot",
+        argNames: ["id", "options"],
+      );
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  RustStreamSink<ClipProgress> dco_decode_StreamSink_clip_progress_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<FrameSlot> dco_decode_StreamSink_frame_slot_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<LogEntry> dco_decode_StreamSink_log_entry_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<PipelineStats> dco_decode_StreamSink_pipeline_stats_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<ProcessedFrame> dco_decode_StreamSink_processed_frame_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  BurstOptions dco_decode_box_autoadd_burst_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_burst_options(raw);
+  }
+
+  @protected
+  ClipOptions dco_decode_box_autoadd_clip_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_clip_options(raw);
+  }
+
+  @protected
+  DatasetOptions dco_decode_box_autoadd_dataset_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_dataset_options(raw);
+  }
+
+  @protected
+  FrameAllocationStats dco_decode_box_autoadd_frame_allocation_stats(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_frame_allocation_stats(raw);
+  }
+
+  @protected
+  ImageEffects dco_decode_box_autoadd_image_effects(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_image_effects(raw);
+  }
+
+  @protected
+  PipelineStats dco_decode_box_autoadd_pipeline_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_pipeline_stats(raw);
+  }
+
+  @protected
+  RecordingOptions dco_decode_box_autoadd_recording_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_recording_options(raw);
+  }
+
+  @protected
+  ReplayOptions dco_decode_box_autoadd_replay_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_replay_options(raw);
+  }
+
+  @protected
+  ReplaySaveOptions dco_decode_box_autoadd_replay_save_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_replay_save_options(raw);
+  }
+
+  @protected
+  SnapshotOptions dco_decode_box_autoadd_snapshot_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_snapshot_options(raw);
+  }
+
+  @protected
+  TimelapseOptions dco_decode_box_autoadd_timelapse_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_timelapse_options(raw);
+  }
+
+  @protected
+  BurstFrame dco_decode_burst_frame(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return BurstFrame(
+      width: dco_decode_u_32(arr[0]),
+      height: dco_decode_u_32(arr[1]),
+      image: dco_decode_list_prim_u_8_strict(arr[2]),
+      timestampUs: dco_decode_u_64(arr[3]),
+      sharpness: dco_decode_f_64(arr[4]),
+      exposure: dco_decode_f_64(arr[5]),
+      maskQuality: dco_decode_f_64(arr[6]),
+      score: dco_decode_f_64(arr[7]),
+    );
+  }
+
+  @protected
+  BurstOptions dco_decode_burst_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return BurstOptions(
+      frames: dco_decode_u_32(arr[0]),
+      framesBefore: dco_decode_u_32(arr[1]),
+      format: dco_decode_snapshot_format(arr[2]),
+      quality: dco_decode_u_8(arr[3]),
+      returnAll: dco_decode_bool(arr[4]),
+    );
+  }
+
+  @protected
+  Cameras dco_decode_cameras(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return Cameras(
+      id: dco_decode_String(arr[0]),
+      name: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  ClipFormat dco_decode_clip_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ClipFormat.values[raw as int];
+  }
+
+  @protected
+  ClipOptions dco_decode_clip_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return ClipOptions(
+      path: dco_decode_String(arr[0]),
+      format: dco_decode_clip_format(arr[1]),
+      seconds: dco_decode_f_64(arr[2]),
+      fps: dco_decode_u_32(arr[3]),
+      width: dco_decode_u_32(arr[4]),
+      quality: dco_decode_u_8(arr[5]),
+    );
+  }
+
+  @protected
+  ClipProgress dco_decode_clip_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ClipProgress(
+      stage: dco_decode_clip_stage(arr[0]),
+      fraction: dco_decode_f_64(arr[1]),
+      frames: dco_decode_u_32(arr[2]),
+      bytes: dco_decode_u_64(arr[3]),
+      error: dco_decode_opt_String(arr[4]),
+    );
+  }
+
+  @protected
+  ClipStage dco_decode_clip_stage(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ClipStage.values[raw as int];
+  }
+
+  @protected
+  DatasetOptions dco_decode_dataset_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return DatasetOptions(
+      directory: dco_decode_String(arr[0]),
+      everyFrames: dco_decode_u_32(arr[1]),
+      maxConfidence: dco_decode_f_32(arr[2]),
+      lowConfidenceSpacing: dco_decode_u_32(arr[3]),
+      maxFrames: dco_decode_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  DatasetSummary dco_decode_dataset_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return DatasetSummary(
+      framesSaved: dco_decode_u_32(arr[0]),
+      intervalFrames: dco_decode_u_32(arr[1]),
+      lowConfidenceFrames: dco_decode_u_32(arr[2]),
+      flaggedFrames: dco_decode_u_32(arr[3]),
+      framesDropped: dco_decode_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  double dco_decode_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  double dco_decode_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  FrameAllocationStats dco_decode_frame_allocation_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return FrameAllocationStats(
+      frames: dco_decode_u_64(arr[0]),
+      allocations: dco_decode_u_64(arr[1]),
+      lastFrameAllocations: dco_decode_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  FrameEffects dco_decode_frame_effects(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return FrameEffects(
+      blur: dco_decode_bool(arr[0]),
+      backgroundReplacement: dco_decode_bool(arr[1]),
+      maskOverlay: dco_decode_bool(arr[2]),
+      roi: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  FrameInfo dco_decode_frame_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return FrameInfo(
+      width: dco_decode_u_32(arr[0]),
+      height: dco_decode_u_32(arr[1]),
+      stride: dco_decode_u_32(arr[2]),
+      pixelFormat: dco_decode_pixel_format(arr[3]),
+      captureTimestampUs: dco_decode_u_64(arr[4]),
+      sequence: dco_decode_u_64(arr[5]),
+      latencyUs: dco_decode_u_64(arr[6]),
+      effects: dco_decode_frame_effects(arr[7]),
+      qualityLevel: dco_decode_u_32(arr[8]),
+    );
+  }
+
+  @protected
+  FrameSlot dco_decode_frame_slot(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return FrameSlot(
+      generation: dco_decode_u_64(arr[0]),
+      index: dco_decode_u_32(arr[1]),
+      address: dco_decode_u_64(arr[2]),
+      len: dco_decode_u_32(arr[3]),
+      info: dco_decode_frame_info(arr[4]),
+    );
+  }
+
+  @protected
+  ImageEffects dco_decode_image_effects(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ImageEffects(
+      blur: dco_decode_bool(arr[0]),
+      background: dco_decode_opt_list_prim_u_8_strict(arr[1]),
+      debug: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  List<BurstFrame> dco_decode_list_burst_frame(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_burst_frame).toList();
+  }
+
+  @protected
+  List<Cameras> dco_decode_list_cameras(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_cameras).toList();
+  }
+
+  @protected
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as List<int>;
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  LogEntry dco_decode_log_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return LogEntry(
+      level: dco_decode_log_level(arr[0]),
+      target: dco_decode_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      timestampUs: dco_decode_u_64(arr[3]),
+    );
+  }
+
+  @protected
+  LogLevel dco_decode_log_level(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return LogLevel.values[raw as int];
+  }
+
+  @protected
+  MaskBenchmark dco_decode_mask_benchmark(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return MaskBenchmark(
+      referencePreprocessUs: dco_decode_u_64(arr[0]),
+      referencePostprocessUs: dco_decode_u_64(arr[1]),
+      fusedPreprocessUs: dco_decode_u_64(arr[2]),
+      fusedPostprocessUs: dco_decode_u_64(arr[3]),
+    );
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  FrameAllocationStats? dco_decode_opt_box_autoadd_frame_allocation_stats(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_frame_allocation_stats(raw);
+  }
+
+  @protected
+  PipelineStats? dco_decode_opt_box_autoadd_pipeline_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_pipeline_stats(raw);
+  }
+
+  @protected
+  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_prim_u_8_strict(raw);
+  }
+
+  @protected
+  PipelineStats dco_decode_pipeline_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 13)
+      throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
+    return PipelineStats(
+      capturedFrames: dco_decode_u_64(arr[0]),
+      processedFrames: dco_decode_u_64(arr[1]),
+      droppedFrames: dco_decode_u_64(arr[2]),
+      captureFps: dco_decode_f_64(arr[3]),
+      outputFps: dco_decode_f_64(arr[4]),
+      decode: dco_decode_stage_stats(arr[5]),
+      preprocess: dco_decode_stage_stats(arr[6]),
+      inference: dco_decode_stage_stats(arr[7]),
+      postprocess: dco_decode_stage_stats(arr[8]),
+      composite: dco_decode_stage_stats(arr[9]),
+      encode: dco_decode_stage_stats(arr[10]),
+      sink: dco_decode_stage_stats(arr[11]),
+      endToEnd: dco_decode_stage_stats(arr[12]),
+    );
+  }
+
+  @protected
+  PixelFormat dco_decode_pixel_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PixelFormat.values[raw as int];
+  }
+
+  @protected
+  PowerMode dco_decode_power_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PowerMode.values[raw as int];
+  }
+
+  @protected
+  ProcessedFrame dco_decode_processed_frame(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ProcessedFrame(
+      info: dco_decode_frame_info(arr[0]),
+      pixels: dco_decode_list_prim_u_8_strict(arr[1]),
+    );
+  }
+
+  @protected
+  RecordingFormat dco_decode_recording_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RecordingFormat.values[raw as int];
+  }
+
+  @protected
+  RecordingOptions dco_decode_recording_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return RecordingOptions(
+      path: dco_decode_String(arr[0]),
+      format: dco_decode_recording_format(arr[1]),
+      fps: dco_decode_u_32(arr[2]),
+      quality: dco_decode_u_8(arr[3]),
+      queueDepth: dco_decode_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  RecordingSummary dco_decode_recording_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return RecordingSummary(
+      framesWritten: dco_decode_u_64(arr[0]),
+      framesDropped: dco_decode_u_64(arr[1]),
+      durationUs: dco_decode_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  ReplayFormat dco_decode_replay_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ReplayFormat.values[raw as int];
+  }
+
+  @protected
+  ReplayOptions dco_decode_replay_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ReplayOptions(
+      seconds: dco_decode_u_32(arr[0]),
+      maxMemoryMb: dco_decode_u_32(arr[1]),
+      jpegQuality: dco_decode_u_8(arr[2]),
+    );
+  }
+
+  @protected
+  ReplaySaveOptions dco_decode_replay_save_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ReplaySaveOptions(
+      path: dco_decode_String(arr[0]),
+      format: dco_decode_replay_format(arr[1]),
+      seconds: dco_decode_u_32(arr[2]),
+      fps: dco_decode_u_32(arr[3]),
+      quality: dco_decode_u_8(arr[4]),
+    );
+  }
+
+  @protected
+  SessionCaptureSummary dco_decode_session_capture_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return SessionCaptureSummary(
+      frames: dco_decode_u_64(arr[0]),
+      settingsChanges: dco_decode_u_64(arr[1]),
+      bytes: dco_decode_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  Snapshot dco_decode_snapshot(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return Snapshot(
+      width: dco_decode_u_32(arr[0]),
+      height: dco_decode_u_32(arr[1]),
+      image: dco_decode_list_prim_u_8_strict(arr[2]),
+      raw: dco_decode_opt_list_prim_u_8_strict(arr[3]),
+      mask: dco_decode_opt_list_prim_u_8_strict(arr[4]),
+      effects: dco_decode_frame_effects(arr[5]),
+    );
+  }
+
+  @protected
+  SnapshotFormat dco_decode_snapshot_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SnapshotFormat.values[raw as int];
+  }
+
+  @protected
+  SnapshotOptions dco_decode_snapshot_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return SnapshotOptions(
+      format: dco_decode_snapshot_format(arr[0]),
+      quality: dco_decode_u_8(arr[1]),
+      includeRaw: dco_decode_bool(arr[2]),
+      includeMask: dco_decode_bool(arr[3]),
+      fullResolution: dco_decode_bool(arr[4]),
+    );
+  }
+
+  @protected
+  StageStats dco_decode_stage_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return StageStats(
+      lastUs: dco_decode_u_64(arr[0]),
+      meanUs: dco_decode_u_64(arr[1]),
+      p50Us: dco_decode_u_64(arr[2]),
+      p95Us: dco_decode_u_64(arr[3]),
+      p99Us: dco_decode_u_64(arr[4]),
+      maxUs: dco_decode_u_64(arr[5]),
+    );
+  }
+
+  @protected
+  TimelapseOptions dco_decode_timelapse_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return TimelapseOptions(
+      directory: dco_decode_String(arr[0]),
+      intervalMs: dco_decode_u_64(arr[1]),
+      everyFrames: dco_decode_u_32(arr[2]),
+      format: dco_decode_snapshot_format(arr[3]),
+      quality: dco_decode_u_8(arr[4]),
+      pauseWithoutPerson: dco_decode_bool(arr[5]),
+      maxFrames: dco_decode_u_32(arr[6]),
+    );
+  }
+
+  @protected
+  TimelapseSummary dco_decode_timelapse_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return TimelapseSummary(
+      framesSaved: dco_decode_u_32(arr[0]),
+      framesWithoutPerson: dco_decode_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  RustStreamSink<ClipProgress> sse_decode_StreamSink_clip_progress_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<FrameSlot> sse_decode_StreamSink_frame_slot_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<LogEntry> sse_decode_StreamSink_log_entry_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<PipelineStats> sse_decode_StreamSink_pipeline_stats_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<ProcessedFrame> sse_decode_StreamSink_processed_frame_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  BurstOptions sse_decode_box_autoadd_burst_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_burst_options(deserializer));
+  }
+
+  @protected
+  ClipOptions sse_decode_box_autoadd_clip_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_clip_options(deserializer));
+  }
+
+  @protected
+  DatasetOptions sse_decode_box_autoadd_dataset_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_dataset_options(deserializer));
+  }
+
+  @protected
+  FrameAllocationStats sse_decode_box_autoadd_frame_allocation_stats(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_frame_allocation_stats(deserializer));
+  }
+
+  @protected
+  ImageEffects sse_decode_box_autoadd_image_effects(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_image_effects(deserializer));
+  }
+
+  @protected
+  PipelineStats sse_decode_box_autoadd_pipeline_stats(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_pipeline_stats(deserializer));
+  }
+
+  @protected
+  RecordingOptions sse_decode_box_autoadd_recording_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_recording_options(deserializer));
+  }
+
+  @protected
+  ReplayOptions sse_decode_box_autoadd_replay_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_replay_options(deserializer));
+  }
+
+  @protected
+  ReplaySaveOptions sse_decode_box_autoadd_replay_save_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_replay_save_options(deserializer));
+  }
+
+  @protected
+  SnapshotOptions sse_decode_box_autoadd_snapshot_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_snapshot_options(deserializer));
+  }
+
+  @protected
+  TimelapseOptions sse_decode_box_autoadd_timelapse_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_timelapse_options(deserializer));
+  }
+
+  @protected
+  BurstFrame sse_decode_burst_frame(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_width = sse_decode_u_32(deserializer);
+    var var_height = sse_decode_u_32(deserializer);
+    var var_image = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_timestampUs = sse_decode_u_64(deserializer);
+    var var_sharpness = sse_decode_f_64(deserializer);
+    var var_exposure = sse_decode_f_64(deserializer);
+    var var_maskQuality = sse_decode_f_64(deserializer);
+    var var_score = sse_decode_f_64(deserializer);
+    return BurstFrame(
+      width: var_width,
+      height: var_height,
+      image: var_image,
+      timestampUs: var_timestampUs,
+      sharpness: var_sharpness,
+      exposure: var_exposure,
+      maskQuality: var_maskQuality,
+      score: var_score,
+    );
+  }
+
+  @protected
+  BurstOptions sse_decode_burst_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_frames = sse_decode_u_32(deserializer);
+    var var_framesBefore = sse_decode_u_32(deserializer);
+    var var_format = sse_decode_snapshot_format(deserializer);
+    var var_quality = sse_decode_u_8(deserializer);
+    var var_returnAll = sse_decode_bool(deserializer);
+    return BurstOptions(
+      frames: var_frames,
+      framesBefore: var_framesBefore,
+      format: var_format,
+      quality: var_quality,
+      returnAll: var_returnAll,
+    );
+  }
+
+  @protected
+  Cameras sse_decode_cameras(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    return Cameras(id: var_id, name: var_name);
+  }
+
+  @protected
+  ClipFormat sse_decode_clip_format(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ClipFormat.values[inner];
+  }
+
+  @protected
+  ClipOptions sse_decode_clip_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_format = sse_decode_clip_format(deserializer);
+    var var_seconds = sse_decode_f_64(deserializer);
+    var var_fps = sse_decode_u_32(deserializer);
+    var var_width = sse_decode_u_32(deserializer);
+    var var_quality = sse_decode_u_8(deserializer);
+    return ClipOptions(
+      path: var_path,
+      format: var_format,
+      seconds: var_seconds,
+      fps: var_fps,
+      width: var_width,
+      quality: var_quality,
+    );
+  }
+
+  @protected
+  ClipProgress sse_decode_clip_progress(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_stage = sse_decode_clip_stage(deserializer);
+    var var_fraction = sse_decode_f_64(deserializer);
+    var var_frames = sse_decode_u_32(deserializer);
+    var var_bytes = sse_decode_u_64(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return ClipProgress(
+      stage: var_stage,
+      fraction: var_fraction,
+      frames: var_frames,
+      bytes: var_bytes,
+      error: var_error,
+    );
+  }
+
+  @protected
+  ClipStage sse_decode_clip_stage(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ClipStage.values[inner];
+  }
+
+  @protected
+  DatasetOptions sse_decode_dataset_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_directory = sse_decode_String(deserializer);
+    var var_everyFrames = sse_decode_u_32(deserializer);
+    var var_maxConfidence = sse_decode_f_32(deserializer);
+    var var_lowConfidenceSpacing = sse_decode_u_32(deserializer);
+    var var_maxFrames = sse_decode_u_32(deserializer);
+    return DatasetOptions(
+      directory: var_directory,
+      everyFrames: var_everyFrames,
+      maxConfidence: var_maxConfidence,
+      lowConfidenceSpacing: var_lowConfidenceSpacing,
+      maxFrames: var_maxFrames,
+    );
+  }
+
+  @protected
+  DatasetSummary sse_decode_dataset_summary(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_framesSaved = sse_decode_u_32(deserializer);
+    var var_intervalFrames = sse_decode_u_32(deserializer);
+    var var_lowConfidenceFrames = sse_decode_u_32(deserializer);
+    var var_flaggedFrames = sse_decode_u_32(deserializer);
+    var var_framesDropped = sse_decode_u_32(deserializer);
+    return DatasetSummary(
+      framesSaved: var_framesSaved,
+      intervalFrames: var_intervalFrames,
+      lowConfidenceFrames: var_lowConfidenceFrames,
+      flaggedFrames: var_flaggedFrames,
+      framesDropped: var_framesDropped,
+    );
+  }
+
+  @protected
+  double sse_decode_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat32();
+  }
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat64();
+  }
+
+  @protected
+  FrameAllocationStats sse_decode_frame_allocation_stats(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_frames = sse_decode_u_64(deserializer);
+    var var_allocations = sse_decode_u_64(deserializer);
+    var var_lastFrameAllocations = sse_decode_u_64(deserializer);
+    return FrameAllocationStats(
+      frames: var_frames,
+      allocations: var_allocations,
+      lastFrameAllocations: var_lastFrameAllocations,
+    );
+  }
+
+  @protected
+  FrameEffects sse_decode_frame_effects(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_blur = sse_decode_bool(deserializer);
+    var var_backgroundReplacement = sse_decode_bool(deserializer);
+    var var_maskOverlay = sse_decode_bool(deserializer);
+    var var_roi = sse_decode_bool(deserializer);
+    return FrameEffects(
+      blur: var_blur,
+      backgroundReplacement: var_backgroundReplacement,
+      maskOverlay: var_maskOverlay,
+      roi: var_roi,
+    );
+  }
+
+  @protected
+  FrameInfo sse_decode_frame_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_width = sse_decode_u_32(deserializer);
+    var var_height = sse_decode_u_32(deserializer);
+    var var_stride = sse_decode_u_32(deserializer);
+    var var_pixelFormat = sse_decode_pixel_format(deserializer);
+    var var_captureTimestampUs = sse_decode_u_64(deserializer);
+    var var_sequence = sse_decode_u_64(deserializer);
+    var var_latencyUs = sse_decode_u_64(deserializer);
+    var var_effects = sse_decode_frame_effects(deserializer);
+    var var_qualityLevel = sse_decode_u_32(deserializer);
+    return FrameInfo(
+      width: var_width,
+      height: var_height,
+      stride: var_stride,
+      pixelFormat: var_pixelFormat,
+      captureTimestampUs: var_captureTimestampUs,
+      sequence: var_sequence,
+      latencyUs: var_latencyUs,
+      effects: var_effects,
+      qualityLevel: var_qualityLevel,
+    );
+  }
+
+  @protected
+  FrameSlot sse_decode_frame_slot(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_generation = sse_decode_u_64(deserializer);
+    var var_index = sse_decode_u_32(deserializer);
+    var var_address = sse_decode_u_64(deserializer);
+    var var_len = sse_decode_u_32(deserializer);
+    var var_info = sse_decode_frame_info(deserializer);
+    return FrameSlot(
+      generation: var_generation,
+      index: var_index,
+      address: var_address,
+      len: var_len,
+      info: var_info,
+    );
+  }
+
+  @protected
+  ImageEffects sse_decode_image_effects(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_blur = sse_decode_bool(deserializer);
+    var var_background = sse_decode_opt_list_prim_u_8_strict(deserializer);
+    var var_debug = sse_decode_bool(deserializer);
+    return ImageEffects(
+      blur: var_blur,
+      background: var_background,
+      debug: var_debug,
+    );
+  }
+
+  @protected
+  List<BurstFrame> sse_decode_list_burst_frame(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <BurstFrame>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_burst_frame(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Cameras> sse_decode_list_cameras(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Cameras>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_cameras(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
 
   @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
+  LogEntry sse_decode_log_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_level = sse_decode_log_level(deserializer);
+    var var_target = sse_decode_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_timestampUs = sse_decode_u_64(deserializer);
+    return LogEntry(
+      level: var_level,
+      target: var_target,
+      message: var_message,
+      timestampUs: var_timestampUs,
+    );
   }
 
   @protected
-  RustStreamSink<Uint8List> dco_decode_StreamSink_list_prim_u_8_strict_Sse(
-    dynamic raw,
+  LogLevel sse_decode_log_level(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return LogLevel.values[inner];
+  }
+
+  @protected
+  MaskBenchmark sse_decode_mask_benchmark(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_referencePreprocessUs = sse_decode_u_64(deserializer);
+    var var_referencePostprocessUs = sse_decode_u_64(deserializer);
+    var var_fusedPreprocessUs = sse_decode_u_64(deserializer);
+    var var_fusedPostprocessUs = sse_decode_u_64(deserializer);
+    return MaskBenchmark(
+      referencePreprocessUs: var_referencePreprocessUs,
+      referencePostprocessUs: var_referencePostprocessUs,
+      fusedPreprocessUs: var_fusedPreprocessUs,
+      fusedPostprocessUs: var_fusedPostprocessUs,
+    );
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  FrameAllocationStats? sse_decode_opt_box_autoadd_frame_allocation_stats(
+    SseDeserializer deserializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_frame_allocation_stats(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
+  PipelineStats? sse_decode_opt_box_autoadd_pipeline_stats(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_pipeline_stats(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
+  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_prim_u_8_strict(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PipelineStats sse_decode_pipeline_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_capturedFrames = sse_decode_u_64(deserializer);
+    var var_processedFrames = sse_decode_u_64(deserializer);
+    var var_droppedFrames = sse_decode_u_64(deserializer);
+    var var_captureFps = sse_decode_f_64(deserializer);
+    var var_outputFps = sse_decode_f_64(deserializer);
+    var var_decode = sse_decode_stage_stats(deserializer);
+    var var_preprocess = sse_decode_stage_stats(deserializer);
+    var var_inference = sse_decode_stage_stats(deserializer);
+    var var_postprocess = sse_decode_stage_stats(deserializer);
+    var var_composite = sse_decode_stage_stats(deserializer);
+    var var_encode = sse_decode_stage_stats(deserializer);
+    var var_sink = sse_decode_stage_stats(deserializer);
+    var var_endToEnd = sse_decode_stage_stats(deserializer);
+    return PipelineStats(
+      capturedFrames: var_capturedFrames,
+      processedFrames: var_processedFrames,
+      droppedFrames: var_droppedFrames,
+      captureFps: var_captureFps,
+      outputFps: var_outputFps,
+      decode: var_decode,
+      preprocess: var_preprocess,
+      inference: var_inference,
+      postprocess: var_postprocess,
+      composite: var_composite,
+      encode: var_encode,
+      sink: var_sink,
+      endToEnd: var_endToEnd,
+    );
+  }
+
+  @protected
+  PixelFormat sse_decode_pixel_format(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PixelFormat.values[inner];
+  }
+
+  @protected
+  PowerMode sse_decode_power_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PowerMode.values[inner];
+  }
+
+  @protected
+  ProcessedFrame sse_decode_processed_frame(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_info = sse_decode_frame_info(deserializer);
+    var var_pixels = sse_decode_list_prim_u_8_strict(deserializer);
+    return ProcessedFrame(info: var_info, pixels: var_pixels);
+  }
+
+  @protected
+  RecordingFormat sse_decode_recording_format(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return RecordingFormat.values[inner];
+  }
+
+  @protected
+  RecordingOptions sse_decode_recording_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_format = sse_decode_recording_format(deserializer);
+    var var_fps = sse_decode_u_32(deserializer);
+    var var_quality = sse_decode_u_8(deserializer);
+    var var_queueDepth = sse_decode_u_32(deserializer);
+    return RecordingOptions(
+      path: var_path,
+      format: var_format,
+      fps: var_fps,
+      quality: var_quality,
+      queueDepth: var_queueDepth,
+    );
+  }
+
+  @protected
+  RecordingSummary sse_decode_recording_summary(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_framesWritten = sse_decode_u_64(deserializer);
+    var var_framesDropped = sse_decode_u_64(deserializer);
+    var var_durationUs = sse_decode_u_64(deserializer);
+    return RecordingSummary(
+      framesWritten: var_framesWritten,
+      framesDropped: var_framesDropped,
+      durationUs: var_durationUs,
+    );
+  }
+
+  @protected
+  ReplayFormat sse_decode_replay_format(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ReplayFormat.values[inner];
+  }
+
+  @protected
+  ReplayOptions sse_decode_replay_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_seconds = sse_decode_u_32(deserializer);
+    var var_maxMemoryMb = sse_decode_u_32(deserializer);
+    var var_jpegQuality = sse_decode_u_8(deserializer);
+    return ReplayOptions(
+      seconds: var_seconds,
+      maxMemoryMb: var_maxMemoryMb,
+      jpegQuality: var_jpegQuality,
+    );
+  }
+
+  @protected
+  ReplaySaveOptions sse_decode_replay_save_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_format = sse_decode_replay_format(deserializer);
+    var var_seconds = sse_decode_u_32(deserializer);
+    var var_fps = sse_decode_u_32(deserializer);
+    var var_quality = sse_decode_u_8(deserializer);
+    return ReplaySaveOptions(
+      path: var_path,
+      format: var_format,
+      seconds: var_seconds,
+      fps: var_fps,
+      quality: var_quality,
+    );
+  }
+
+  @protected
+  SessionCaptureSummary sse_decode_session_capture_summary(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_frames = sse_decode_u_64(deserializer);
+    var var_settingsChanges = sse_decode_u_64(deserializer);
+    var var_bytes = sse_decode_u_64(deserializer);
+    return SessionCaptureSummary(
+      frames: var_frames,
+      settingsChanges: var_settingsChanges,
+      bytes: var_bytes,
+    );
+  }
+
+  @protected
+  Snapshot sse_decode_snapshot(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_width = sse_decode_u_32(deserializer);
+    var var_height = sse_decode_u_32(deserializer);
+    var var_image = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_raw = sse_decode_opt_list_prim_u_8_strict(deserializer);
+    var var_mask = sse_decode_opt_list_prim_u_8_strict(deserializer);
+    var var_effects = sse_decode_frame_effects(deserializer);
+    return Snapshot(
+      width: var_width,
+      height: var_height,
+      image: var_image,
+      raw: var_raw,
+      mask: var_mask,
+      effects: var_effects,
+    );
+  }
+
+  @protected
+  SnapshotFormat sse_decode_snapshot_format(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SnapshotFormat.values[inner];
+  }
+
+  @protected
+  SnapshotOptions sse_decode_snapshot_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_format = sse_decode_snapshot_format(deserializer);
+    var var_quality = sse_decode_u_8(deserializer);
+    var var_includeRaw = sse_decode_bool(deserializer);
+    var var_includeMask = sse_decode_bool(deserializer);
+    var var_fullResolution = sse_decode_bool(deserializer);
+    return SnapshotOptions(
+      format: var_format,
+      quality: var_quality,
+      includeRaw: var_includeRaw,
+      includeMask: var_includeMask,
+      fullResolution: var_fullResolution,
+    );
+  }
+
+  @protected
+  StageStats sse_decode_stage_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_lastUs = sse_decode_u_64(deserializer);
+    var var_meanUs = sse_decode_u_64(deserializer);
+    var var_p50Us = sse_decode_u_64(deserializer);
+    var var_p95Us = sse_decode_u_64(deserializer);
+    var var_p99Us = sse_decode_u_64(deserializer);
+    var var_maxUs = sse_decode_u_64(deserializer);
+    return StageStats(
+      lastUs: var_lastUs,
+      meanUs: var_meanUs,
+      p50Us: var_p50Us,
+      p95Us: var_p95Us,
+      p99Us: var_p99Us,
+      maxUs: var_maxUs,
+    );
+  }
+
+  @protected
+  TimelapseOptions sse_decode_timelapse_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_directory = sse_decode_String(deserializer);
+    var var_intervalMs = sse_decode_u_64(deserializer);
+    var var_everyFrames = sse_decode_u_32(deserializer);
+    var var_format = sse_decode_snapshot_format(deserializer);
+    var var_quality = sse_decode_u_8(deserializer);
+    var var_pauseWithoutPerson = sse_decode_bool(deserializer);
+    var var_maxFrames = sse_decode_u_32(deserializer);
+    return TimelapseOptions(
+      directory: var_directory,
+      intervalMs: var_intervalMs,
+      everyFrames: var_everyFrames,
+      format: var_format,
+      quality: var_quality,
+      pauseWithoutPerson: var_pauseWithoutPerson,
+      maxFrames: var_maxFrames,
+    );
+  }
+
+  @protected
+  TimelapseSummary sse_decode_timelapse_summary(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_framesSaved = sse_decode_u_32(deserializer);
+    var var_framesWithoutPerson = sse_decode_u_64(deserializer);
+    return TimelapseSummary(
+      framesSaved: var_framesSaved,
+      framesWithoutPerson: var_framesWithoutPerson,
+    );
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
+  }
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void sse_encode_StreamSink_clip_progress_Sse(
+    RustStreamSink<ClipProgress> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_clip_progress,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_frame_slot_Sse(
+    RustStreamSink<FrameSlot> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_frame_slot,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_log_entry_Sse(
+    RustStreamSink<LogEntry> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_log_entry,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_pipeline_stats_Sse(
+    RustStreamSink<PipelineStats> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_pipeline_stats,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_processed_frame_Sse(
+    RustStreamSink<ProcessedFrame> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_processed_frame,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+  }
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_burst_options(
+    BurstOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_burst_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_clip_options(
+    ClipOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_clip_options(self, serializer);
   }
 
   @protected
-  Cameras dco_decode_cameras(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return Cameras(
-      id: dco_decode_String(arr[0]),
-      name: dco_decode_String(arr[1]),
-    );
+  void sse_encode_box_autoadd_dataset_options(
+    DatasetOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_dataset_options(self, serializer);
   }
 
   @protected
-  List<Cameras> dco_decode_list_cameras(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_cameras).toList();
+  void sse_encode_box_autoadd_frame_allocation_stats(
+    FrameAllocationStats self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_frame_allocation_stats(self, serializer);
   }
 
   @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as List<int>;
+  void sse_encode_box_autoadd_image_effects(
+    ImageEffects self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_image_effects(self, serializer);
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  void sse_encode_box_autoadd_pipeline_stats(
+    PipelineStats self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_pipeline_stats(self, serializer);
   }
 
   @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_box_autoadd_recording_options(
+    RecordingOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_recording_options(self, serializer);
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_box_autoadd_replay_options(
+    ReplayOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_replay_options(self, serializer);
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void sse_encode_box_autoadd_replay_save_options(
+    ReplaySaveOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_replay_save_options(self, serializer);
   }
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_snapshot_options(
+    SnapshotOptions self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
+    sse_encode_snapshot_options(self, serializer);
   }
 
   @protected
-  RustStreamSink<Uint8List> sse_decode_StreamSink_list_prim_u_8_strict_Sse(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_timelapse_options(
+    TimelapseOptions self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    throw UnimplementedError('Unreachable ()');
+    sse_encode_timelapse_options(self, serializer);
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_burst_frame(BurstFrame self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    sse_encode_u_32(self.width, serializer);
+    sse_encode_u_32(self.height, serializer);
+    sse_encode_list_prim_u_8_strict(self.image, serializer);
+    sse_encode_u_64(self.timestampUs, serializer);
+    sse_encode_f_64(self.sharpness, serializer);
+    sse_encode_f_64(self.exposure, serializer);
+    sse_encode_f_64(self.maskQuality, serializer);
+    sse_encode_f_64(self.score, serializer);
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_burst_options(BurstOptions self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    sse_encode_u_32(self.frames, serializer);
+    sse_encode_u_32(self.framesBefore, serializer);
+    sse_encode_snapshot_format(self.format, serializer);
+    sse_encode_u_8(self.quality, serializer);
+    sse_encode_bool(self.returnAll, serializer);
   }
 
   @protected
-  Cameras sse_decode_cameras(SseDeserializer deserializer) {
+  void sse_encode_cameras(Cameras self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_String(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    return Cameras(id: var_id, name: var_name);
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.name, serializer);
   }
 
   @protected
-  List<Cameras> sse_decode_list_cameras(SseDeserializer deserializer) {
+  void sse_encode_clip_format(ClipFormat self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Cameras>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_cameras(deserializer));
-    }
-    return ans_;
+  @protected
+  void sse_encode_clip_options(ClipOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.path, serializer);
+    sse_encode_clip_format(self.format, serializer);
+    sse_encode_f_64(self.seconds, serializer);
+    sse_encode_u_32(self.fps, serializer);
+    sse_encode_u_32(self.width, serializer);
+    sse_encode_u_8(self.quality, serializer);
   }
 
   @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
+  void sse_encode_clip_progress(ClipProgress self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    sse_encode_clip_stage(self.stage, serializer);
+    sse_encode_f_64(self.fraction, serializer);
+    sse_encode_u_32(self.frames, serializer);
+    sse_encode_u_64(self.bytes, serializer);
+    sse_encode_opt_String(self.error, serializer);
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_clip_stage(ClipStage self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
+  void sse_encode_dataset_options(
+    DatasetOptions self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
+    sse_encode_String(self.directory, serializer);
+    sse_encode_u_32(self.everyFrames, serializer);
+    sse_encode_f_32(self.maxConfidence, serializer);
+    sse_encode_u_32(self.lowConfidenceSpacing, serializer);
+    sse_encode_u_32(self.maxFrames, serializer);
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_dataset_summary(
+    DatasetSummary self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+    sse_encode_u_32(self.framesSaved, serializer);
+    sse_encode_u_32(self.intervalFrames, serializer);
+    sse_encode_u_32(self.lowConfidenceFrames, serializer);
+    sse_encode_u_32(self.flaggedFrames, serializer);
+    sse_encode_u_32(self.framesDropped, serializer);
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_f_32(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putFloat32(self);
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_f_64(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    serializer.buffer.putFloat64(self);
   }
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
+  void sse_encode_frame_allocation_stats(
+    FrameAllocationStats self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
+    sse_encode_u_64(self.frames, serializer);
+    sse_encode_u_64(self.allocations, serializer);
+    sse_encode_u_64(self.lastFrameAllocations, serializer);
   }
 
   @protected
-  void sse_encode_StreamSink_list_prim_u_8_strict_Sse(
-    RustStreamSink<Uint8List> self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_frame_effects(FrameEffects self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(
-      self.setupAndSerialize(
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-      ),
-      serializer,
-    );
+    sse_encode_bool(self.blur, serializer);
+    sse_encode_bool(self.backgroundReplacement, serializer);
+    sse_encode_bool(self.maskOverlay, serializer);
+    sse_encode_bool(self.roi, serializer);
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_frame_info(FrameInfo self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+    sse_encode_u_32(self.width, serializer);
+    sse_encode_u_32(self.height, serializer);
+    sse_encode_u_32(self.stride, serializer);
+    sse_encode_pixel_format(self.pixelFormat, serializer);
+    sse_encode_u_64(self.captureTimestampUs, serializer);
+    sse_encode_u_64(self.sequence, serializer);
+    sse_encode_u_64(self.latencyUs, serializer);
+    sse_encode_frame_effects(self.effects, serializer);
+    sse_encode_u_32(self.qualityLevel, serializer);
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_frame_slot(FrameSlot self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_u_64(self.generation, serializer);
+    sse_encode_u_32(self.index, serializer);
+    sse_encode_u_64(self.address, serializer);
+    sse_encode_u_32(self.len, serializer);
+    sse_encode_frame_info(self.info, serializer);
   }
 
   @protected
-  void sse_encode_cameras(Cameras self, SseSerializer serializer) {
+  void sse_encode_image_effects(ImageEffects self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.id, serializer);
-    sse_encode_String(self.name, serializer);
+    sse_encode_bool(self.blur, serializer);
+    sse_encode_opt_list_prim_u_8_strict(self.background, serializer);
+    sse_encode_bool(self.debug, serializer);
+  }
+
+  @protected
+  void sse_encode_list_burst_frame(
+    List<BurstFrame> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_burst_frame(item, serializer);
+    }
   }
 
   @protected
@@ -587,12 +3485,267 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putUint8List(self);
   }
 
+  @protected
+  void sse_encode_log_entry(LogEntry self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_log_level(self.level, serializer);
+    sse_encode_String(self.target, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_u_64(self.timestampUs, serializer);
+  }
+
+  @protected
+  void sse_encode_log_level(LogLevel self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_mask_benchmark(MaskBenchmark self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.referencePreprocessUs, serializer);
+    sse_encode_u_64(self.referencePostprocessUs, serializer);
+    sse_encode_u_64(self.fusedPreprocessUs, serializer);
+    sse_encode_u_64(self.fusedPostprocessUs, serializer);
+  }
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_frame_allocation_stats(
+    FrameAllocationStats? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_frame_allocation_stats(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_pipeline_stats(
+    PipelineStats? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_pipeline_stats(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_list_prim_u_8_strict(
+    Uint8List? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_prim_u_8_strict(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_pipeline_stats(PipelineStats self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.capturedFrames, serializer);
+    sse_encode_u_64(self.processedFrames, serializer);
+    sse_encode_u_64(self.droppedFrames, serializer);
+    sse_encode_f_64(self.captureFps, serializer);
+    sse_encode_f_64(self.outputFps, serializer);
+    sse_encode_stage_stats(self.decode, serializer);
+    sse_encode_stage_stats(self.preprocess, serializer);
+    sse_encode_stage_stats(self.inference, serializer);
+    sse_encode_stage_stats(self.postprocess, serializer);
+    sse_encode_stage_stats(self.composite, serializer);
+    sse_encode_stage_stats(self.encode, serializer);
+    sse_encode_stage_stats(self.sink, serializer);
+    sse_encode_stage_stats(self.endToEnd, serializer);
+  }
+
+  @protected
+  void sse_encode_pixel_format(PixelFormat self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_power_mode(PowerMode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_processed_frame(
+    ProcessedFrame self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_frame_info(self.info, serializer);
+    sse_encode_list_prim_u_8_strict(self.pixels, serializer);
+  }
+
+  @protected
+  void sse_encode_recording_format(
+    RecordingFormat self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_recording_options(
+    RecordingOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.path, serializer);
+    sse_encode_recording_format(self.format, serializer);
+    sse_encode_u_32(self.fps, serializer);
+    sse_encode_u_8(self.quality, serializer);
+    sse_encode_u_32(self.queueDepth, serializer);
+  }
+
+  @protected
+  void sse_encode_recording_summary(
+    RecordingSummary self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.framesWritten, serializer);
+    sse_encode_u_64(self.framesDropped, serializer);
+    sse_encode_u_64(self.durationUs, serializer);
+  }
+
+  @protected
+  void sse_encode_replay_format(ReplayFormat self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_replay_options(ReplayOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.seconds, serializer);
+    sse_encode_u_32(self.maxMemoryMb, serializer);
+    sse_encode_u_8(self.jpegQuality, serializer);
+  }
+
+  @protected
+  void sse_encode_replay_save_options(
+    ReplaySaveOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.path, serializer);
+    sse_encode_replay_format(self.format, serializer);
+    sse_encode_u_32(self.seconds, serializer);
+    sse_encode_u_32(self.fps, serializer);
+    sse_encode_u_8(self.quality, serializer);
+  }
+
+  @protected
+  void sse_encode_session_capture_summary(
+    SessionCaptureSummary self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.frames, serializer);
+    sse_encode_u_64(self.settingsChanges, serializer);
+    sse_encode_u_64(self.bytes, serializer);
+  }
+
+  @protected
+  void sse_encode_snapshot(Snapshot self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.width, serializer);
+    sse_encode_u_32(self.height, serializer);
+    sse_encode_list_prim_u_8_strict(self.image, serializer);
+    sse_encode_opt_list_prim_u_8_strict(self.raw, serializer);
+    sse_encode_opt_list_prim_u_8_strict(self.mask, serializer);
+    sse_encode_frame_effects(self.effects, serializer);
+  }
+
+  @protected
+  void sse_encode_snapshot_format(
+    SnapshotFormat self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_snapshot_options(
+    SnapshotOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_snapshot_format(self.format, serializer);
+    sse_encode_u_8(self.quality, serializer);
+    sse_encode_bool(self.includeRaw, serializer);
+    sse_encode_bool(self.includeMask, serializer);
+    sse_encode_bool(self.fullResolution, serializer);
+  }
+
+  @protected
+  void sse_encode_stage_stats(StageStats self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.lastUs, serializer);
+    sse_encode_u_64(self.meanUs, serializer);
+    sse_encode_u_64(self.p50Us, serializer);
+    sse_encode_u_64(self.p95Us, serializer);
+    sse_encode_u_64(self.p99Us, serializer);
+    sse_encode_u_64(self.maxUs, serializer);
+  }
+
+  @protected
+  void sse_encode_timelapse_options(
+    TimelapseOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.directory, serializer);
+    sse_encode_u_64(self.intervalMs, serializer);
+    sse_encode_u_32(self.everyFrames, serializer);
+    sse_encode_snapshot_format(self.format, serializer);
+    sse_encode_u_8(self.quality, serializer);
+    sse_encode_bool(self.pauseWithoutPerson, serializer);
+    sse_encode_u_32(self.maxFrames, serializer);
+  }
+
+  @protected
+  void sse_encode_timelapse_summary(
+    TimelapseSummary self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.framesSaved, serializer);
+    sse_encode_u_64(self.framesWithoutPerson, serializer);
+  }
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     serializer.buffer.putUint32(self);
   }
 
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putBigUint64(self);
+  }
+
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
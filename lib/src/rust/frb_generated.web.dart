@@ -7,6 +7,10 @@
 // ignore_for_file: argument_type_not_assignable
 
 import 'api/camera.dart';
+import 'api/effects.dart';
+import 'api/logging.dart';
+import 'api/metrics.dart';
+import 'api/recording.dart';
 import 'api/simple.dart';
 import 'dart:async';
 import 'dart:convert';
@@ -25,7 +29,23 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   AnyhowException dco_decode_AnyhowException(dynamic raw);
 
   @protected
-  RustStreamSink<Uint8List> dco_decode_StreamSink_list_prim_u_8_strict_Sse(
+  RustStreamSink<ClipProgress> dco_decode_StreamSink_clip_progress_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<FrameSlot> dco_decode_StreamSink_frame_slot_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<LogEntry> dco_decode_StreamSink_log_entry_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<PipelineStats> dco_decode_StreamSink_pipeline_stats_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<ProcessedFrame> dco_decode_StreamSink_processed_frame_Sse(
     dynamic raw,
   );
 
@@ -35,9 +55,92 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   bool dco_decode_bool(dynamic raw);
 
+  @protected
+  BurstOptions dco_decode_box_autoadd_burst_options(dynamic raw);
+
+  @protected
+  ClipOptions dco_decode_box_autoadd_clip_options(dynamic raw);
+
+  @protected
+  DatasetOptions dco_decode_box_autoadd_dataset_options(dynamic raw);
+
+  @protected
+  FrameAllocationStats dco_decode_box_autoadd_frame_allocation_stats(
+    dynamic raw,
+  );
+
+  @protected
+  ImageEffects dco_decode_box_autoadd_image_effects(dynamic raw);
+
+  @protected
+  PipelineStats dco_decode_box_autoadd_pipeline_stats(dynamic raw);
+
+  @protected
+  RecordingOptions dco_decode_box_autoadd_recording_options(dynamic raw);
+
+  @protected
+  ReplayOptions dco_decode_box_autoadd_replay_options(dynamic raw);
+
+  @protected
+  ReplaySaveOptions dco_decode_box_autoadd_replay_save_options(dynamic raw);
+
+  @protected
+  SnapshotOptions dco_decode_box_autoadd_snapshot_options(dynamic raw);
+
+  @protected
+  TimelapseOptions dco_decode_box_autoadd_timelapse_options(dynamic raw);
+
+  @protected
+  BurstFrame dco_decode_burst_frame(dynamic raw);
+
+  @protected
+  BurstOptions dco_decode_burst_options(dynamic raw);
+
   @protected
   Cameras dco_decode_cameras(dynamic raw);
 
+  @protected
+  ClipFormat dco_decode_clip_format(dynamic raw);
+
+  @protected
+  ClipOptions dco_decode_clip_options(dynamic raw);
+
+  @protected
+  ClipProgress dco_decode_clip_progress(dynamic raw);
+
+  @protected
+  ClipStage dco_decode_clip_stage(dynamic raw);
+
+  @protected
+  DatasetOptions dco_decode_dataset_options(dynamic raw);
+
+  @protected
+  DatasetSummary dco_decode_dataset_summary(dynamic raw);
+
+  @protected
+  double dco_decode_f_32(dynamic raw);
+
+  @protected
+  double dco_decode_f_64(dynamic raw);
+
+  @protected
+  FrameAllocationStats dco_decode_frame_allocation_stats(dynamic raw);
+
+  @protected
+  FrameEffects dco_decode_frame_effects(dynamic raw);
+
+  @protected
+  FrameInfo dco_decode_frame_info(dynamic raw);
+
+  @protected
+  FrameSlot dco_decode_frame_slot(dynamic raw);
+
+  @protected
+  ImageEffects dco_decode_image_effects(dynamic raw);
+
+  @protected
+  List<BurstFrame> dco_decode_list_burst_frame(dynamic raw);
+
   @protected
   List<Cameras> dco_decode_list_cameras(dynamic raw);
 
@@ -47,9 +150,86 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
+  @protected
+  LogEntry dco_decode_log_entry(dynamic raw);
+
+  @protected
+  LogLevel dco_decode_log_level(dynamic raw);
+
+  @protected
+  MaskBenchmark dco_decode_mask_benchmark(dynamic raw);
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw);
+
+  @protected
+  FrameAllocationStats? dco_decode_opt_box_autoadd_frame_allocation_stats(
+    dynamic raw,
+  );
+
+  @protected
+  PipelineStats? dco_decode_opt_box_autoadd_pipeline_stats(dynamic raw);
+
+  @protected
+  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  PipelineStats dco_decode_pipeline_stats(dynamic raw);
+
+  @protected
+  PixelFormat dco_decode_pixel_format(dynamic raw);
+
+  @protected
+  PowerMode dco_decode_power_mode(dynamic raw);
+
+  @protected
+  ProcessedFrame dco_decode_processed_frame(dynamic raw);
+
+  @protected
+  RecordingFormat dco_decode_recording_format(dynamic raw);
+
+  @protected
+  RecordingOptions dco_decode_recording_options(dynamic raw);
+
+  @protected
+  RecordingSummary dco_decode_recording_summary(dynamic raw);
+
+  @protected
+  ReplayFormat dco_decode_replay_format(dynamic raw);
+
+  @protected
+  ReplayOptions dco_decode_replay_options(dynamic raw);
+
+  @protected
+  ReplaySaveOptions dco_decode_replay_save_options(dynamic raw);
+
+  @protected
+  SessionCaptureSummary dco_decode_session_capture_summary(dynamic raw);
+
+  @protected
+  Snapshot dco_decode_snapshot(dynamic raw);
+
+  @protected
+  SnapshotFormat dco_decode_snapshot_format(dynamic raw);
+
+  @protected
+  SnapshotOptions dco_decode_snapshot_options(dynamic raw);
+
+  @protected
+  StageStats dco_decode_stage_stats(dynamic raw);
+
+  @protected
+  TimelapseOptions dco_decode_timelapse_options(dynamic raw);
+
+  @protected
+  TimelapseSummary dco_decode_timelapse_summary(dynamic raw);
+
   @protected
   int dco_decode_u_32(dynamic raw);
 
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
   @protected
   int dco_decode_u_8(dynamic raw);
 
@@ -60,7 +240,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
 
   @protected
-  RustStreamSink<Uint8List> sse_decode_StreamSink_list_prim_u_8_strict_Sse(
+  RustStreamSink<ClipProgress> sse_decode_StreamSink_clip_progress_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<FrameSlot> sse_decode_StreamSink_frame_slot_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<LogEntry> sse_decode_StreamSink_log_entry_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<PipelineStats> sse_decode_StreamSink_pipeline_stats_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<ProcessedFrame> sse_decode_StreamSink_processed_frame_Sse(
     SseDeserializer deserializer,
   );
 
@@ -70,9 +270,112 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   bool sse_decode_bool(SseDeserializer deserializer);
 
+  @protected
+  BurstOptions sse_decode_box_autoadd_burst_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ClipOptions sse_decode_box_autoadd_clip_options(SseDeserializer deserializer);
+
+  @protected
+  DatasetOptions sse_decode_box_autoadd_dataset_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FrameAllocationStats sse_decode_box_autoadd_frame_allocation_stats(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ImageEffects sse_decode_box_autoadd_image_effects(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PipelineStats sse_decode_box_autoadd_pipeline_stats(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RecordingOptions sse_decode_box_autoadd_recording_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ReplayOptions sse_decode_box_autoadd_replay_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ReplaySaveOptions sse_decode_box_autoadd_replay_save_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SnapshotOptions sse_decode_box_autoadd_snapshot_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TimelapseOptions sse_decode_box_autoadd_timelapse_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  BurstFrame sse_decode_burst_frame(SseDeserializer deserializer);
+
+  @protected
+  BurstOptions sse_decode_burst_options(SseDeserializer deserializer);
+
   @protected
   Cameras sse_decode_cameras(SseDeserializer deserializer);
 
+  @protected
+  ClipFormat sse_decode_clip_format(SseDeserializer deserializer);
+
+  @protected
+  ClipOptions sse_decode_clip_options(SseDeserializer deserializer);
+
+  @protected
+  ClipProgress sse_decode_clip_progress(SseDeserializer deserializer);
+
+  @protected
+  ClipStage sse_decode_clip_stage(SseDeserializer deserializer);
+
+  @protected
+  DatasetOptions sse_decode_dataset_options(SseDeserializer deserializer);
+
+  @protected
+  DatasetSummary sse_decode_dataset_summary(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_32(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer);
+
+  @protected
+  FrameAllocationStats sse_decode_frame_allocation_stats(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FrameEffects sse_decode_frame_effects(SseDeserializer deserializer);
+
+  @protected
+  FrameInfo sse_decode_frame_info(SseDeserializer deserializer);
+
+  @protected
+  FrameSlot sse_decode_frame_slot(SseDeserializer deserializer);
+
+  @protected
+  ImageEffects sse_decode_image_effects(SseDeserializer deserializer);
+
+  @protected
+  List<BurstFrame> sse_decode_list_burst_frame(SseDeserializer deserializer);
+
   @protected
   List<Cameras> sse_decode_list_cameras(SseDeserializer deserializer);
 
@@ -82,9 +385,92 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
+  @protected
+  LogEntry sse_decode_log_entry(SseDeserializer deserializer);
+
+  @protected
+  LogLevel sse_decode_log_level(SseDeserializer deserializer);
+
+  @protected
+  MaskBenchmark sse_decode_mask_benchmark(SseDeserializer deserializer);
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  FrameAllocationStats? sse_decode_opt_box_autoadd_frame_allocation_stats(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PipelineStats? sse_decode_opt_box_autoadd_pipeline_stats(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  PipelineStats sse_decode_pipeline_stats(SseDeserializer deserializer);
+
+  @protected
+  PixelFormat sse_decode_pixel_format(SseDeserializer deserializer);
+
+  @protected
+  PowerMode sse_decode_power_mode(SseDeserializer deserializer);
+
+  @protected
+  ProcessedFrame sse_decode_processed_frame(SseDeserializer deserializer);
+
+  @protected
+  RecordingFormat sse_decode_recording_format(SseDeserializer deserializer);
+
+  @protected
+  RecordingOptions sse_decode_recording_options(SseDeserializer deserializer);
+
+  @protected
+  RecordingSummary sse_decode_recording_summary(SseDeserializer deserializer);
+
+  @protected
+  ReplayFormat sse_decode_replay_format(SseDeserializer deserializer);
+
+  @protected
+  ReplayOptions sse_decode_replay_options(SseDeserializer deserializer);
+
+  @protected
+  ReplaySaveOptions sse_decode_replay_save_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SessionCaptureSummary sse_decode_session_capture_summary(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Snapshot sse_decode_snapshot(SseDeserializer deserializer);
+
+  @protected
+  SnapshotFormat sse_decode_snapshot_format(SseDeserializer deserializer);
+
+  @protected
+  SnapshotOptions sse_decode_snapshot_options(SseDeserializer deserializer);
+
+  @protected
+  StageStats sse_decode_stage_stats(SseDeserializer deserializer);
+
+  @protected
+  TimelapseOptions sse_decode_timelapse_options(SseDeserializer deserializer);
+
+  @protected
+  TimelapseSummary sse_decode_timelapse_summary(SseDeserializer deserializer);
+
   @protected
   int sse_decode_u_32(SseDeserializer deserializer);
 
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
   @protected
   int sse_decode_u_8(SseDeserializer deserializer);
 
@@ -101,8 +487,32 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   );
 
   @protected
-  void sse_encode_StreamSink_list_prim_u_8_strict_Sse(
-    RustStreamSink<Uint8List> self,
+  void sse_encode_StreamSink_clip_progress_Sse(
+    RustStreamSink<ClipProgress> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_frame_slot_Sse(
+    RustStreamSink<FrameSlot> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_log_entry_Sse(
+    RustStreamSink<LogEntry> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_pipeline_stats_Sse(
+    RustStreamSink<PipelineStats> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_processed_frame_Sse(
+    RustStreamSink<ProcessedFrame> self,
     SseSerializer serializer,
   );
 
@@ -112,9 +522,135 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_burst_options(
+    BurstOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_clip_options(
+    ClipOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_dataset_options(
+    DatasetOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_frame_allocation_stats(
+    FrameAllocationStats self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_image_effects(
+    ImageEffects self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_pipeline_stats(
+    PipelineStats self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_recording_options(
+    RecordingOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_replay_options(
+    ReplayOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_replay_save_options(
+    ReplaySaveOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_snapshot_options(
+    SnapshotOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_timelapse_options(
+    TimelapseOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_burst_frame(BurstFrame self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_burst_options(BurstOptions self, SseSerializer serializer);
+
   @protected
   void sse_encode_cameras(Cameras self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_clip_format(ClipFormat self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_clip_options(ClipOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_clip_progress(ClipProgress self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_clip_stage(ClipStage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_dataset_options(
+    DatasetOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_dataset_summary(
+    DatasetSummary self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_f_32(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_f_64(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_frame_allocation_stats(
+    FrameAllocationStats self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_frame_effects(FrameEffects self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_frame_info(FrameInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_frame_slot(FrameSlot self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_image_effects(ImageEffects self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_burst_frame(
+    List<BurstFrame> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_cameras(List<Cameras> self, SseSerializer serializer);
 
@@ -127,9 +663,123 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_log_entry(LogEntry self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_log_level(LogLevel self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_mask_benchmark(MaskBenchmark self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_frame_allocation_stats(
+    FrameAllocationStats? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_pipeline_stats(
+    PipelineStats? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_list_prim_u_8_strict(
+    Uint8List? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_pipeline_stats(PipelineStats self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_pixel_format(PixelFormat self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_power_mode(PowerMode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_processed_frame(
+    ProcessedFrame self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_recording_format(
+    RecordingFormat self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_recording_options(
+    RecordingOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_recording_summary(
+    RecordingSummary self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_replay_format(ReplayFormat self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_replay_options(ReplayOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_replay_save_options(
+    ReplaySaveOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_session_capture_summary(
+    SessionCaptureSummary self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_snapshot(Snapshot self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_snapshot_format(
+    SnapshotFormat self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_snapshot_options(
+    SnapshotOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_stage_stats(StageStats self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_timelapse_options(
+    TimelapseOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_timelapse_summary(
+    TimelapseSummary self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer);
 
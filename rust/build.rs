@@ -9,4 +9,48 @@ fn main() {
         .half_precision(false)
         .record_type(burn_import::onnx::RecordType::Bincode) // ← Utiliser Bincode au lieu de NamedMpk
         .run_from_script();
+
+    // Conventions of the model, see `ModelDescriptor`. Fields left out keep the values of the
+    // bundled selfie segmentation model.
+    println!("cargo:rerun-if-env-changed=ONNX_MODEL_DESCRIPTOR");
+    let descriptor = match std::env::var("ONNX_MODEL_DESCRIPTOR") {
+        Ok(path) => {
+            println!("cargo:rerun-if-changed={path}");
+            std::fs::read_to_string(path).unwrap()
+        }
+        Err(_) => "{}".to_string(),
+    };
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    std::fs::write(format!("{out_dir}/model/descriptor.json"), descriptor).unwrap();
+
+    // Optional lighter model, run by the cheapest quality levels and the low power mode.
+    // Its descriptor usually gives a smaller input size.
+    println!("cargo:rerun-if-env-changed=ONNX_LITE_MODEL_PATH");
+    println!("cargo:rerun-if-env-changed=ONNX_LITE_MODEL_DESCRIPTOR");
+    if let Ok(file) = std::env::var("ONNX_LITE_MODEL_PATH") {
+        ModelGen::new()
+            .input(&file)
+            .out_dir("lite_model/")
+            .embed_states(true)
+            .half_precision(false)
+            .record_type(burn_import::onnx::RecordType::Bincode)
+            .run_from_script();
+        // Generated after the file name, renamed so `ml::lite_model` can include it.
+        let stem = std::path::Path::new(&file).file_stem().unwrap();
+        std::fs::rename(
+            format!("{out_dir}/lite_model/{}.rs", stem.to_string_lossy()),
+            format!("{out_dir}/lite_model/model.rs"),
+        )
+        .unwrap();
+
+        let descriptor = match std::env::var("ONNX_LITE_MODEL_DESCRIPTOR") {
+            Ok(path) => {
+                println!("cargo:rerun-if-changed={path}");
+                std::fs::read_to_string(path).unwrap()
+            }
+            Err(_) => "{}".to_string(),
+        };
+        std::fs::write(format!("{out_dir}/lite_model/descriptor.json"), descriptor).unwrap();
+        println!("cargo:rustc-cfg=lite_model");
+    }
 }
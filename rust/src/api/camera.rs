@@ -1,17 +1,38 @@
 use std::{
-    sync::{atomic::AtomicBool, Arc, Mutex, OnceLock},
+    sync::{
+        atomic::{AtomicBool, AtomicU32},
+        Arc, Mutex, OnceLock,
+    },
     thread,
+    time::Instant,
 };
 
-use image::ImageReader;
+use image::{ImageReader, RgbaImage};
 use nokhwa::{
     pixel_format::RgbAFormat,
     utils::{RequestedFormat, RequestedFormatType},
-    Buffer,
 };
 
-use crate::ml::image::{blur_background, show_mask_overlay, ImageSegmentation};
-use crate::{frb_generated::StreamSink, ml::image::replace_background};
+use crate::frb_generated::StreamSink;
+use crate::media::convert::OutputFormat;
+use crate::media::still::{decode_oriented, encode_rgba, StillFormat};
+use crate::ml::image::benchmark_mask_processing;
+use crate::ml::roi::mask_stats;
+use crate::ml::{composite, model};
+use crate::pipeline::archive::{self, FrameState, SessionCapture};
+use crate::pipeline::burst;
+use crate::pipeline::dataset::{self, DatasetFrame};
+use crate::pipeline::frame::{unix_micros, CapturedFrame};
+use crate::pipeline::handoff::{FrameQueue, Overflow, Pushed};
+use crate::pipeline::metrics::Stage;
+use crate::pipeline::pool::{FramePool, PooledFrame};
+use crate::pipeline::power::{self, FrameLimiter};
+use crate::pipeline::processor::{AppliedEffects, EffectSettings, FrameProcessor};
+use crate::pipeline::quality::QualityController;
+use crate::pipeline::recorder;
+use crate::pipeline::ring::{self, FrameRing};
+use crate::pipeline::session::{self, StreamSession};
+use crate::pipeline::snapshot::{SnapshotFrame, SnapshotRequest};
 
 #[derive(Debug)]
 pub struct Cameras {
@@ -21,15 +42,45 @@ pub struct Cameras {
 
 pub fn init_cams() {
     nokhwa::nokhwa_initialize(|x| {
-        println!("nokhwa initialized: {x}");
+        log::info!("nokhwa initialized: {x}");
     });
 }
 
+/// Load the segmentation models and run a first inference on them,
+/// so starting a stream doesn't stall on the first frames.
+pub fn preload_models() {
+    model::preload();
+}
+
+/// Average mask pre/post processing times in microseconds,
+/// for the previous image crate based path and the fused one.
+pub struct MaskBenchmark {
+    pub reference_preprocess_us: u64,
+    pub reference_postprocess_us: u64,
+    pub fused_preprocess_us: u64,
+    pub fused_postprocess_us: u64,
+}
+
+pub fn benchmark_mask(iterations: u32) -> MaskBenchmark {
+    let (reference, fused) = benchmark_mask_processing(iterations);
+    MaskBenchmark {
+        reference_preprocess_us: reference.preprocess.as_micros() as u64,
+        reference_postprocess_us: reference.postprocess.as_micros() as u64,
+        fused_preprocess_us: fused.preprocess.as_micros() as u64,
+        fused_postprocess_us: fused.postprocess.as_micros() as u64,
+    }
+}
+
+/// Number of threads used to composite frames, 0 for one per core.
+pub fn set_compositing_threads(threads: u32) {
+    composite::set_threads(threads as usize);
+}
+
 pub fn check_for_cameras() -> Vec<Cameras> {
     let mut cams: Vec<Cameras> = Vec::new();
     match nokhwa::query(nokhwa::native_api_backend().unwrap()) {
         Ok(cameras) => {
-            println!("Cameras: {cameras:?}");
+            log::debug!("Cameras: {cameras:?}");
             for (i, camera) in cameras.iter().enumerate() {
                 cams.push(Cameras {
                     id: i.to_string(),
@@ -37,168 +88,903 @@ pub fn check_for_cameras() -> Vec<Cameras> {
                 });
             }
         }
-        Err(e) => println!("Error: {e}"),
+        Err(e) => log::error!("Can't list cameras: {e}"),
     }
     cams
 }
 
+#[derive(Default)]
 struct CameraState {
     mask: Arc<AtomicBool>,
-    background: Arc<Mutex<Option<Vec<u8>>>>,
+    background: Arc<Mutex<Option<Arc<RgbaImage>>>>,
     debug: Arc<AtomicBool>,
+    roi: Arc<AtomicBool>,
+    refine: AtomicBool,
+    temporal: AtomicBool,
+    output_format: Mutex<OutputFormat>,
+    // Target of the adaptive quality, 0 when it is off.
+    adaptive_fps: AtomicU32,
+    frame_queue: Mutex<(usize, Overflow)>,
+}
+
+impl CameraState {
+    fn effect_settings(&self) -> EffectSettings {
+        EffectSettings {
+            blur: self.mask.load(std::sync::atomic::Ordering::Relaxed),
+            background: self.background.lock().unwrap().clone(),
+            debug: self.debug.load(std::sync::atomic::Ordering::Relaxed),
+            roi: self.roi.load(std::sync::atomic::Ordering::Relaxed),
+            refine: self.refine.load(std::sync::atomic::Ordering::Relaxed),
+            temporal: self.temporal.load(std::sync::atomic::Ordering::Relaxed),
+        }
+    }
+
+    fn output_format(&self) -> OutputFormat {
+        *self.output_format.lock().unwrap()
+    }
+
+    fn frame_queue(&self) -> FrameQueue {
+        let (depth, overflow) = *self.frame_queue.lock().unwrap();
+        FrameQueue::new(depth, overflow)
+    }
+
+    fn adaptive_fps(&self) -> u32 {
+        self.adaptive_fps.load(std::sync::atomic::Ordering::Relaxed)
+    }
 }
 
 static CAMERA_STATE: OnceLock<Arc<CameraState>> = OnceLock::new();
 
+fn camera_state() -> &'static Arc<CameraState> {
+    CAMERA_STATE.get_or_init(Default::default)
+}
+
 pub fn set_mask(mask: bool) {
-    let state = CAMERA_STATE.get_or_init(|| {
-        Arc::new(CameraState {
-            mask: Arc::new(AtomicBool::new(mask)),
-            background: Arc::new(Mutex::new(None)),
-            debug: Arc::new(AtomicBool::new(false)),
-        })
-    });
+    let state = camera_state();
     state.mask.store(mask, std::sync::atomic::Ordering::Relaxed);
     state.background.lock().unwrap().take();
 }
 
-pub fn set_background(background: Vec<u8>) {
-    let img = ImageReader::new(std::io::Cursor::new(&background))
-        .with_guessed_format()
-        .unwrap()
-        .decode()
-        .unwrap();
+/// Fails without changing the effects when the image can't be decoded.
+pub fn set_background(background: Vec<u8>) -> Result<(), std::io::Error> {
+    let reader = ImageReader::new(std::io::Cursor::new(&background)).with_guessed_format()?;
+    let img = decode_oriented(reader)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
 
-    let resized = img.resize_exact(640, 480, image::imageops::FilterType::Lanczos3);
-    let buf = resized.to_rgba8().into_raw();
+    // Resized to the frame size by the processing thread.
+    let buf = img.to_rgba8();
 
-    let state = CAMERA_STATE.get_or_init(|| {
-        Arc::new(CameraState {
-            mask: Arc::new(AtomicBool::new(false)),
-            background: Arc::new(Mutex::new(Some(buf.clone()))),
-            debug: Arc::new(AtomicBool::new(false)),
-        })
-    });
+    let state = camera_state();
     state
         .mask
         .store(false, std::sync::atomic::Ordering::Relaxed);
-    state.background.lock().unwrap().replace(buf);
+    state.background.lock().unwrap().replace(Arc::new(buf));
+    Ok(())
 }
 
 pub fn set_debug(debug: bool) {
-    let state = CAMERA_STATE.get_or_init(|| {
-        Arc::new(CameraState {
-            mask: Arc::new(AtomicBool::new(false)),
-            background: Arc::new(Mutex::new(None)),
-            debug: Arc::new(AtomicBool::new(debug)),
-        })
-    });
-    state
+    camera_state()
         .debug
         .store(debug, std::sync::atomic::Ordering::Relaxed);
 }
 
-pub fn stream_camera(id: u32, sink: StreamSink<Vec<u8>>) -> Result<(), std::io::Error> {
-    let latest_frame = Arc::new(Mutex::new(None::<Buffer>));
-    let frame_for_capture = latest_frame.clone();
+/// Run inference on a crop around the person instead of the whole frame.
+/// Gives a finer mask when the person is small in the frame.
+pub fn set_roi_mode(enabled: bool) {
+    camera_state()
+        .roi
+        .store(enabled, std::sync::atomic::Ordering::Relaxed);
+}
 
-    let should_run = Arc::new(AtomicBool::new(true));
-    let should_run_capture = should_run.clone();
+/// Smooth the mask edges, off by default. Costs a few milliseconds per frame at 640x480.
+pub fn set_mask_refinement(enabled: bool) {
+    camera_state()
+        .refine
+        .store(enabled, std::sync::atomic::Ordering::Relaxed);
+}
 
-    thread::spawn(move || {
-        let requested = RequestedFormat::new::<RgbAFormat>(RequestedFormatType::Closest(
-            nokhwa::utils::CameraFormat::new(
-                nokhwa::utils::Resolution::new(640, 480),
-                nokhwa::utils::FrameFormat::YUYV,
-                30,
-            ),
+/// Average the mask over the last frames, off by default.
+/// Steadies flickering edges, but the mask lags a little behind fast motion.
+pub fn set_temporal_smoothing(enabled: bool) {
+    camera_state()
+        .temporal
+        .store(enabled, std::sync::atomic::Ordering::Relaxed);
+}
+
+/// Whether the crate was built with a lite model, see `set_adaptive_quality`.
+pub fn has_lite_model() -> bool {
+    model::has_lite_model()
+}
+
+/// Format of the frames sent by the streams, encoded on the processing thread.
+/// `quality` goes from 1 to 100 and is only used by JPEG.
+pub fn set_output_format(format: PixelFormat, quality: u8) {
+    *camera_state().output_format.lock().unwrap() = format.output_format(quality);
+}
+
+/// Lower the quality when frames take longer to process than `target_fps` allows,
+/// and raise it back once there is headroom. The level in use is in `FrameInfo`.
+///
+/// Levels go from 0, full quality, to 4:
+/// 1 stops smoothing the mask edges when `set_mask_refinement` turned it on,
+/// 2 runs the segmentation every other frame with an approximated blur,
+/// 3 switches to the lite model, when built with one, and a single pass blur,
+/// and 4 runs the segmentation every third frame and sends frames at half resolution.
+pub fn set_adaptive_quality(enabled: bool, target_fps: u32) {
+    let target = if enabled { target_fps.max(1) } else { 0 };
+    camera_state()
+        .adaptive_fps
+        .store(target, std::sync::atomic::Ordering::Relaxed);
+}
+
+/// Frames waiting between capture and processing, for streams started afterwards.
+/// By default only the latest frame is kept, so a slow processing drops frames
+/// instead of adding latency. With `drop_frames` off, capture waits for room in
+/// the queue instead, for consumers like recording that need every frame.
+pub fn set_frame_queue(depth: u32, drop_frames: bool) {
+    let overflow = if drop_frames {
+        Overflow::DropOldest
+    } else {
+        Overflow::Block
+    };
+    *camera_state().frame_queue.lock().unwrap() = (depth.max(1) as usize, overflow);
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum PowerMode {
+    Normal,
+    /// At most 15 fps, and the mask is reused while the scene doesn't move.
+    /// Inference runs on the smaller input of the lite model, when the crate was built with
+    /// one, see `has_lite_model`. Otherwise the full model runs as in the normal mode.
+    LowPower,
+    /// Camera closed and stream threads asleep, for when the app window is hidden.
+    /// Streams pick up where they were when leaving this mode.
+    Idle,
+}
+
+impl From<PowerMode> for power::PowerMode {
+    fn from(mode: PowerMode) -> Self {
+        match mode {
+            PowerMode::Normal => power::PowerMode::Normal,
+            PowerMode::LowPower => power::PowerMode::LowPower,
+            PowerMode::Idle => power::PowerMode::Idle,
+        }
+    }
+}
+
+pub fn set_power_mode(mode: PowerMode) {
+    power::set_mode(mode.into());
+}
+
+/// Process at most `fps` frames per second, 0 to follow the camera.
+/// Frames over the limit are skipped before being decoded.
+pub fn set_target_fps(fps: u32) {
+    power::set_target_fps(fps);
+}
+
+pub struct FrameAllocationStats {
+    pub frames: u64,
+    pub allocations: u64,
+    pub last_frame_allocations: u64,
+}
+
+/// Frame buffers allocated by the stream of a camera, `None` before its first stream.
+/// Once running, `last_frame_allocations` should stay at 0. With `stream_camera` the
+/// pixels are still copied into the message sent to Dart, that copy belongs to the bridge.
+pub fn frame_allocation_stats(id: u32) -> Option<FrameAllocationStats> {
+    let stats = session::get(id)?.pool.stats();
+    Some(FrameAllocationStats {
+        frames: stats.frames,
+        allocations: stats.allocations,
+        last_frame_allocations: stats.last_frame_allocations,
+    })
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum PixelFormat {
+    Rgba8888,
+    Bgra8888,
+    Rgb888,
+    /// Y plane then interleaved UV at half resolution.
+    Nv12,
+    /// Y plane then U and V planes at half resolution.
+    I420,
+    Jpeg,
+    Png,
+}
+
+impl PixelFormat {
+    fn output_format(self, quality: u8) -> OutputFormat {
+        match self {
+            PixelFormat::Rgba8888 => OutputFormat::Rgba,
+            PixelFormat::Bgra8888 => OutputFormat::Bgra,
+            PixelFormat::Rgb888 => OutputFormat::Rgb,
+            PixelFormat::Nv12 => OutputFormat::Nv12,
+            PixelFormat::I420 => OutputFormat::I420,
+            PixelFormat::Jpeg => OutputFormat::Jpeg { quality },
+            PixelFormat::Png => OutputFormat::Png,
+        }
+    }
+}
+
+impl From<OutputFormat> for PixelFormat {
+    fn from(format: OutputFormat) -> Self {
+        match format {
+            OutputFormat::Rgba => PixelFormat::Rgba8888,
+            OutputFormat::Bgra => PixelFormat::Bgra8888,
+            OutputFormat::Rgb => PixelFormat::Rgb888,
+            OutputFormat::Nv12 => PixelFormat::Nv12,
+            OutputFormat::I420 => PixelFormat::I420,
+            OutputFormat::Jpeg { .. } => PixelFormat::Jpeg,
+            OutputFormat::Png => PixelFormat::Png,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct FrameEffects {
+    pub blur: bool,
+    pub background_replacement: bool,
+    pub mask_overlay: bool,
+    pub roi: bool,
+}
+
+impl From<AppliedEffects> for FrameEffects {
+    fn from(effects: AppliedEffects) -> Self {
+        FrameEffects {
+            blur: effects.blur,
+            background_replacement: effects.background_replacement,
+            mask_overlay: effects.mask_overlay,
+            roi: effects.roi,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct FrameInfo {
+    pub width: u32,
+    pub height: u32,
+    /// Bytes per row of the first plane, 0 for JPEG and PNG.
+    pub stride: u32,
+    pub pixel_format: PixelFormat,
+    /// Microseconds since the Unix epoch.
+    pub capture_timestamp_us: u64,
+    /// Increases by one for each frame out of the camera, gaps mean dropped
+    /// frames or frames skipped by the frame rate limit.
+    pub sequence: u64,
+    /// From capture to the frame being sent, in microseconds.
+    pub latency_us: u64,
+    pub effects: FrameEffects,
+    /// Adaptive quality level the frame was made with, 0 for full quality.
+    pub quality_level: u32,
+}
+
+pub struct ProcessedFrame {
+    pub info: FrameInfo,
+    pub pixels: Vec<u8>,
+}
+
+/// A processed frame left in shared memory, see `stream_camera_shared`.
+pub struct FrameSlot {
+    /// Ring the slot belongs to, to give back with `release_frame_slot`.
+    pub generation: u64,
+    pub index: u32,
+    /// Address of the pixels, to read in place with dart:ffi.
+    pub address: u64,
+    pub len: u32,
+    pub info: FrameInfo,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum SnapshotFormat {
+    Png,
+    Jpeg,
+    /// Lossless.
+    WebP,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct SnapshotOptions {
+    pub format: SnapshotFormat,
+    /// From 1 to 100, only used by JPEG.
+    pub quality: u8,
+    /// Also return the frame before effects.
+    pub include_raw: bool,
+    /// Also return the person mask as a grayscale image.
+    pub include_mask: bool,
+    /// Briefly switch the camera to its highest resolution for the snapshot.
+    /// The stream skips the frames meanwhile.
+    pub full_resolution: bool,
+}
+
+/// Images encoded in the requested format.
+pub struct Snapshot {
+    pub width: u32,
+    pub height: u32,
+    pub image: Vec<u8>,
+    pub raw: Option<Vec<u8>>,
+    pub mask: Option<Vec<u8>>,
+    pub effects: FrameEffects,
+}
+
+// How long `take_snapshot` waits for the stream, longer when the camera has to switch format.
+const SNAPSHOT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(3);
+const STILL_SNAPSHOT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// Take the next processed frame of the running stream on the camera, with the current effects.
+pub fn take_snapshot(id: u32, options: SnapshotOptions) -> Result<Snapshot, std::io::Error> {
+    let session = session::get(id)
+        .filter(|session| session.is_running())
+        .ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::NotFound, "No stream on this camera")
+        })?;
+
+    let (reply, images) = std::sync::mpsc::channel();
+    session.request_snapshot(SnapshotRequest {
+        format: still_format(options.format, options.quality),
+        raw: options.include_raw,
+        mask: options.include_mask,
+        full_resolution: options.full_resolution,
+        reply,
+    });
+
+    let timeout = if options.full_resolution {
+        STILL_SNAPSHOT_TIMEOUT
+    } else {
+        SNAPSHOT_TIMEOUT
+    };
+    let images = images
+        .recv_timeout(timeout)
+        .map_err(|_| std::io::Error::new(std::io::ErrorKind::TimedOut, "No frame from the stream"))?
+        .map_err(std::io::Error::other)?;
+    Ok(Snapshot {
+        width: images.width,
+        height: images.height,
+        image: images.image,
+        raw: images.raw,
+        mask: images.mask,
+        effects: images.effects.into(),
+    })
+}
+
+pub(crate) fn still_format(format: SnapshotFormat, quality: u8) -> StillFormat {
+    match format {
+        SnapshotFormat::Png => StillFormat::Png,
+        SnapshotFormat::Jpeg => StillFormat::Jpeg { quality },
+        SnapshotFormat::WebP => StillFormat::WebP,
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BurstOptions {
+    /// Frames in the burst, 0 for 8.
+    pub frames: u32,
+    /// How many of them are taken from before the call, out of the frames kept
+    /// by `set_burst_history`. The rest are the next frames of the stream.
+    pub frames_before: u32,
+    pub format: SnapshotFormat,
+    /// From 1 to 100, only used by JPEG.
+    pub quality: u8,
+    /// Return every frame best first, instead of only the best one.
+    pub return_all: bool,
+}
+
+/// A processed frame of a burst with its scores, all from 0 to 1.
+pub struct BurstFrame {
+    pub width: u32,
+    pub height: u32,
+    pub image: Vec<u8>,
+    /// Microseconds since the Unix epoch.
+    pub timestamp_us: u64,
+    /// Edge contrast relative to the sharpest frame of the burst, low for motion blur.
+    pub sharpness: f64,
+    /// High for a balanced brightness without clipped highlights or shadows.
+    pub exposure: f64,
+    /// How clearly the mask separates the person, 0 when nobody is found.
+    pub mask_quality: f64,
+    pub score: f64,
+}
+
+// How long `capture_burst` waits for the frames after the call.
+const BURST_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+// A second at 30 fps, about 250 MB of 1080p frames.
+const MAX_BURST_HISTORY: u32 = 30;
+
+/// Keep the last processed frames of the running stream on the camera, so bursts
+/// can start before they are requested. 0 stops keeping them, at most 30.
+/// Each kept frame is a full RGBA copy, and while frames are kept the mask is computed
+/// for every frame even when no effect needs it.
+pub fn set_burst_history(id: u32, frames: u32) -> Result<(), std::io::Error> {
+    let session = session::get(id).ok_or_else(|| {
+        std::io::Error::new(std::io::ErrorKind::NotFound, "No stream on this camera")
+    })?;
+    session
+        .taps
+        .set_history(frames.min(MAX_BURST_HISTORY) as usize);
+    Ok(())
+}
+
+/// Grab consecutive processed frames around now and rank them by sharpness,
+/// exposure and mask quality, best first.
+pub fn capture_burst(id: u32, options: BurstOptions) -> Result<Vec<BurstFrame>, std::io::Error> {
+    let session = session::get(id)
+        .filter(|session| session.is_running())
+        .ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::NotFound, "No stream on this camera")
+        })?;
+    let count = if options.frames == 0 {
+        8
+    } else {
+        options.frames as usize
+    };
+    let before = (options.frames_before as usize).min(count);
+    let (history, next) = session.taps.add_with_history(count - before, true, before);
+    let frames = burst::collect(history, next, count, BURST_TIMEOUT);
+    if frames.is_empty() {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::TimedOut,
+            "No frame from the stream",
         ));
+    }
+
+    let mut ranked = burst::rank(frames);
+    if !options.return_all {
+        ranked.truncate(1);
+    }
+    let format = still_format(options.format, options.quality);
+    ranked
+        .into_iter()
+        .map(|ranked| {
+            let frame = &ranked.frame;
+            let image = encode_rgba(&frame.pixels, frame.width, frame.height, format)
+                .map_err(std::io::Error::other)?;
+            Ok(BurstFrame {
+                width: frame.width,
+                height: frame.height,
+                image,
+                timestamp_us: frame.timestamp_us,
+                sharpness: ranked.scores.sharpness,
+                exposure: ranked.scores.exposure,
+                mask_quality: ranked.scores.mask,
+                score: ranked.scores.total,
+            })
+        })
+        .collect()
+}
+
+/// Give back a slot received from `stream_camera_shared` once it has been read.
+/// Frames are dropped while Dart holds every slot.
+pub fn release_frame_slot(generation: u64, index: u32) {
+    if let Some(ring) = ring::get(generation) {
+        ring.release(index as usize);
+    }
+}
+
+enum FrameDelivery {
+    Copy(StreamSink<ProcessedFrame>),
+    Shared(Arc<FrameRing>, StreamSink<FrameSlot>),
+}
+
+enum Delivered {
+    Sent,
+    /// Dart still holds every shared slot.
+    Dropped,
+    /// Dart closed the stream.
+    Closed,
+}
+
+impl FrameDelivery {
+    fn send(&self, mut frame: PooledFrame, info: FrameInfo) -> Delivered {
+        let sent = match self {
+            FrameDelivery::Copy(sink) => sink.add(ProcessedFrame {
+                info,
+                // Dart gets a copy, so the buffer can go back to the pool.
+                pixels: frame.to_vec(),
+            }),
+            FrameDelivery::Shared(ring, sink) => {
+                let Some(slot) = ring.publish(&mut frame) else {
+                    return Delivered::Dropped;
+                };
+                sink.add(FrameSlot {
+                    generation: slot.generation,
+                    index: slot.index as u32,
+                    address: slot.address as u64,
+                    len: slot.len as u32,
+                    info,
+                })
+            }
+        };
+        match sent {
+            Ok(()) => Delivered::Sent,
+            Err(_) => Delivered::Closed,
+        }
+    }
+}
+
+pub fn stream_camera(id: u32, sink: StreamSink<ProcessedFrame>) -> Result<(), std::io::Error> {
+    start_stream(id, FrameDelivery::Copy(sink))
+}
+
+/// Same as `stream_camera`, but frames stay in a ring of `slot_count` shared buffers
+/// and only their slot goes through the bridge. Native platforms only.
+/// Slots stay valid until released, even once the stream stopped.
+pub fn stream_camera_shared(
+    id: u32,
+    slot_count: u32,
+    sink: StreamSink<FrameSlot>,
+) -> Result<(), std::io::Error> {
+    let ring = ring::create(slot_count as usize);
+    start_stream(id, FrameDelivery::Shared(ring, sink))
+}
+
+fn stream_format() -> RequestedFormat<'static> {
+    RequestedFormat::new::<RgbAFormat>(RequestedFormatType::Closest(
+        nokhwa::utils::CameraFormat::new(
+            nokhwa::utils::Resolution::new(640, 480),
+            nokhwa::utils::FrameFormat::YUYV,
+            30,
+        ),
+    ))
+}
+
+// Frames read after switching to the still format, exposure needs a few to settle.
+const STILL_WARM_UP_FRAMES: usize = 3;
+
+// Switch the camera to its highest resolution and decode a frame.
+// The camera is left in that format, or partway there on failure, see `restore_stream`.
+fn capture_still(
+    camera: &mut nokhwa::Camera,
+    pool: &Arc<FramePool>,
+) -> Result<(PooledFrame, u32, u32), nokhwa::NokhwaError> {
+    camera.stop_stream()?;
+    camera.set_camera_requset(RequestedFormat::new::<RgbAFormat>(
+        RequestedFormatType::AbsoluteHighestResolution,
+    ))?;
+    camera.open_stream()?;
+
+    let mut frame = camera.frame()?;
+    for _ in 1..STILL_WARM_UP_FRAMES {
+        frame = camera.frame()?;
+    }
+    let resolution = frame.resolution();
+    let mut pixels = pool.get((resolution.width() * resolution.height() * 4) as usize);
+    frame.decode_image_to_buffer::<RgbAFormat>(&mut pixels)?;
+    Ok((pixels, resolution.width(), resolution.height()))
+}
+
+// Attempts at switching back to the stream format before giving up on it.
+const RESTORE_ATTEMPTS: usize = 3;
+
+// Back to the stream format, whichever step of the still failed. If the camera refuses it,
+// the stream goes on in the format it is in rather than stopping.
+fn restore_stream(camera: &mut nokhwa::Camera) -> Result<(), nokhwa::NokhwaError> {
+    let mut result = Ok(());
+    for _ in 0..RESTORE_ATTEMPTS {
+        let _ = camera.stop_stream();
+        result = camera
+            .set_camera_requset(stream_format())
+            .and_then(|_| camera.open_stream());
+        if result.is_ok() {
+            return result;
+        }
+        thread::sleep(std::time::Duration::from_millis(100));
+    }
+    if let Err(e) = &result {
+        log::warn!("Can't switch the camera back to the stream format: {e}");
+    }
+    camera.open_stream().or(result)
+}
+
+// Ends the stream when either of its threads ends, panics included, so the other one doesn't
+// wait for frames or room that won't come.
+struct StreamGuard {
+    session: Arc<StreamSession>,
+    queue: Arc<FrameQueue>,
+    ring: Option<Arc<FrameRing>>,
+}
 
-        let mut camera = nokhwa::Camera::new(nokhwa::utils::CameraIndex::Index(id), requested)
-            .expect("Can't access camera");
-        camera.open_stream().expect("Can't start camera stream");
+impl Drop for StreamGuard {
+    fn drop(&mut self) {
+        self.session.stop();
+        self.queue.close();
+        if let Some(ring) = &self.ring {
+            ring.retire();
+        }
+    }
+}
+
+// Longest wait for a frame before checking the stream is still running.
+const FRAME_WAIT: std::time::Duration = std::time::Duration::from_millis(250);
+
+fn start_stream(id: u32, delivery: FrameDelivery) -> Result<(), std::io::Error> {
+    let queue = Arc::new(camera_state().frame_queue());
+    let queue_for_capture = queue.clone();
+
+    let frame_pool = FramePool::new();
+    let pool_for_capture = frame_pool.clone();
+
+    let session = Arc::new(StreamSession::new(frame_pool.clone()));
+    session::register(id, session.clone());
+    let session_for_capture = session.clone();
+
+    thread::spawn(move || {
+        let session = session_for_capture;
+        let _guard = StreamGuard {
+            session: session.clone(),
+            queue: queue_for_capture.clone(),
+            ring: None,
+        };
+        let camera = nokhwa::Camera::new(nokhwa::utils::CameraIndex::Index(id), stream_format())
+            .and_then(|mut camera| camera.open_stream().map(|()| camera));
+        let mut camera = match camera {
+            Ok(camera) => camera,
+            Err(e) => {
+                log::error!("Can't start camera {id}: {e}");
+                return;
+            }
+        };
+
+        let mut sequence = 0;
+        let mut limiter = FrameLimiter::default();
+        // Set when a full resolution still failed, the next stream frame is used instead.
+        let mut still_fallback = false;
+        while session.is_running() {
+            if session.take_still_request() {
+                let captured_at = Instant::now();
+                match capture_still(&mut camera, &pool_for_capture) {
+                    Ok((pixels, width, height)) => {
+                        let still = CapturedFrame {
+                            pixels,
+                            width,
+                            height,
+                            sequence,
+                            captured_at,
+                            timestamp_us: unix_micros(),
+                            still: true,
+                        };
+                        if let Pushed::Closed = queue_for_capture.push(still) {
+                            break;
+                        }
+                    }
+                    Err(e) => {
+                        log::warn!("Can't take a full resolution still on camera {id}: {e}");
+                        still_fallback = true;
+                    }
+                }
+                if let Err(e) = restore_stream(&mut camera) {
+                    log::error!("Can't restart camera {id} stream: {e}");
+                    session.stop();
+                    break;
+                }
+            }
+
+            if power::mode() == power::PowerMode::Idle {
+                let _ = camera.stop_stream();
+                log::info!("Camera {id} idle");
+                if !power::wait_while_idle(&session) {
+                    break;
+                }
+                if let Err(e) = camera.open_stream() {
+                    log::error!("Can't restart camera {id} stream: {e}");
+                    session.stop();
+                    break;
+                }
+            }
 
-        while should_run_capture.load(std::sync::atomic::Ordering::Relaxed) {
             match camera.frame() {
                 Ok(frame) => {
-                    let mut slot = frame_for_capture.lock().unwrap();
-                    *slot = Some(frame);
+                    let captured_at = Instant::now();
+                    sequence += 1;
+                    session.metrics.frame_captured();
+                    if !limiter.accept(captured_at, power::frame_rate()) && !still_fallback {
+                        continue;
+                    }
+                    let timestamp_us = unix_micros();
+
+                    let resolution = frame.resolution();
+                    let len = (resolution.width() * resolution.height() * 4) as usize;
+                    let mut pixels = pool_for_capture.get(len);
+                    if let Err(e) = frame.decode_image_to_buffer::<RgbAFormat>(&mut pixels) {
+                        log::warn!("Can't decode frame {sequence}: {e}");
+                        continue;
+                    }
+                    session.metrics.record(Stage::Decode, captured_at.elapsed());
+
+                    let pushed = queue_for_capture.push(CapturedFrame {
+                        pixels,
+                        width: resolution.width(),
+                        height: resolution.height(),
+                        sequence,
+                        captured_at,
+                        timestamp_us,
+                        still: std::mem::take(&mut still_fallback),
+                    });
+                    match pushed {
+                        Pushed::Queued => {}
+                        Pushed::DroppedOldest => session.metrics.frame_dropped(),
+                        Pushed::Closed => break,
+                    }
                 }
                 Err(e) => {
-                    eprintln!("Error: {e}");
+                    log::warn!("Can't capture frame: {e}");
                     thread::sleep(std::time::Duration::from_millis(33));
                 }
             }
         }
         let _ = camera.stop_stream();
-        println!("Camera thread stopped");
+        log::info!("Camera {id} capture stopped");
     });
 
-    let frame_for_processing = latest_frame.clone();
-
     thread::spawn(move || {
-        let mut buffer = vec![0u8; 640 * 480 * 4];
-        let is = ImageSegmentation::init();
-
-        while should_run.load(std::sync::atomic::Ordering::Relaxed) {
-            let frame_opt = {
-                let mut slot = frame_for_processing.lock().unwrap();
-                slot.take()
-            };
+        let _guard = StreamGuard {
+            session: session.clone(),
+            queue: queue.clone(),
+            ring: match &delivery {
+                FrameDelivery::Shared(ring, _) => Some(ring.clone()),
+                FrameDelivery::Copy(_) => None,
+            },
+        };
+        let mut processor = FrameProcessor::new(frame_pool);
+        let mut quality = QualityController::default();
+        let metrics = &session.metrics;
+        // Session capture the processor was last reset for.
+        let mut captured: Option<Arc<SessionCapture>> = None;
 
-            let Some(frame) = frame_opt else {
-                thread::sleep(std::time::Duration::from_millis(5));
+        while power::wait_while_idle(&session) {
+            // The timeout only bounds how long a stop or idle request waits.
+            let Some(frame) = queue.pop(FRAME_WAIT) else {
                 continue;
             };
 
-            frame
-                .decode_image_to_buffer::<RgbAFormat>(&mut buffer)
-                .unwrap();
-
-            let debug = CAMERA_STATE
-                .get()
-                .unwrap()
-                .debug
-                .load(std::sync::atomic::Ordering::Relaxed);
-
-            let has_mask = CAMERA_STATE
-                .get()
-                .unwrap()
-                .mask
-                .load(std::sync::atomic::Ordering::Relaxed);
-
-            let background = CAMERA_STATE
-                .get()
-                .unwrap()
-                .background
-                .lock()
-                .unwrap()
-                .clone();
-
-            let mut final_image: Vec<u8>;
-
-            if !debug {
-                if has_mask {
-                    let mask = is.create_mask(buffer.clone());
-                    final_image = blur_background(&buffer, &mask, 12.0);
-                } else if let Some(background) = background {
-                    let mask = is.create_mask(buffer.clone());
-                    final_image = replace_background(&buffer, &background, &mask);
-                } else {
-                    final_image = buffer.clone();
+            let state = camera_state();
+            let settings = state.effect_settings();
+            let low_power = power::mode() == power::PowerMode::LowPower;
+            processor.set_low_power(low_power);
+            let target_fps = state.adaptive_fps();
+            if target_fps == 0 && quality.level() != 0 {
+                quality.reset();
+                processor.set_quality(quality.settings());
+            }
+            let quality_level = quality.level() as u32;
+
+            let capture = archive::get(id);
+            if let Some(capture) = &capture {
+                if !captured
+                    .as_ref()
+                    .is_some_and(|last| Arc::ptr_eq(last, capture))
+                {
+                    // Replays start with a new processor, start over from the same state.
+                    processor.reset();
+                    capture.reset();
                 }
-            } else {
-                let mask = is.create_mask(buffer.clone());
-                final_image = show_mask_overlay(&buffer, &mask);
             }
+            captured.clone_from(&capture);
+            let archived = capture.map(|capture| {
+                let state = FrameState {
+                    timestamp_us: frame.timestamp_us,
+                    sequence: frame.sequence,
+                    quality_level: quality_level as u8,
+                    low_power,
+                    extra_mask: false,
+                };
+                (capture, state)
+            });
+
+            let dataset = dataset::get(id);
+            let snapshots = session.take_snapshots(frame.still);
+            let keep_raw = !snapshots.is_empty() || archived.is_some() || dataset.is_some();
+            let raw = keep_raw.then(|| Arc::new(session.pool.copy(&frame.pixels)));
 
-            // Stop the loop if flutter close the stream.
-            if sink.add(final_image).is_err() {
-                should_run.store(false, std::sync::atomic::Ordering::Relaxed);
-                break;
+            let start = Instant::now();
+            let (output, effects) =
+                processor.process(frame.pixels, frame.width, frame.height, &settings);
+
+            if let Some(raw) = raw.as_ref().filter(|_| !snapshots.is_empty()) {
+                let wants_mask = snapshots.iter().any(|request| request.mask);
+                let snapshot = SnapshotFrame {
+                    processed: &output,
+                    raw,
+                    mask: wants_mask.then(|| processor.mask(raw, frame.width, frame.height)),
+                    width: frame.width,
+                    height: frame.height,
+                    effects,
+                };
+                for request in snapshots {
+                    request.fulfill(&snapshot);
+                }
+            }
+            if frame.still {
+                if let (Some((capture, mut state)), Some(raw)) = (archived, raw) {
+                    state.extra_mask = processor.computed_extra_mask();
+                    capture.push_frame(&settings, state, raw, frame.width, frame.height);
+                }
+                session.pool.frame_done();
+                continue;
+            }
+            if let Some(recording) = recorder::get(id) {
+                recording.push(&output, frame.width, frame.height, frame.captured_at);
+            }
+            session.taps.feed(
+                &output,
+                frame.width,
+                frame.height,
+                frame.timestamp_us,
+                frame.captured_at,
+                || mask_stats(processor.mask(&output, frame.width, frame.height)),
+            );
+            if let (Some(dataset), Some(raw)) = (&dataset, &raw) {
+                let reason = dataset.sample(|| {
+                    mask_stats(processor.mask(raw, frame.width, frame.height)).confidence
+                });
+                if let Some(reason) = reason {
+                    dataset.push(DatasetFrame {
+                        pixels: raw.clone(),
+                        mask: processor.mask(raw, frame.width, frame.height).to_vec(),
+                        mask_offset: processor.mask_offset(frame.width, frame.height),
+                        width: frame.width,
+                        height: frame.height,
+                        sequence: frame.sequence,
+                        timestamp_us: frame.timestamp_us,
+                        reason,
+                        effects: (&settings).into(),
+                        quality_level,
+                        low_power,
+                    });
+                }
+            }
+            // Once the mask can no longer be computed for this frame.
+            if let (Some((capture, mut state)), Some(raw)) = (archived, raw) {
+                state.extra_mask = processor.computed_extra_mask();
+                capture.push_frame(&settings, state, raw, frame.width, frame.height);
+            }
+            if let Some(replay) = session.replay.lock().unwrap().as_ref() {
+                replay.push(&output, frame.width, frame.height, frame.timestamp_us);
+            }
+            let (output, width, height) = processor.scale(output, frame.width, frame.height);
+            let (output, format) = processor.encode(output, width, height, state.output_format());
+            if target_fps != 0 && quality.update(start.elapsed(), target_fps) {
+                processor.set_quality(quality.settings());
+                log::info!("Camera {id} quality level {}", quality.level());
+            }
+
+            let timings = processor.timings();
+            if let Some(mask) = timings.mask {
+                metrics.record(Stage::Preprocess, mask.preprocess);
+                metrics.record(Stage::Inference, mask.inference);
+                metrics.record(Stage::Postprocess, mask.postprocess);
+            }
+            metrics.record(Stage::Composite, timings.composite);
+            metrics.record(Stage::Encode, timings.encode);
+
+            let info = FrameInfo {
+                width,
+                height,
+                stride: format.stride(width),
+                pixel_format: format.into(),
+                capture_timestamp_us: frame.timestamp_us,
+                sequence: frame.sequence,
+                latency_us: frame.captured_at.elapsed().as_micros() as u64,
+                effects: effects.into(),
+                quality_level,
             };
+
+            let sink_start = Instant::now();
+            match delivery.send(output, info) {
+                Delivered::Sent => {
+                    metrics.record(Stage::Sink, sink_start.elapsed());
+                    metrics.record(Stage::EndToEnd, frame.captured_at.elapsed());
+                    metrics.frame_processed();
+                }
+                Delivered::Dropped => metrics.frame_dropped(),
+                // Stop the loop if flutter close the stream.
+                Delivered::Closed => {
+                    session.stop();
+                    break;
+                }
+            }
+            session.pool.frame_done();
         }
-        println!("Processing thread stopped");
+        log::info!("Camera {id} processing stopped");
     });
 
     Ok(())
@@ -0,0 +1,118 @@
+use std::sync::Arc;
+
+use image::{ImageFormat, ImageReader, RgbaImage};
+
+use crate::media::still::{decode_oriented, encode_rgba, StillFormat};
+use crate::pipeline::pool::FramePool;
+use crate::pipeline::processor::{EffectSettings, FrameProcessor};
+
+// JPEG inputs are encoded back with this quality.
+const JPEG_QUALITY: u8 = 90;
+
+#[derive(Debug, Clone, Default)]
+pub struct ImageEffects {
+    pub blur: bool,
+    /// Encoded image replacing the background, resized to the image. Ignored when blurring.
+    pub background: Option<Vec<u8>>,
+    /// Show the mask over the image instead of applying the other effects.
+    pub debug: bool,
+}
+
+/// Apply the stream effects to an encoded image, at its own resolution.
+/// The image is turned upright following its EXIF orientation, the result has none.
+/// The result is in the same format as the input for PNG, JPEG and WebP, PNG otherwise.
+pub fn process_image(bytes: Vec<u8>, effects: ImageEffects) -> Result<Vec<u8>, std::io::Error> {
+    let (image, format) = decode(&bytes)?;
+    let background = match effects.background {
+        Some(background) => Some(Arc::new(decode(&background)?.0)),
+        None => None,
+    };
+    let settings = EffectSettings {
+        blur: effects.blur,
+        background,
+        debug: effects.debug,
+        roi: false,
+        refine: false,
+        temporal: false,
+    };
+
+    let (width, height) = image.dimensions();
+    let pool = FramePool::new();
+    let mut frame = pool.get(image.as_raw().len());
+    frame.copy_from_slice(image.as_raw());
+    let mut processor = FrameProcessor::new(pool);
+    processor.set_camera_frames(false);
+    let (output, _) = processor.process(frame, width, height, &settings);
+    encode_rgba(&output, width, height, format).map_err(std::io::Error::other)
+}
+
+// Upright RGBA image, and the format to encode the result in.
+fn decode(bytes: &[u8]) -> Result<(RgbaImage, StillFormat), std::io::Error> {
+    let reader = ImageReader::new(std::io::Cursor::new(bytes)).with_guessed_format()?;
+    let format = match reader.format() {
+        Some(ImageFormat::Jpeg) => StillFormat::Jpeg {
+            quality: JPEG_QUALITY,
+        },
+        Some(ImageFormat::WebP) => StillFormat::WebP,
+        _ => StillFormat::Png,
+    };
+    let image = decode_oriented(reader).map_err(invalid_data)?.to_rgba8();
+    Ok((image, format))
+}
+
+fn invalid_data(e: image::ImageError) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, e)
+}
+
+#[cfg(test)]
+mod tests {
+    use image::codecs::png::PngEncoder;
+    use image::{ExtendedColorType, ImageEncoder};
+
+    use super::*;
+
+    // Big endian EXIF with only an orientation entry.
+    fn exif(orientation: u8) -> Vec<u8> {
+        let mut exif = b"MM\0\x2a\0\0\0\x08\0\x01".to_vec();
+        exif.extend_from_slice(&[0x01, 0x12, 0, 3, 0, 0, 0, 1, 0, orientation, 0, 0]);
+        exif.extend_from_slice(&[0, 0, 0, 0]);
+        exif
+    }
+
+    #[test]
+    fn decode_applies_the_exif_orientation() {
+        // Red then blue, stored sideways: orientation 6 turns it 90 degrees clockwise.
+        let pixels = [255, 0, 0, 255, 0, 0, 255, 255];
+        let mut png = Vec::new();
+        let mut encoder = PngEncoder::new(&mut png);
+        encoder.set_exif_metadata(exif(6)).unwrap();
+        encoder
+            .write_image(&pixels, 2, 1, ExtendedColorType::Rgba8)
+            .unwrap();
+
+        let (image, format) = decode(&png).unwrap();
+        assert_eq!(format, StillFormat::Png);
+        assert_eq!(image.dimensions(), (1, 2));
+        assert_eq!(image.get_pixel(0, 0).0, [255, 0, 0, 255]);
+        assert_eq!(image.get_pixel(0, 1).0, [0, 0, 255, 255]);
+    }
+
+    #[test]
+    fn decode_keeps_the_jpeg_format() {
+        let jpeg = encode_rgba(&[128; 64], 4, 4, StillFormat::Jpeg { quality: 50 }).unwrap();
+        let (image, format) = decode(&jpeg).unwrap();
+        assert_eq!(
+            format,
+            StillFormat::Jpeg {
+                quality: JPEG_QUALITY
+            }
+        );
+        assert_eq!(image.dimensions(), (4, 4));
+    }
+
+    #[test]
+    fn decode_rejects_garbage() {
+        let error = decode(b"not an image").unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,129 @@
+use std::collections::VecDeque;
+use std::sync::{Mutex, OnceLock};
+
+use log::{LevelFilter, Log, Metadata, Record};
+
+use crate::frb_generated::StreamSink;
+use crate::pipeline::frame::unix_micros;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl From<log::Level> for LogLevel {
+    fn from(level: log::Level) -> LogLevel {
+        match level {
+            log::Level::Error => LogLevel::Error,
+            log::Level::Warn => LogLevel::Warn,
+            log::Level::Info => LogLevel::Info,
+            log::Level::Debug => LogLevel::Debug,
+            log::Level::Trace => LogLevel::Trace,
+        }
+    }
+}
+
+impl From<LogLevel> for LevelFilter {
+    fn from(level: LogLevel) -> LevelFilter {
+        match level {
+            LogLevel::Error => LevelFilter::Error,
+            LogLevel::Warn => LevelFilter::Warn,
+            LogLevel::Info => LevelFilter::Info,
+            LogLevel::Debug => LevelFilter::Debug,
+            LogLevel::Trace => LevelFilter::Trace,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+    pub level: LogLevel,
+    /// Module the record comes from, like `rust_lib_flutter_rust_cam_test::api::camera`.
+    pub target: String,
+    pub message: String,
+    /// Microseconds since the Unix epoch.
+    pub timestamp_us: u64,
+}
+
+// Records kept until Dart subscribes, so what happened at startup isn't lost.
+const HISTORY: usize = 256;
+
+#[derive(Default)]
+struct Forwarder {
+    sink: Option<StreamSink<LogEntry>>,
+    history: VecDeque<LogEntry>,
+}
+
+static FORWARDER: OnceLock<Mutex<Forwarder>> = OnceLock::new();
+
+fn forwarder() -> &'static Mutex<Forwarder> {
+    FORWARDER.get_or_init(Default::default)
+}
+
+struct Logger;
+
+static LOGGER: Logger = Logger;
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let entry = LogEntry {
+            level: record.level().into(),
+            target: record.target().to_string(),
+            message: record.args().to_string(),
+            timestamp_us: unix_micros(),
+        };
+        // Still visible when running a debug build from a terminal or an IDE, release
+        // builds only forward to Dart.
+        #[cfg(debug_assertions)]
+        eprintln!("[{} {}] {}", record.level(), entry.target, entry.message);
+
+        let mut forwarder = forwarder().lock().unwrap();
+        if forwarder.history.len() == HISTORY {
+            forwarder.history.pop_front();
+        }
+        forwarder.history.push_back(entry.clone());
+        if let Some(sink) = &forwarder.sink {
+            if sink.add(entry).is_err() {
+                forwarder.sink = None;
+            }
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Install the crate logger, done once from `init_app`.
+pub(crate) fn init(level: LevelFilter) {
+    if log::set_logger(&LOGGER).is_ok() {
+        log::set_max_level(level);
+    }
+}
+
+/// Only keep records at `level` or more severe, for Dart and the console of debug builds.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_log_level(level: LogLevel) {
+    log::set_max_level(level.into());
+}
+
+/// Forward log records to Dart, starting with the recent ones.
+/// Replaces any previous subscription.
+pub fn subscribe_logs(sink: StreamSink<LogEntry>) {
+    let mut forwarder = forwarder().lock().unwrap();
+    for entry in &forwarder.history {
+        if sink.add(entry.clone()).is_err() {
+            return;
+        }
+    }
+    forwarder.sink = Some(sink);
+}
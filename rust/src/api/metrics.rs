@@ -0,0 +1,89 @@
+use std::{thread, time::Duration};
+
+use crate::frb_generated::StreamSink;
+use crate::pipeline::metrics::{MetricsSnapshot, PipelineMetrics, Stage, StageSnapshot};
+use crate::pipeline::session;
+
+/// Timings of one pipeline stage over the last frames, in microseconds.
+pub struct StageStats {
+    pub last_us: u64,
+    pub mean_us: u64,
+    pub p50_us: u64,
+    pub p95_us: u64,
+    pub p99_us: u64,
+    pub max_us: u64,
+}
+
+impl From<StageSnapshot> for StageStats {
+    fn from(stage: StageSnapshot) -> StageStats {
+        StageStats {
+            last_us: stage.last_us,
+            mean_us: stage.mean_us,
+            p50_us: stage.p50_us,
+            p95_us: stage.p95_us,
+            p99_us: stage.p99_us,
+            max_us: stage.max_us,
+        }
+    }
+}
+
+/// Counters and stage timings of a camera stream.
+/// Stages skipped on a frame, like inference without any effect, keep their previous values.
+pub struct PipelineStats {
+    pub captured_frames: u64,
+    pub processed_frames: u64,
+    /// Frames replaced by a newer one before being processed, or not sent because
+    /// every shared slot was still held.
+    pub dropped_frames: u64,
+    pub capture_fps: f64,
+    pub output_fps: f64,
+    pub decode: StageStats,
+    pub preprocess: StageStats,
+    pub inference: StageStats,
+    pub postprocess: StageStats,
+    pub composite: StageStats,
+    pub encode: StageStats,
+    pub sink: StageStats,
+    pub end_to_end: StageStats,
+}
+
+impl From<MetricsSnapshot> for PipelineStats {
+    fn from(snapshot: MetricsSnapshot) -> PipelineStats {
+        PipelineStats {
+            captured_frames: snapshot.captured,
+            processed_frames: snapshot.processed,
+            dropped_frames: snapshot.dropped,
+            capture_fps: snapshot.capture_fps,
+            output_fps: snapshot.output_fps,
+            decode: snapshot.stage(Stage::Decode).into(),
+            preprocess: snapshot.stage(Stage::Preprocess).into(),
+            inference: snapshot.stage(Stage::Inference).into(),
+            postprocess: snapshot.stage(Stage::Postprocess).into(),
+            composite: snapshot.stage(Stage::Composite).into(),
+            encode: snapshot.stage(Stage::Encode).into(),
+            sink: snapshot.stage(Stage::Sink).into(),
+            end_to_end: snapshot.stage(Stage::EndToEnd).into(),
+        }
+    }
+}
+
+/// Stats of the last stream started on the camera, `None` if there was none.
+pub fn pipeline_stats(id: u32) -> Option<PipelineStats> {
+    session::get(id).map(|session| session.metrics.snapshot().into())
+}
+
+/// Send the stats of the camera stream every `interval_ms`, until Dart closes the stream.
+/// Follows the new stream when the camera is restarted, and sends zeros until the first one
+/// starts.
+pub fn stream_pipeline_stats(id: u32, interval_ms: u32, sink: StreamSink<PipelineStats>) {
+    let interval = Duration::from_millis(interval_ms.max(1) as u64);
+    thread::spawn(move || loop {
+        thread::sleep(interval);
+        // Sending even without a stream is how a closed sink is noticed.
+        let stats =
+            pipeline_stats(id).unwrap_or_else(|| PipelineMetrics::default().snapshot().into());
+        if sink.add(stats).is_err() {
+            break;
+        }
+    });
+}
@@ -1,2 +1,6 @@
 pub mod camera;
+pub mod effects;
+pub mod logging;
+pub mod metrics;
+pub mod recording;
 pub mod simple;
@@ -0,0 +1,474 @@
+use std::fs::File;
+
+use crate::api::camera::{still_format, SnapshotFormat};
+use crate::frb_generated::StreamSink;
+use crate::media::animation::{self, AnimationFormat};
+use crate::media::still::StillFormat;
+use crate::media::video::VideoFormat;
+use crate::ml::roi::MIN_PERSON_COVERAGE;
+use crate::pipeline::archive::{self, SessionCapture};
+use crate::pipeline::clip::{self, ClipSettings};
+use crate::pipeline::dataset::{self, DatasetCapture, DatasetSettings};
+use crate::pipeline::recorder::{self, Recording};
+use crate::pipeline::replay::{self, ReplayBuffer, ReplaySettings};
+use crate::pipeline::session;
+use crate::pipeline::tap::{Pace, Pacer};
+use crate::pipeline::timelapse::{self, Interval, Timelapse, TimelapseSettings};
+
+#[derive(Debug, Clone, Copy)]
+pub enum RecordingFormat {
+    /// Uncompressed YUV 4:2:0, large files but no quality loss.
+    Y4m,
+    /// Motion JPEG in AVI, limited to 4 GiB.
+    MjpegAvi,
+}
+
+#[derive(Debug, Clone)]
+pub struct RecordingOptions {
+    pub path: String,
+    pub format: RecordingFormat,
+    /// Frame rate of the file, 0 for 30. Camera frames are placed by their capture time,
+    /// repeated or skipped to match it.
+    pub fps: u32,
+    /// From 1 to 100, only used by MJPEG.
+    pub quality: u8,
+    /// Frames waiting to be written, the recording drops frames once it is full.
+    pub queue_depth: u32,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct RecordingSummary {
+    pub frames_written: u64,
+    pub frames_dropped: u64,
+    pub duration_us: u64,
+}
+
+/// Record the processed frames of the streams on the camera, without the output
+/// format and resolution changes. Frames of another size than the first are dropped.
+pub fn start_recording(id: u32, options: RecordingOptions) -> Result<(), std::io::Error> {
+    let format = match options.format {
+        RecordingFormat::Y4m => VideoFormat::Y4m,
+        RecordingFormat::MjpegAvi => VideoFormat::MjpegAvi {
+            quality: options.quality,
+        },
+    };
+    let fps = if options.fps == 0 { 30 } else { options.fps };
+    recorder::register_with(id, || {
+        let file = File::create(&options.path)?;
+        Recording::start(file, format, fps, options.queue_depth.max(1) as usize)
+    })?;
+    log::info!("Camera {id} recording to {}", options.path);
+    Ok(())
+}
+
+pub fn pause_recording(id: u32) {
+    if let Some(recording) = recorder::get(id) {
+        recording.set_paused(true);
+    }
+}
+
+pub fn resume_recording(id: u32) {
+    if let Some(recording) = recorder::get(id) {
+        recording.set_paused(false);
+    }
+}
+
+/// Finish writing the file. Also returns the error that stopped a recording early.
+pub fn stop_recording(id: u32) -> Result<RecordingSummary, std::io::Error> {
+    let recording = recorder::remove(id)
+        .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::NotFound, "Camera not recording"))?;
+    let summary = recording.stop()?;
+    log::info!("Camera {id} recording stopped, {summary:?}");
+    Ok(RecordingSummary {
+        frames_written: summary.frames_written,
+        frames_dropped: summary.frames_dropped,
+        duration_us: summary.duration_us,
+    })
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct SessionCaptureSummary {
+    pub frames: u64,
+    pub settings_changes: u64,
+    pub bytes: u64,
+}
+
+/// Record the raw frames of the streams on the camera into a `.camsession` archive, with the
+/// effect settings and processing state of each. The `run_pipeline` tool replays it with the
+/// same output as the stream. Frames are compressed losslessly, and the stream slows down
+/// rather than leave frames out when the disk can't keep up.
+pub fn start_session_capture(id: u32, path: String) -> Result<(), std::io::Error> {
+    archive::register_with(id, || SessionCapture::start(File::create(&path)?, id))?;
+    log::info!("Camera {id} session capture to {path}");
+    Ok(())
+}
+
+/// Finish writing the archive. Also returns the error that stopped a capture early.
+pub fn stop_session_capture(id: u32) -> Result<SessionCaptureSummary, std::io::Error> {
+    let capture = archive::remove(id).ok_or_else(|| {
+        std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            "No session capture on this camera",
+        )
+    })?;
+    let summary = capture.stop()?;
+    log::info!("Camera {id} session capture stopped, {summary:?}");
+    Ok(SessionCaptureSummary {
+        frames: summary.frames,
+        settings_changes: summary.settings_changes,
+        bytes: summary.bytes,
+    })
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ReplayOptions {
+    /// Length of the buffer, 0 to turn it off.
+    pub seconds: u32,
+    /// Older frames are dropped once the buffer takes more memory than this, 0 for no limit.
+    pub max_memory_mb: u32,
+    /// Keep frames as JPEG of this quality, from 1 to 100, or 0 to keep them uncompressed.
+    pub jpeg_quality: u8,
+}
+
+/// Keep the last seconds of processed frames of the running stream on the camera.
+/// Changing the options empties the buffer.
+pub fn set_replay_buffer(id: u32, options: ReplayOptions) -> Result<(), std::io::Error> {
+    let session = session::get(id).ok_or_else(|| {
+        std::io::Error::new(std::io::ErrorKind::NotFound, "No stream on this camera")
+    })?;
+    *session.replay.lock().unwrap() = (options.seconds > 0).then(|| {
+        ReplayBuffer::new(ReplaySettings {
+            duration_us: options.seconds as u64 * 1_000_000,
+            max_bytes: match options.max_memory_mb {
+                0 => usize::MAX,
+                mb => mb as usize * 1024 * 1024,
+            },
+            jpeg_quality: (options.jpeg_quality > 0).then_some(options.jpeg_quality),
+        })
+    });
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum ReplayFormat {
+    Y4m,
+    MjpegAvi,
+    /// One file per frame in a directory, named after the frame index and capture timestamp.
+    PngSequence,
+    JpegSequence,
+}
+
+#[derive(Debug, Clone)]
+pub struct ReplaySaveOptions {
+    /// File for videos, directory for image sequences.
+    pub path: String,
+    pub format: ReplayFormat,
+    /// Save only the last seconds, 0 for the whole buffer.
+    pub seconds: u32,
+    /// Frame rate of videos, 0 for 30.
+    pub fps: u32,
+    /// From 1 to 100, for MJPEG and JPEG.
+    pub quality: u8,
+}
+
+/// Write the replay buffer of the camera stream, returns the number of frames written.
+/// The buffer keeps filling meanwhile.
+pub fn save_replay(id: u32, options: ReplaySaveOptions) -> Result<u64, std::io::Error> {
+    let session = session::get(id).ok_or_else(|| {
+        std::io::Error::new(std::io::ErrorKind::NotFound, "No stream on this camera")
+    })?;
+    let duration_us = match options.seconds {
+        0 => u64::MAX,
+        seconds => seconds as u64 * 1_000_000,
+    };
+    let frames = match session.replay.lock().unwrap().as_ref() {
+        Some(replay) => replay.last(duration_us),
+        None => {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "No replay buffer on this camera",
+            ))
+        }
+    };
+
+    let fps = if options.fps == 0 { 30 } else { options.fps };
+    let path = std::path::Path::new(&options.path);
+    let frames = match options.format {
+        ReplayFormat::Y4m => {
+            replay::save_video(&frames, File::create(path)?, VideoFormat::Y4m, fps)?
+        }
+        ReplayFormat::MjpegAvi => replay::save_video(
+            &frames,
+            File::create(path)?,
+            VideoFormat::MjpegAvi {
+                quality: options.quality,
+            },
+            fps,
+        )?,
+        ReplayFormat::PngSequence => replay::save_images(&frames, path, StillFormat::Png)?,
+        ReplayFormat::JpegSequence => {
+            let format = StillFormat::Jpeg {
+                quality: options.quality,
+            };
+            replay::save_images(&frames, path, format)?
+        }
+    };
+    log::info!(
+        "Camera {id} replay saved to {}, {frames} frames",
+        options.path
+    );
+    Ok(frames)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum ClipFormat {
+    Gif,
+    /// Lossless animated WebP.
+    WebP,
+}
+
+#[derive(Debug, Clone)]
+pub struct ClipOptions {
+    pub path: String,
+    pub format: ClipFormat,
+    pub seconds: f64,
+    /// Frames per second of the clip, 0 for 10.
+    pub fps: u32,
+    /// Width of the clip, the height follows the stream aspect ratio. 0 for 320.
+    pub width: u32,
+    /// GIF palette quality, from 1 to 100. Higher is slower to encode.
+    pub quality: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClipStage {
+    Capturing,
+    Encoding,
+    Done,
+    Failed,
+}
+
+#[derive(Debug, Clone)]
+pub struct ClipProgress {
+    pub stage: ClipStage,
+    /// Progress of the stage, from 0 to 1.
+    pub fraction: f64,
+    pub frames: u32,
+    /// Size of the file once done.
+    pub bytes: u64,
+    pub error: Option<String>,
+}
+
+/// Capture the next seconds of the camera stream and write them as a looping animation,
+/// in the background. Progress goes to the sink, ending with `Done` or `Failed`.
+pub fn export_clip(
+    id: u32,
+    options: ClipOptions,
+    sink: StreamSink<ClipProgress>,
+) -> Result<(), std::io::Error> {
+    let session = session::get(id)
+        .filter(|session| session.is_running())
+        .ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::NotFound, "No stream on this camera")
+        })?;
+    let duration = std::time::Duration::try_from_secs_f64(options.seconds.max(0.0))
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidInput, e))?;
+    let fps = if options.fps == 0 { 10 } else { options.fps };
+    let frames = session.taps.add(8, false, Pacer::new(Pace::Fps(fps)));
+    let settings = ClipSettings {
+        duration,
+        fps,
+        width: Some(if options.width == 0 {
+            320
+        } else {
+            options.width
+        }),
+    };
+    let format = match options.format {
+        // Quality 100 is speed 1, the slowest and best of the GIF encoder.
+        ClipFormat::Gif => AnimationFormat::Gif {
+            speed: 30 - (options.quality.clamp(1, 100) as u32 * 29 / 100) as u8,
+        },
+        ClipFormat::WebP => AnimationFormat::WebP,
+    };
+
+    std::thread::spawn(move || {
+        let report = |stage, fraction, frames: usize, bytes, error| {
+            let _ = sink.add(ClipProgress {
+                stage,
+                fraction,
+                frames: frames as u32,
+                bytes,
+                error,
+            });
+        };
+
+        let clip = clip::capture(frames, settings, |fraction| {
+            report(ClipStage::Capturing, fraction, 0, 0, None)
+        });
+        let count = clip.len();
+        let encoded = animation::encode(clip, format, |done| {
+            report(
+                ClipStage::Encoding,
+                done as f64 / count.max(1) as f64,
+                done,
+                0,
+                None,
+            )
+        })
+        .map_err(std::io::Error::other)
+        .and_then(|data| std::fs::write(&options.path, &data).map(|()| data.len()));
+
+        match encoded {
+            Ok(bytes) => {
+                log::info!("Camera {id} clip saved to {}", options.path);
+                report(ClipStage::Done, 1.0, count, bytes as u64, None);
+            }
+            Err(e) => {
+                log::warn!("Can't export clip to {}: {e}", options.path);
+                report(ClipStage::Failed, 1.0, count, 0, Some(e.to_string()));
+            }
+        }
+    });
+    Ok(())
+}
+
+#[derive(Debug, Clone)]
+pub struct TimelapseOptions {
+    /// Created if needed. Frames are named `000000.png` and so on, next to `manifest.jsonl`
+    /// with a header line then a line per frame. A directory used before keeps its frames,
+    /// the new ones are numbered after them.
+    pub directory: String,
+    /// Time between saved frames, used when `every_frames` is 0.
+    pub interval_ms: u64,
+    /// Save one frame out of this many instead of going by time, 0 to use `interval_ms`.
+    pub every_frames: u32,
+    pub format: SnapshotFormat,
+    /// From 1 to 100, only used by JPEG.
+    pub quality: u8,
+    /// Skip frames where no person is detected, the interval only counts frames with someone.
+    pub pause_without_person: bool,
+    /// Stop after this many frames, 0 for no limit.
+    pub max_frames: u32,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct TimelapseSummary {
+    pub frames_saved: u32,
+    pub frames_without_person: u64,
+}
+
+/// Save one processed frame of the running stream on the camera every interval,
+/// until `stop_timelapse`, the stream ends or `max_frames` are saved.
+pub fn start_timelapse(id: u32, options: TimelapseOptions) -> Result<(), std::io::Error> {
+    let session = session::get(id)
+        .filter(|session| session.is_running())
+        .ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::NotFound, "No stream on this camera")
+        })?;
+    let settings = TimelapseSettings {
+        directory: options.directory.clone().into(),
+        interval: match options.every_frames {
+            0 => Interval::Milliseconds(options.interval_ms.max(1)),
+            count => Interval::Frames(count),
+        },
+        format: still_format(options.format, options.quality),
+        require_person: options.pause_without_person,
+        min_coverage: MIN_PERSON_COVERAGE,
+        max_frames: (options.max_frames > 0).then_some(options.max_frames),
+    };
+    timelapse::register_with(id, || {
+        let pacer = Pacer::new(settings.interval.pace());
+        let frames = session.taps.add(4, settings.require_person, pacer.clone());
+        Timelapse::start(id, frames, pacer, settings)
+    })?;
+    log::info!("Camera {id} time-lapse to {}", options.directory);
+    Ok(())
+}
+
+/// Stop the time-lapse, or get the summary of one that ended by itself.
+/// Also returns the error that stopped it early.
+pub fn stop_timelapse(id: u32) -> Result<TimelapseSummary, std::io::Error> {
+    let timelapse = timelapse::remove(id).ok_or_else(|| {
+        std::io::Error::new(std::io::ErrorKind::NotFound, "No time-lapse on this camera")
+    })?;
+    let summary = timelapse.stop()?;
+    Ok(TimelapseSummary {
+        frames_saved: summary.frames_saved,
+        frames_without_person: summary.frames_without_person,
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct DatasetOptions {
+    /// Created if needed, and must be empty. Frames go to `images/`, masks to `masks/` and
+    /// annotations to `annotations/`, all named `000000.png` or `.json` and so on, with the
+    /// COCO annotations of every frame in `annotations.json`.
+    pub directory: String,
+    /// Save one frame out of this many, 0 to only save low confidence and flagged frames.
+    pub every_frames: u32,
+    /// Save frames where less than this fraction of the mask is confident, from 0 to 1.
+    /// 0 to turn it off, otherwise the mask is computed for every frame even without effects.
+    pub max_confidence: f32,
+    /// Frames skipped after saving a low confidence frame.
+    pub low_confidence_spacing: u32,
+    /// Stop after this many frames, 0 for no limit.
+    pub max_frames: u32,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DatasetSummary {
+    pub frames_saved: u32,
+    pub interval_frames: u32,
+    pub low_confidence_frames: u32,
+    pub flagged_frames: u32,
+    /// Frames left out because the disk couldn't keep up.
+    pub frames_dropped: u32,
+}
+
+/// Save camera frames of the streams on the camera with their predicted masks and effect
+/// settings, to collect segmentation training data. Frames are picked by `options`, and
+/// `flag_dataset_frame` saves the next one. Runs until `stop_dataset_capture` or `max_frames`.
+pub fn start_dataset_capture(id: u32, options: DatasetOptions) -> Result<(), std::io::Error> {
+    let settings = DatasetSettings {
+        directory: options.directory.clone().into(),
+        every_frames: options.every_frames,
+        max_confidence: (options.max_confidence > 0.0).then_some(options.max_confidence),
+        low_confidence_spacing: options.low_confidence_spacing,
+        max_frames: (options.max_frames > 0).then_some(options.max_frames),
+    };
+    dataset::register_with(id, || DatasetCapture::start(id, settings))?;
+    log::info!("Camera {id} dataset capture to {}", options.directory);
+    Ok(())
+}
+
+/// Save the next frame of the dataset capture, for a hard case spotted by the user.
+pub fn flag_dataset_frame(id: u32) -> Result<(), std::io::Error> {
+    let capture = dataset::get(id).ok_or_else(|| {
+        std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            "No dataset capture on this camera",
+        )
+    })?;
+    capture.flag();
+    Ok(())
+}
+
+/// Stop the dataset capture, or get the summary of one that ended by itself.
+/// Also returns the error that stopped it early.
+pub fn stop_dataset_capture(id: u32) -> Result<DatasetSummary, std::io::Error> {
+    let capture = dataset::remove(id).ok_or_else(|| {
+        std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            "No dataset capture on this camera",
+        )
+    })?;
+    let summary = capture.stop()?;
+    Ok(DatasetSummary {
+        frames_saved: summary.frames_saved,
+        interval_frames: summary.interval_frames,
+        low_confidence_frames: summary.low_confidence_frames,
+        flagged_frames: summary.flagged_frames,
+        frames_dropped: summary.frames_dropped,
+    })
+}
@@ -12,6 +12,14 @@ pub fn get_image(file: String) -> Result<Vec<u8>, std::io::Error> {
 
 #[flutter_rust_bridge::frb(init)]
 pub fn init_app() {
+    // Before the default utilities, so the crate logger takes the place of theirs.
+    crate::api::logging::init(if cfg!(debug_assertions) {
+        log::LevelFilter::Debug
+    } else {
+        log::LevelFilter::Info
+    });
     // Default utilities - feel free to customize
     flutter_rust_bridge::setup_default_user_utils();
+    // Load the models in the background, streams wait for them if they are not ready yet.
+    std::thread::spawn(crate::api::camera::preload_models);
 }
@@ -0,0 +1,393 @@
+// Batch processing of image files with the stream effects, for the `batch` command line tool.
+// Images are processed in parallel, each worker thread with its own segmentation state.
+
+use std::collections::HashMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+use image::RgbaImage;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::media::still::{encode_rgba, open_oriented, StillFormat};
+use crate::ml::composite::BlurQuality;
+use crate::ml::image::ImageSegmentation;
+use crate::pipeline::processor::{Compositor, Effect as CompositeEffect};
+
+// Extensions picked up when the input is a directory.
+const IMAGE_EXTENSIONS: [&str; 6] = ["png", "jpg", "jpeg", "webp", "bmp", "tiff"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Effect {
+    Blur,
+    /// Needs `background` in the configuration.
+    Background,
+    /// Transparent background, use PNG or WebP to keep it.
+    Cutout,
+    /// The mask over the image, to check the segmentation.
+    Mask,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FileFormat {
+    #[default]
+    Png,
+    Jpeg,
+    Webp,
+}
+
+/// Effect configuration file, in JSON.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BatchConfig {
+    pub effect: Effect,
+    /// Image replacing the background, relative to the configuration file.
+    #[serde(default)]
+    pub background: Option<PathBuf>,
+    #[serde(default = "default_blur_sigma")]
+    pub blur_sigma: f32,
+    #[serde(default)]
+    pub format: FileFormat,
+    /// JPEG quality, from 1 to 100.
+    #[serde(default = "default_quality")]
+    pub quality: u8,
+    /// Smooth the mask edges.
+    #[serde(default = "default_refine_mask")]
+    pub refine_mask: bool,
+}
+
+fn default_blur_sigma() -> f32 {
+    12.0
+}
+
+fn default_quality() -> u8 {
+    90
+}
+
+fn default_refine_mask() -> bool {
+    true
+}
+
+impl BatchConfig {
+    pub fn load(path: &Path) -> io::Result<BatchConfig> {
+        let json = std::fs::read(path)?;
+        let mut config: BatchConfig = serde_json::from_slice(&json)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        match &mut config.background {
+            Some(background) => {
+                if let Some(directory) = path.parent() {
+                    *background = directory.join(&*background);
+                }
+            }
+            None if config.effect == Effect::Background => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "The background effect needs a background image",
+                ));
+            }
+            None => {}
+        }
+        Ok(config)
+    }
+
+    fn still_format(&self) -> StillFormat {
+        match self.format {
+            FileFormat::Png => StillFormat::Png,
+            FileFormat::Jpeg => StillFormat::Jpeg {
+                quality: self.quality,
+            },
+            FileFormat::Webp => StillFormat::WebP,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImageReport {
+    pub input: PathBuf,
+    pub output: Option<PathBuf>,
+    pub width: u32,
+    pub height: u32,
+    pub millis: u64,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchReport {
+    pub processed: usize,
+    pub failed: usize,
+    pub total_millis: u64,
+    pub images: Vec<ImageReport>,
+}
+
+/// Images of a directory, not recursing, or the files matching a glob pattern. Sorted by path.
+pub fn find_inputs(input: &str) -> io::Result<Vec<PathBuf>> {
+    let path = Path::new(input);
+    let mut inputs: Vec<PathBuf> = if path.is_dir() {
+        std::fs::read_dir(path)?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.is_file() && is_image(path))
+            .collect()
+    } else {
+        glob::glob(input)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?
+            .filter_map(Result::ok)
+            .filter(|path| path.is_file())
+            .collect()
+    };
+    inputs.sort();
+    Ok(inputs)
+}
+
+fn is_image(path: &Path) -> bool {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| IMAGE_EXTENSIONS.contains(&extension.to_lowercase().as_str()))
+}
+
+/// Where each input goes in `output_dir`: its path from the deepest directory holding every
+/// input, with the extension of `format`. Inputs that would still get the same name, like
+/// `a.jpg` and `a.png`, keep their extension in it: `a.jpg.png` and `a.png.png`.
+pub fn output_paths(inputs: &[PathBuf], output_dir: &Path, format: StillFormat) -> Vec<PathBuf> {
+    let base = common_directory(inputs);
+    let extension = format.extension();
+    let relative: Vec<&Path> = inputs
+        .iter()
+        .map(|input| input.strip_prefix(&base).unwrap_or(input))
+        .collect();
+    let mut names: HashMap<PathBuf, usize> = HashMap::new();
+    for path in &relative {
+        *names.entry(path.with_extension(extension)).or_default() += 1;
+    }
+    relative
+        .into_iter()
+        .map(|path| {
+            let renamed = path.with_extension(extension);
+            let name = match (names[&renamed], path.extension()) {
+                (1, _) | (_, None) => renamed,
+                (_, Some(original)) => {
+                    path.with_extension(format!("{}.{extension}", original.to_string_lossy()))
+                }
+            };
+            output_dir.join(name)
+        })
+        .collect()
+}
+
+fn common_directory(inputs: &[PathBuf]) -> PathBuf {
+    let mut parents = inputs
+        .iter()
+        .map(|input| input.parent().unwrap_or(Path::new("")));
+    let Some(first) = parents.next() else {
+        return PathBuf::new();
+    };
+    parents.fold(first.to_path_buf(), |common, parent| {
+        common
+            .components()
+            .zip(parent.components())
+            .take_while(|(a, b)| a == b)
+            .map(|(a, _)| a)
+            .collect()
+    })
+}
+
+/// Process every input into `output_dir`, at the paths from `output_paths`.
+/// `threads` is the number of images processed at once, 0 for one per core.
+/// `progress` is called after each image with the number done so far.
+pub fn run(
+    inputs: &[PathBuf],
+    config: &BatchConfig,
+    output_dir: &Path,
+    threads: usize,
+    progress: impl Fn(usize, &ImageReport) + Sync,
+) -> io::Result<BatchReport> {
+    std::fs::create_dir_all(output_dir)?;
+    let background = match &config.background {
+        Some(path) if config.effect == Effect::Background => Some(Arc::new(
+            open_oriented(path)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?
+                .to_rgba8(),
+        )),
+        _ => None,
+    };
+    let outputs = output_paths(inputs, output_dir, config.still_format());
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .thread_name(|i| format!("batch-{i}"))
+        .build()
+        .map_err(io::Error::other)?;
+
+    let start = Instant::now();
+    let done = AtomicUsize::new(0);
+    let images: Vec<ImageReport> = pool.install(|| {
+        inputs
+            .par_iter()
+            .zip(&outputs)
+            .map_init(
+                || {
+                    let mut segmentation = ImageSegmentation::init();
+                    segmentation.set_refinement(config.refine_mask);
+                    (segmentation, Compositor::default())
+                },
+                |(segmentation, compositor), (input, output)| {
+                    let image_start = Instant::now();
+                    let mut report = ImageReport {
+                        input: input.clone(),
+                        output: None,
+                        width: 0,
+                        height: 0,
+                        millis: 0,
+                        error: None,
+                    };
+                    match process_file(
+                        input,
+                        output,
+                        config,
+                        background.as_ref(),
+                        segmentation,
+                        compositor,
+                        &mut report,
+                    ) {
+                        Ok(()) => report.output = Some(output.clone()),
+                        Err(e) => report.error = Some(e.to_string()),
+                    }
+                    report.millis = image_start.elapsed().as_millis() as u64;
+                    progress(done.fetch_add(1, Ordering::Relaxed) + 1, &report);
+                    report
+                },
+            )
+            .collect()
+    });
+
+    let failed = images.iter().filter(|image| image.error.is_some()).count();
+    Ok(BatchReport {
+        processed: images.len() - failed,
+        failed,
+        total_millis: start.elapsed().as_millis() as u64,
+        images,
+    })
+}
+
+fn process_file(
+    input: &Path,
+    output: &Path,
+    config: &BatchConfig,
+    background: Option<&Arc<RgbaImage>>,
+    segmentation: &mut ImageSegmentation,
+    compositor: &mut Compositor,
+    report: &mut ImageReport,
+) -> io::Result<()> {
+    let image = open_oriented(input)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?
+        .to_rgba8();
+    let (width, height) = image.dimensions();
+    (report.width, report.height) = (width, height);
+
+    let effect = match config.effect {
+        Effect::Blur => CompositeEffect::Blur {
+            sigma: config.blur_sigma,
+            quality: BlurQuality::Exact,
+        },
+        Effect::Background => CompositeEffect::Background(
+            background.expect("Background loaded for the background effect"),
+        ),
+        Effect::Cutout => CompositeEffect::Cutout,
+        Effect::Mask => CompositeEffect::MaskOverlay,
+    };
+    let mask = segmentation.create_mask(&image, width, height);
+    let mut pixels = vec![0; image.len()];
+    // Photos don't need the shift of camera frames.
+    compositor.apply(effect, &image, mask, width, (0, 0), &mut pixels);
+
+    let data =
+        encode_rgba(&pixels, width, height, config.still_format()).map_err(io::Error::other)?;
+    if let Some(directory) = output.parent() {
+        std::fs::create_dir_all(directory)?;
+    }
+    std::fs::write(output, data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!("batch-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&directory);
+        std::fs::create_dir_all(&directory).unwrap();
+        directory
+    }
+
+    fn paths(paths: &[&str]) -> Vec<PathBuf> {
+        paths.iter().map(PathBuf::from).collect()
+    }
+
+    #[test]
+    fn load_resolves_the_background_next_to_the_config() {
+        let directory = temp_dir("config");
+        let path = directory.join("config.json");
+        std::fs::write(
+            &path,
+            r#"{ "effect": "background", "background": "office.jpg" }"#,
+        )
+        .unwrap();
+        let config = BatchConfig::load(&path).unwrap();
+        assert_eq!(config.background, Some(directory.join("office.jpg")));
+        assert_eq!(config.format, FileFormat::Png);
+        assert_eq!(config.quality, 90);
+        assert!(config.refine_mask);
+
+        std::fs::write(&path, r#"{ "effect": "background" }"#).unwrap();
+        let error = BatchConfig::load(&path).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+
+        std::fs::write(&path, r#"{ "effect": "cutout", "colour": "red" }"#).unwrap();
+        let error = BatchConfig::load(&path).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn find_inputs_picks_images() {
+        let directory = temp_dir("inputs");
+        for name in ["b.JPG", "a.png", "notes.txt"] {
+            std::fs::write(directory.join(name), b"").unwrap();
+        }
+        std::fs::create_dir(directory.join("nested.png")).unwrap();
+
+        let found = find_inputs(directory.to_str().unwrap()).unwrap();
+        assert_eq!(found, [directory.join("a.png"), directory.join("b.JPG")]);
+        let pattern = directory.join("*.txt");
+        let found = find_inputs(pattern.to_str().unwrap()).unwrap();
+        assert_eq!(found, [directory.join("notes.txt")]);
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn output_paths_keep_the_layout() {
+        let inputs = paths(&[
+            "photos/2024/a.jpg",
+            "photos/2025/a.jpg",
+            "photos/2025/b.webp",
+        ]);
+        let outputs = output_paths(&inputs, Path::new("out"), StillFormat::Png);
+        assert_eq!(
+            outputs,
+            paths(&["out/2024/a.png", "out/2025/a.png", "out/2025/b.png"])
+        );
+    }
+
+    #[test]
+    fn colliding_outputs_keep_their_extension() {
+        let inputs = paths(&["in/a.jpg", "in/a.png", "in/b.png"]);
+        let outputs = output_paths(&inputs, Path::new("out"), StillFormat::Png);
+        assert_eq!(
+            outputs,
+            paths(&["out/a.jpg.png", "out/a.png.png", "out/b.png"])
+        );
+    }
+}
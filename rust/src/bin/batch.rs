@@ -0,0 +1,114 @@
+// Apply the camera effects to a directory of images, without the app.
+//
+//     batch <input directory or glob> <config.json> <output directory> [--threads N]
+//
+// The configuration picks the effect, see `BatchConfig`:
+//
+//     { "effect": "cutout", "format": "png" }
+//     { "effect": "background", "background": "office.jpg", "format": "jpeg", "quality": 85 }
+//
+// Outputs keep the layout of the inputs, see `batch::output_paths`. A `report.json` with the
+// result of each image is written in the output directory.
+//
+// Only built with the `cli` feature, which keeps it out of the app builds.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use rust_lib_flutter_rust_cam_test::batch::{self, BatchConfig};
+
+const USAGE: &str =
+    "Usage: batch <input directory or glob> <config.json> <output directory> [--threads N]";
+
+struct Args {
+    input: String,
+    config: PathBuf,
+    output: PathBuf,
+    threads: usize,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut positional = Vec::new();
+    let mut threads = 0;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--threads" => {
+                let value = args.next().ok_or("--threads needs a value")?;
+                threads = value
+                    .parse()
+                    .map_err(|_| format!("Invalid thread count: {value}"))?;
+            }
+            "-h" | "--help" => return Err(USAGE.into()),
+            _ => positional.push(arg),
+        }
+    }
+    let [input, config, output] = <[String; 3]>::try_from(positional).map_err(|_| USAGE)?;
+    Ok(Args {
+        input,
+        config: config.into(),
+        output: output.into(),
+        threads,
+    })
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("{message}");
+            return ExitCode::FAILURE;
+        }
+    };
+    match run(&args) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(2),
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// Returns false if some images failed.
+fn run(args: &Args) -> std::io::Result<bool> {
+    let config = BatchConfig::load(&args.config)?;
+    let inputs = batch::find_inputs(&args.input)?;
+    if inputs.is_empty() {
+        eprintln!("No image found for {}", args.input);
+        return Ok(true);
+    }
+    let total = inputs.len();
+    eprintln!("Processing {total} images with {:?}", config.effect);
+
+    let report = batch::run(
+        &inputs,
+        &config,
+        &args.output,
+        args.threads,
+        |done, image| {
+            let name = image
+                .input
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy();
+            match &image.error {
+                None => eprintln!("[{done}/{total}] {name} ({} ms)", image.millis),
+                Some(error) => eprintln!("[{done}/{total}] {name} failed: {error}"),
+            }
+        },
+    )?;
+
+    let report_path = args.output.join("report.json");
+    let json = serde_json::to_vec_pretty(&report).map_err(std::io::Error::other)?;
+    std::fs::write(&report_path, json)?;
+
+    eprintln!(
+        "{} processed, {} failed in {:.1} s, report in {}",
+        report.processed,
+        report.failed,
+        report.total_millis as f64 / 1000.0,
+        report_path.display()
+    );
+    Ok(report.failed == 0)
+}
@@ -0,0 +1,111 @@
+// Run recorded frames through the stream processing, segmentation, temporal smoothing and
+// effects, without a camera or the app.
+//
+//     run_pipeline <input.y4m | input.camsession | image directory | glob>
+//         <output.y4m | output directory> [--blur] [--background IMAGE] [--mask] [--roi]
+//         [--refine] [--temporal] [--quality LEVEL] [--low-power] [--fps N] [--report FILE]
+//
+// Session archives recorded by the app replay the frames with the settings they had in the
+// stream, the effect and quality options are ignored for them.
+//
+// The report is a JSON file with the mask stats and processing time of each frame,
+// to compare runs of two versions on the same clip.
+//
+// Only built with the `cli` feature, which keeps it out of the app builds.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use rust_lib_flutter_rust_cam_test::runner::{self, RunSettings};
+
+const USAGE: &str = "Usage: run_pipeline <input.y4m | input.camsession | image directory | glob> \
+<output.y4m | output directory> [--blur] [--background IMAGE] [--mask] [--roi] [--refine] \
+[--temporal] [--quality LEVEL] [--low-power] [--fps N] [--report FILE]";
+
+struct Args {
+    input: String,
+    output: PathBuf,
+    report: Option<PathBuf>,
+    settings: RunSettings,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut positional = Vec::new();
+    let mut settings = RunSettings::default();
+    let mut report = None;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("{arg} needs a value"));
+        match arg.as_str() {
+            "--blur" => settings.blur = true,
+            "--background" => settings.background = Some(value()?.into()),
+            "--mask" => settings.debug = true,
+            "--roi" => settings.roi = true,
+            "--refine" => settings.refine = true,
+            "--temporal" => settings.temporal = true,
+            "--low-power" => settings.low_power = true,
+            "--quality" => {
+                let value = value()?;
+                settings.quality_level = value
+                    .parse()
+                    .map_err(|_| format!("Invalid quality level: {value}"))?;
+            }
+            "--fps" => {
+                let value = value()?;
+                settings.fps = value
+                    .parse()
+                    .map_err(|_| format!("Invalid frame rate: {value}"))?;
+            }
+            "--report" => report = Some(value()?.into()),
+            "-h" | "--help" => return Err(USAGE.into()),
+            _ => positional.push(arg),
+        }
+    }
+    let [input, output] = <[String; 2]>::try_from(positional).map_err(|_| USAGE)?;
+    Ok(Args {
+        input,
+        output: output.into(),
+        report,
+        settings,
+    })
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("{message}");
+            return ExitCode::FAILURE;
+        }
+    };
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: &Args) -> std::io::Result<()> {
+    let report = runner::run(&args.input, &args.output, &args.settings, |done| {
+        if done % 30 == 0 {
+            eprintln!("{done} frames");
+        }
+    })?;
+
+    let count = report.frames.len();
+    let average_ms = report.frames.iter().map(|f| f.process_ms).sum::<f64>() / count.max(1) as f64;
+    let inferences = report.frames.iter().filter(|f| f.inference).count();
+    eprintln!(
+        "{count} frames at {} fps in {:.1} s, {average_ms:.1} ms per frame, segmentation on {inferences}",
+        report.fps,
+        report.total_ms as f64 / 1000.0,
+    );
+
+    if let Some(path) = &args.report {
+        let json = serde_json::to_vec_pretty(&report).map_err(std::io::Error::other)?;
+        std::fs::write(path, json)?;
+    }
+    Ok(())
+}
@@ -37,7 +37,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 558903947;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 1241920464;
 
 // Section: executor
 
@@ -45,6 +45,76 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
+fn wire__crate__api__camera__benchmark_mask_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "benchmark_mask",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_iterations = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::camera::benchmark_mask(api_iterations))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__camera__capture_burst_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "capture_burst",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <u32>::sse_decode(&mut deserializer);
+            let api_options = <crate::api::camera::BurstOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::camera::capture_burst(api_id, api_options)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__camera__check_for_cameras_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -77,6 +147,116 @@ fn wire__crate__api__camera__check_for_cameras_impl(
         },
     )
 }
+fn wire__crate__api__recording__export_clip_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "export_clip",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <u32>::sse_decode(&mut deserializer);
+            let api_options = <crate::api::recording::ClipOptions>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::recording::ClipProgress,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::recording::export_clip(api_id, api_options, api_sink)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__recording__flag_dataset_frame_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "flag_dataset_frame",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::recording::flag_dataset_frame(api_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__camera__frame_allocation_stats_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "frame_allocation_stats",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::camera::frame_allocation_stats(api_id))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__simple__get_image_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -139,6 +319,38 @@ fn wire__crate__api__simple__greet_impl(
         },
     )
 }
+fn wire__crate__api__camera__has_lite_model_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "has_lite_model",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::camera::has_lite_model())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__simple__init_app_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -207,7 +419,7 @@ fn wire__crate__api__camera__init_cams_impl(
         },
     )
 }
-fn wire__crate__api__camera__set_background_impl(
+fn wire__crate__api__recording__pause_recording_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -215,7 +427,7 @@ fn wire__crate__api__camera__set_background_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_background",
+            debug_name: "pause_recording",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -229,12 +441,12 @@ fn wire__crate__api__camera__set_background_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_background = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_id = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
                     let output_ok = Result::<_, ()>::Ok({
-                        crate::api::camera::set_background(api_background);
+                        crate::api::recording::pause_recording(api_id);
                     })?;
                     Ok(output_ok)
                 })())
@@ -242,7 +454,7 @@ fn wire__crate__api__camera__set_background_impl(
         },
     )
 }
-fn wire__crate__api__camera__set_debug_impl(
+fn wire__crate__api__metrics__pipeline_stats_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -250,7 +462,7 @@ fn wire__crate__api__camera__set_debug_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_debug",
+            debug_name: "pipeline_stats",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -264,20 +476,19 @@ fn wire__crate__api__camera__set_debug_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_debug = <bool>::sse_decode(&mut deserializer);
+            let api_id = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::camera::set_debug(api_debug);
-                    })?;
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::metrics::pipeline_stats(api_id))?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__camera__set_mask_impl(
+fn wire__crate__api__camera__preload_models_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -285,7 +496,7 @@ fn wire__crate__api__camera__set_mask_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_mask",
+            debug_name: "preload_models",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -299,12 +510,11 @@ fn wire__crate__api__camera__set_mask_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_mask = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
                     let output_ok = Result::<_, ()>::Ok({
-                        crate::api::camera::set_mask(api_mask);
+                        crate::api::camera::preload_models();
                     })?;
                     Ok(output_ok)
                 })())
@@ -312,7 +522,7 @@ fn wire__crate__api__camera__set_mask_impl(
         },
     )
 }
-fn wire__crate__api__camera__stream_camera_impl(
+fn wire__crate__api__effects__process_image_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -320,7 +530,7 @@ fn wire__crate__api__camera__stream_camera_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "stream_camera",
+            debug_name: "process_image",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -334,16 +544,13 @@ fn wire__crate__api__camera__stream_camera_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_id = <u32>::sse_decode(&mut deserializer);
-            let api_sink =
-                <StreamSink<Vec<u8>, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(
-                    &mut deserializer,
-                );
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_effects = <crate::api::effects::ImageEffects>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::camera::stream_camera(api_id, api_sink)?;
+                        let output_ok = crate::api::effects::process_image(api_bytes, api_effects)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -351,190 +558,3103 @@ fn wire__crate__api__camera__stream_camera_impl(
         },
     )
 }
-
-// Section: dart2rust
-
-impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
-    }
-}
-
-impl SseDecode for StreamSink<Vec<u8>, flutter_rust_bridge::for_generated::SseCodec> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return StreamSink::deserialize(inner);
-    }
+fn wire__crate__api__camera__release_frame_slot_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "release_frame_slot",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_generation = <u64>::sse_decode(&mut deserializer);
+            let api_index = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::camera::release_frame_slot(api_generation, api_index);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
-    }
+fn wire__crate__api__recording__resume_recording_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "resume_recording",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::recording::resume_recording(api_id);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__recording__save_replay_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "save_replay",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <u32>::sse_decode(&mut deserializer);
+            let api_options =
+                <crate::api::recording::ReplaySaveOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::recording::save_replay(api_id, api_options)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__camera__set_adaptive_quality_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_adaptive_quality",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            let api_target_fps = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::camera::set_adaptive_quality(api_enabled, api_target_fps);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__camera__set_background_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_background",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_background = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::camera::set_background(api_background)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__camera__set_burst_history_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_burst_history",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <u32>::sse_decode(&mut deserializer);
+            let api_frames = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::camera::set_burst_history(api_id, api_frames)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__camera__set_compositing_threads_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_compositing_threads",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_threads = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::camera::set_compositing_threads(api_threads);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__camera__set_debug_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_debug",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_debug = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::camera::set_debug(api_debug);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__camera__set_frame_queue_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_frame_queue",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_depth = <u32>::sse_decode(&mut deserializer);
+            let api_drop_frames = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::camera::set_frame_queue(api_depth, api_drop_frames);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__logging__set_log_level_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_log_level",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_level = <crate::api::logging::LogLevel>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::logging::set_log_level(api_level);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__camera__set_mask_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_mask",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mask = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::camera::set_mask(api_mask);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__camera__set_mask_refinement_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_mask_refinement",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::camera::set_mask_refinement(api_enabled);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__camera__set_output_format_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_output_format",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_format = <crate::api::camera::PixelFormat>::sse_decode(&mut deserializer);
+            let api_quality = <u8>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::camera::set_output_format(api_format, api_quality);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__camera__set_power_mode_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_power_mode",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_mode = <crate::api::camera::PowerMode>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::camera::set_power_mode(api_mode);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__recording__set_replay_buffer_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_replay_buffer",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <u32>::sse_decode(&mut deserializer);
+            let api_options = <crate::api::recording::ReplayOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::recording::set_replay_buffer(api_id, api_options)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__camera__set_roi_mode_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_roi_mode",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::camera::set_roi_mode(api_enabled);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__camera__set_target_fps_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_target_fps",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_fps = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::camera::set_target_fps(api_fps);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__camera__set_temporal_smoothing_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_temporal_smoothing",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::camera::set_temporal_smoothing(api_enabled);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__recording__start_dataset_capture_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_dataset_capture",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <u32>::sse_decode(&mut deserializer);
+            let api_options =
+                <crate::api::recording::DatasetOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::recording::start_dataset_capture(api_id, api_options)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__recording__start_recording_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_recording",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <u32>::sse_decode(&mut deserializer);
+            let api_options =
+                <crate::api::recording::RecordingOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::recording::start_recording(api_id, api_options)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__recording__start_session_capture_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_session_capture",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <u32>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::recording::start_session_capture(api_id, api_path)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__recording__start_timelapse_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_timelapse",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <u32>::sse_decode(&mut deserializer);
+            let api_options =
+                <crate::api::recording::TimelapseOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::recording::start_timelapse(api_id, api_options)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__recording__stop_dataset_capture_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_dataset_capture",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::recording::stop_dataset_capture(api_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__recording__stop_recording_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_recording",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::recording::stop_recording(api_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__recording__stop_session_capture_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_session_capture",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::recording::stop_session_capture(api_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__recording__stop_timelapse_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_timelapse",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::recording::stop_timelapse(api_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__camera__stream_camera_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stream_camera",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <u32>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::camera::ProcessedFrame,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::camera::stream_camera(api_id, api_sink)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__camera__stream_camera_shared_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stream_camera_shared",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <u32>::sse_decode(&mut deserializer);
+            let api_slot_count = <u32>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::camera::FrameSlot,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::camera::stream_camera_shared(
+                            api_id,
+                            api_slot_count,
+                            api_sink,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__metrics__stream_pipeline_stats_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stream_pipeline_stats",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <u32>::sse_decode(&mut deserializer);
+            let api_interval_ms = <u32>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::metrics::PipelineStats,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::metrics::stream_pipeline_stats(
+                            api_id,
+                            api_interval_ms,
+                            api_sink,
+                        );
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__logging__subscribe_logs_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_logs",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::api::logging::LogEntry,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::logging::subscribe_logs(api_sink);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__camera__take_snapshot_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "take_snapshot",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <u32>::sse_decode(&mut deserializer);
+            let api_options = <crate::api::camera::SnapshotOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::camera::take_snapshot(api_id, api_options)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::recording::ClipProgress,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<crate::api::camera::FrameSlot, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<crate::api::logging::LogEntry, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<crate::api::metrics::PipelineStats, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<crate::api::camera::ProcessedFrame, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::api::camera::BurstFrame {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_width = <u32>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
+        let mut var_image = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_timestamp_us = <u64>::sse_decode(deserializer);
+        let mut var_sharpness = <f64>::sse_decode(deserializer);
+        let mut var_exposure = <f64>::sse_decode(deserializer);
+        let mut var_mask_quality = <f64>::sse_decode(deserializer);
+        let mut var_score = <f64>::sse_decode(deserializer);
+        return crate::api::camera::BurstFrame {
+            width: var_width,
+            height: var_height,
+            image: var_image,
+            timestamp_us: var_timestamp_us,
+            sharpness: var_sharpness,
+            exposure: var_exposure,
+            mask_quality: var_mask_quality,
+            score: var_score,
+        };
+    }
+}
+
+impl SseDecode for crate::api::camera::BurstOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_frames = <u32>::sse_decode(deserializer);
+        let mut var_frames_before = <u32>::sse_decode(deserializer);
+        let mut var_format = <crate::api::camera::SnapshotFormat>::sse_decode(deserializer);
+        let mut var_quality = <u8>::sse_decode(deserializer);
+        let mut var_return_all = <bool>::sse_decode(deserializer);
+        return crate::api::camera::BurstOptions {
+            frames: var_frames,
+            frames_before: var_frames_before,
+            format: var_format,
+            quality: var_quality,
+            return_all: var_return_all,
+        };
+    }
+}
+
+impl SseDecode for crate::api::camera::Cameras {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        return crate::api::camera::Cameras {
+            id: var_id,
+            name: var_name,
+        };
+    }
+}
+
+impl SseDecode for crate::api::recording::ClipFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::recording::ClipFormat::Gif,
+            1 => crate::api::recording::ClipFormat::WebP,
+            _ => unreachable!("Invalid variant for ClipFormat: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::recording::ClipOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_format = <crate::api::recording::ClipFormat>::sse_decode(deserializer);
+        let mut var_seconds = <f64>::sse_decode(deserializer);
+        let mut var_fps = <u32>::sse_decode(deserializer);
+        let mut var_width = <u32>::sse_decode(deserializer);
+        let mut var_quality = <u8>::sse_decode(deserializer);
+        return crate::api::recording::ClipOptions {
+            path: var_path,
+            format: var_format,
+            seconds: var_seconds,
+            fps: var_fps,
+            width: var_width,
+            quality: var_quality,
+        };
+    }
+}
+
+impl SseDecode for crate::api::recording::ClipProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_stage = <crate::api::recording::ClipStage>::sse_decode(deserializer);
+        let mut var_fraction = <f64>::sse_decode(deserializer);
+        let mut var_frames = <u32>::sse_decode(deserializer);
+        let mut var_bytes = <u64>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        return crate::api::recording::ClipProgress {
+            stage: var_stage,
+            fraction: var_fraction,
+            frames: var_frames,
+            bytes: var_bytes,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::api::recording::ClipStage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::recording::ClipStage::Capturing,
+            1 => crate::api::recording::ClipStage::Encoding,
+            2 => crate::api::recording::ClipStage::Done,
+            3 => crate::api::recording::ClipStage::Failed,
+            _ => unreachable!("Invalid variant for ClipStage: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::recording::DatasetOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_directory = <String>::sse_decode(deserializer);
+        let mut var_every_frames = <u32>::sse_decode(deserializer);
+        let mut var_max_confidence = <f32>::sse_decode(deserializer);
+        let mut var_low_confidence_spacing = <u32>::sse_decode(deserializer);
+        let mut var_max_frames = <u32>::sse_decode(deserializer);
+        return crate::api::recording::DatasetOptions {
+            directory: var_directory,
+            every_frames: var_every_frames,
+            max_confidence: var_max_confidence,
+            low_confidence_spacing: var_low_confidence_spacing,
+            max_frames: var_max_frames,
+        };
+    }
+}
+
+impl SseDecode for crate::api::recording::DatasetSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_frames_saved = <u32>::sse_decode(deserializer);
+        let mut var_interval_frames = <u32>::sse_decode(deserializer);
+        let mut var_low_confidence_frames = <u32>::sse_decode(deserializer);
+        let mut var_flagged_frames = <u32>::sse_decode(deserializer);
+        let mut var_frames_dropped = <u32>::sse_decode(deserializer);
+        return crate::api::recording::DatasetSummary {
+            frames_saved: var_frames_saved,
+            interval_frames: var_interval_frames,
+            low_confidence_frames: var_low_confidence_frames,
+            flagged_frames: var_flagged_frames,
+            frames_dropped: var_frames_dropped,
+        };
+    }
+}
+
+impl SseDecode for f32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::camera::FrameAllocationStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_frames = <u64>::sse_decode(deserializer);
+        let mut var_allocations = <u64>::sse_decode(deserializer);
+        let mut var_last_frame_allocations = <u64>::sse_decode(deserializer);
+        return crate::api::camera::FrameAllocationStats {
+            frames: var_frames,
+            allocations: var_allocations,
+            last_frame_allocations: var_last_frame_allocations,
+        };
+    }
+}
+
+impl SseDecode for crate::api::camera::FrameEffects {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_blur = <bool>::sse_decode(deserializer);
+        let mut var_background_replacement = <bool>::sse_decode(deserializer);
+        let mut var_mask_overlay = <bool>::sse_decode(deserializer);
+        let mut var_roi = <bool>::sse_decode(deserializer);
+        return crate::api::camera::FrameEffects {
+            blur: var_blur,
+            background_replacement: var_background_replacement,
+            mask_overlay: var_mask_overlay,
+            roi: var_roi,
+        };
+    }
+}
+
+impl SseDecode for crate::api::camera::FrameInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_width = <u32>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
+        let mut var_stride = <u32>::sse_decode(deserializer);
+        let mut var_pixel_format = <crate::api::camera::PixelFormat>::sse_decode(deserializer);
+        let mut var_capture_timestamp_us = <u64>::sse_decode(deserializer);
+        let mut var_sequence = <u64>::sse_decode(deserializer);
+        let mut var_latency_us = <u64>::sse_decode(deserializer);
+        let mut var_effects = <crate::api::camera::FrameEffects>::sse_decode(deserializer);
+        let mut var_quality_level = <u32>::sse_decode(deserializer);
+        return crate::api::camera::FrameInfo {
+            width: var_width,
+            height: var_height,
+            stride: var_stride,
+            pixel_format: var_pixel_format,
+            capture_timestamp_us: var_capture_timestamp_us,
+            sequence: var_sequence,
+            latency_us: var_latency_us,
+            effects: var_effects,
+            quality_level: var_quality_level,
+        };
+    }
+}
+
+impl SseDecode for crate::api::camera::FrameSlot {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_generation = <u64>::sse_decode(deserializer);
+        let mut var_index = <u32>::sse_decode(deserializer);
+        let mut var_address = <u64>::sse_decode(deserializer);
+        let mut var_len = <u32>::sse_decode(deserializer);
+        let mut var_info = <crate::api::camera::FrameInfo>::sse_decode(deserializer);
+        return crate::api::camera::FrameSlot {
+            generation: var_generation,
+            index: var_index,
+            address: var_address,
+            len: var_len,
+            info: var_info,
+        };
+    }
+}
+
+impl SseDecode for crate::api::effects::ImageEffects {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_blur = <bool>::sse_decode(deserializer);
+        let mut var_background = <Option<Vec<u8>>>::sse_decode(deserializer);
+        let mut var_debug = <bool>::sse_decode(deserializer);
+        return crate::api::effects::ImageEffects {
+            blur: var_blur,
+            background: var_background,
+            debug: var_debug,
+        };
+    }
+}
+
+impl SseDecode for Vec<crate::api::camera::BurstFrame> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::camera::BurstFrame>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::camera::Cameras> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::camera::Cameras>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::logging::LogEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_level = <crate::api::logging::LogLevel>::sse_decode(deserializer);
+        let mut var_target = <String>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_timestamp_us = <u64>::sse_decode(deserializer);
+        return crate::api::logging::LogEntry {
+            level: var_level,
+            target: var_target,
+            message: var_message,
+            timestamp_us: var_timestamp_us,
+        };
+    }
+}
+
+impl SseDecode for crate::api::logging::LogLevel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::logging::LogLevel::Error,
+            1 => crate::api::logging::LogLevel::Warn,
+            2 => crate::api::logging::LogLevel::Info,
+            3 => crate::api::logging::LogLevel::Debug,
+            4 => crate::api::logging::LogLevel::Trace,
+            _ => unreachable!("Invalid variant for LogLevel: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::camera::MaskBenchmark {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_reference_preprocess_us = <u64>::sse_decode(deserializer);
+        let mut var_reference_postprocess_us = <u64>::sse_decode(deserializer);
+        let mut var_fused_preprocess_us = <u64>::sse_decode(deserializer);
+        let mut var_fused_postprocess_us = <u64>::sse_decode(deserializer);
+        return crate::api::camera::MaskBenchmark {
+            reference_preprocess_us: var_reference_preprocess_us,
+            reference_postprocess_us: var_reference_postprocess_us,
+            fused_preprocess_us: var_fused_preprocess_us,
+            fused_postprocess_us: var_fused_postprocess_us,
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::camera::FrameAllocationStats> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::camera::FrameAllocationStats>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::metrics::PipelineStats> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::metrics::PipelineStats>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<u8>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<u8>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::metrics::PipelineStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_captured_frames = <u64>::sse_decode(deserializer);
+        let mut var_processed_frames = <u64>::sse_decode(deserializer);
+        let mut var_dropped_frames = <u64>::sse_decode(deserializer);
+        let mut var_capture_fps = <f64>::sse_decode(deserializer);
+        let mut var_output_fps = <f64>::sse_decode(deserializer);
+        let mut var_decode = <crate::api::metrics::StageStats>::sse_decode(deserializer);
+        let mut var_preprocess = <crate::api::metrics::StageStats>::sse_decode(deserializer);
+        let mut var_inference = <crate::api::metrics::StageStats>::sse_decode(deserializer);
+        let mut var_postprocess = <crate::api::metrics::StageStats>::sse_decode(deserializer);
+        let mut var_composite = <crate::api::metrics::StageStats>::sse_decode(deserializer);
+        let mut var_encode = <crate::api::metrics::StageStats>::sse_decode(deserializer);
+        let mut var_sink = <crate::api::metrics::StageStats>::sse_decode(deserializer);
+        let mut var_end_to_end = <crate::api::metrics::StageStats>::sse_decode(deserializer);
+        return crate::api::metrics::PipelineStats {
+            captured_frames: var_captured_frames,
+            processed_frames: var_processed_frames,
+            dropped_frames: var_dropped_frames,
+            capture_fps: var_capture_fps,
+            output_fps: var_output_fps,
+            decode: var_decode,
+            preprocess: var_preprocess,
+            inference: var_inference,
+            postprocess: var_postprocess,
+            composite: var_composite,
+            encode: var_encode,
+            sink: var_sink,
+            end_to_end: var_end_to_end,
+        };
+    }
+}
+
+impl SseDecode for crate::api::camera::PixelFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::camera::PixelFormat::Rgba8888,
+            1 => crate::api::camera::PixelFormat::Bgra8888,
+            2 => crate::api::camera::PixelFormat::Rgb888,
+            3 => crate::api::camera::PixelFormat::Nv12,
+            4 => crate::api::camera::PixelFormat::I420,
+            5 => crate::api::camera::PixelFormat::Jpeg,
+            6 => crate::api::camera::PixelFormat::Png,
+            _ => unreachable!("Invalid variant for PixelFormat: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::camera::PowerMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::camera::PowerMode::Normal,
+            1 => crate::api::camera::PowerMode::LowPower,
+            2 => crate::api::camera::PowerMode::Idle,
+            _ => unreachable!("Invalid variant for PowerMode: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::camera::ProcessedFrame {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_info = <crate::api::camera::FrameInfo>::sse_decode(deserializer);
+        let mut var_pixels = <Vec<u8>>::sse_decode(deserializer);
+        return crate::api::camera::ProcessedFrame {
+            info: var_info,
+            pixels: var_pixels,
+        };
+    }
+}
+
+impl SseDecode for crate::api::recording::RecordingFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::recording::RecordingFormat::Y4m,
+            1 => crate::api::recording::RecordingFormat::MjpegAvi,
+            _ => unreachable!("Invalid variant for RecordingFormat: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::recording::RecordingOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_format = <crate::api::recording::RecordingFormat>::sse_decode(deserializer);
+        let mut var_fps = <u32>::sse_decode(deserializer);
+        let mut var_quality = <u8>::sse_decode(deserializer);
+        let mut var_queue_depth = <u32>::sse_decode(deserializer);
+        return crate::api::recording::RecordingOptions {
+            path: var_path,
+            format: var_format,
+            fps: var_fps,
+            quality: var_quality,
+            queue_depth: var_queue_depth,
+        };
+    }
+}
+
+impl SseDecode for crate::api::recording::RecordingSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_frames_written = <u64>::sse_decode(deserializer);
+        let mut var_frames_dropped = <u64>::sse_decode(deserializer);
+        let mut var_duration_us = <u64>::sse_decode(deserializer);
+        return crate::api::recording::RecordingSummary {
+            frames_written: var_frames_written,
+            frames_dropped: var_frames_dropped,
+            duration_us: var_duration_us,
+        };
+    }
+}
+
+impl SseDecode for crate::api::recording::ReplayFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::recording::ReplayFormat::Y4m,
+            1 => crate::api::recording::ReplayFormat::MjpegAvi,
+            2 => crate::api::recording::ReplayFormat::PngSequence,
+            3 => crate::api::recording::ReplayFormat::JpegSequence,
+            _ => unreachable!("Invalid variant for ReplayFormat: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::recording::ReplayOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_seconds = <u32>::sse_decode(deserializer);
+        let mut var_max_memory_mb = <u32>::sse_decode(deserializer);
+        let mut var_jpeg_quality = <u8>::sse_decode(deserializer);
+        return crate::api::recording::ReplayOptions {
+            seconds: var_seconds,
+            max_memory_mb: var_max_memory_mb,
+            jpeg_quality: var_jpeg_quality,
+        };
+    }
+}
+
+impl SseDecode for crate::api::recording::ReplaySaveOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_format = <crate::api::recording::ReplayFormat>::sse_decode(deserializer);
+        let mut var_seconds = <u32>::sse_decode(deserializer);
+        let mut var_fps = <u32>::sse_decode(deserializer);
+        let mut var_quality = <u8>::sse_decode(deserializer);
+        return crate::api::recording::ReplaySaveOptions {
+            path: var_path,
+            format: var_format,
+            seconds: var_seconds,
+            fps: var_fps,
+            quality: var_quality,
+        };
+    }
+}
+
+impl SseDecode for crate::api::recording::SessionCaptureSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_frames = <u64>::sse_decode(deserializer);
+        let mut var_settings_changes = <u64>::sse_decode(deserializer);
+        let mut var_bytes = <u64>::sse_decode(deserializer);
+        return crate::api::recording::SessionCaptureSummary {
+            frames: var_frames,
+            settings_changes: var_settings_changes,
+            bytes: var_bytes,
+        };
+    }
+}
+
+impl SseDecode for crate::api::camera::Snapshot {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_width = <u32>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
+        let mut var_image = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_raw = <Option<Vec<u8>>>::sse_decode(deserializer);
+        let mut var_mask = <Option<Vec<u8>>>::sse_decode(deserializer);
+        let mut var_effects = <crate::api::camera::FrameEffects>::sse_decode(deserializer);
+        return crate::api::camera::Snapshot {
+            width: var_width,
+            height: var_height,
+            image: var_image,
+            raw: var_raw,
+            mask: var_mask,
+            effects: var_effects,
+        };
+    }
+}
+
+impl SseDecode for crate::api::camera::SnapshotFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::camera::SnapshotFormat::Png,
+            1 => crate::api::camera::SnapshotFormat::Jpeg,
+            2 => crate::api::camera::SnapshotFormat::WebP,
+            _ => unreachable!("Invalid variant for SnapshotFormat: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::camera::SnapshotOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_format = <crate::api::camera::SnapshotFormat>::sse_decode(deserializer);
+        let mut var_quality = <u8>::sse_decode(deserializer);
+        let mut var_include_raw = <bool>::sse_decode(deserializer);
+        let mut var_include_mask = <bool>::sse_decode(deserializer);
+        let mut var_full_resolution = <bool>::sse_decode(deserializer);
+        return crate::api::camera::SnapshotOptions {
+            format: var_format,
+            quality: var_quality,
+            include_raw: var_include_raw,
+            include_mask: var_include_mask,
+            full_resolution: var_full_resolution,
+        };
+    }
+}
+
+impl SseDecode for crate::api::metrics::StageStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_last_us = <u64>::sse_decode(deserializer);
+        let mut var_mean_us = <u64>::sse_decode(deserializer);
+        let mut var_p50_us = <u64>::sse_decode(deserializer);
+        let mut var_p95_us = <u64>::sse_decode(deserializer);
+        let mut var_p99_us = <u64>::sse_decode(deserializer);
+        let mut var_max_us = <u64>::sse_decode(deserializer);
+        return crate::api::metrics::StageStats {
+            last_us: var_last_us,
+            mean_us: var_mean_us,
+            p50_us: var_p50_us,
+            p95_us: var_p95_us,
+            p99_us: var_p99_us,
+            max_us: var_max_us,
+        };
+    }
+}
+
+impl SseDecode for crate::api::recording::TimelapseOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_directory = <String>::sse_decode(deserializer);
+        let mut var_interval_ms = <u64>::sse_decode(deserializer);
+        let mut var_every_frames = <u32>::sse_decode(deserializer);
+        let mut var_format = <crate::api::camera::SnapshotFormat>::sse_decode(deserializer);
+        let mut var_quality = <u8>::sse_decode(deserializer);
+        let mut var_pause_without_person = <bool>::sse_decode(deserializer);
+        let mut var_max_frames = <u32>::sse_decode(deserializer);
+        return crate::api::recording::TimelapseOptions {
+            directory: var_directory,
+            interval_ms: var_interval_ms,
+            every_frames: var_every_frames,
+            format: var_format,
+            quality: var_quality,
+            pause_without_person: var_pause_without_person,
+            max_frames: var_max_frames,
+        };
+    }
+}
+
+impl SseDecode for crate::api::recording::TimelapseSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_frames_saved = <u32>::sse_decode(deserializer);
+        let mut var_frames_without_person = <u64>::sse_decode(deserializer);
+        return crate::api::recording::TimelapseSummary {
+            frames_saved: var_frames_saved,
+            frames_without_person: var_frames_without_person,
+        };
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__camera__benchmark_mask_impl(port, ptr, rust_vec_len, data_len),
+        2 => wire__crate__api__camera__capture_burst_impl(port, ptr, rust_vec_len, data_len),
+        3 => wire__crate__api__camera__check_for_cameras_impl(port, ptr, rust_vec_len, data_len),
+        4 => wire__crate__api__recording__export_clip_impl(port, ptr, rust_vec_len, data_len),
+        5 => {
+            wire__crate__api__recording__flag_dataset_frame_impl(port, ptr, rust_vec_len, data_len)
+        }
+        6 => {
+            wire__crate__api__camera__frame_allocation_stats_impl(port, ptr, rust_vec_len, data_len)
+        }
+        9 => wire__crate__api__camera__has_lite_model_impl(port, ptr, rust_vec_len, data_len),
+        10 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
+        11 => wire__crate__api__camera__init_cams_impl(port, ptr, rust_vec_len, data_len),
+        12 => wire__crate__api__recording__pause_recording_impl(port, ptr, rust_vec_len, data_len),
+        13 => wire__crate__api__metrics__pipeline_stats_impl(port, ptr, rust_vec_len, data_len),
+        14 => wire__crate__api__camera__preload_models_impl(port, ptr, rust_vec_len, data_len),
+        15 => wire__crate__api__effects__process_image_impl(port, ptr, rust_vec_len, data_len),
+        16 => wire__crate__api__camera__release_frame_slot_impl(port, ptr, rust_vec_len, data_len),
+        17 => wire__crate__api__recording__resume_recording_impl(port, ptr, rust_vec_len, data_len),
+        18 => wire__crate__api__recording__save_replay_impl(port, ptr, rust_vec_len, data_len),
+        19 => {
+            wire__crate__api__camera__set_adaptive_quality_impl(port, ptr, rust_vec_len, data_len)
+        }
+        20 => wire__crate__api__camera__set_background_impl(port, ptr, rust_vec_len, data_len),
+        21 => wire__crate__api__camera__set_burst_history_impl(port, ptr, rust_vec_len, data_len),
+        22 => wire__crate__api__camera__set_compositing_threads_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        23 => wire__crate__api__camera__set_debug_impl(port, ptr, rust_vec_len, data_len),
+        24 => wire__crate__api__camera__set_frame_queue_impl(port, ptr, rust_vec_len, data_len),
+        26 => wire__crate__api__camera__set_mask_impl(port, ptr, rust_vec_len, data_len),
+        27 => wire__crate__api__camera__set_mask_refinement_impl(port, ptr, rust_vec_len, data_len),
+        28 => wire__crate__api__camera__set_output_format_impl(port, ptr, rust_vec_len, data_len),
+        29 => wire__crate__api__camera__set_power_mode_impl(port, ptr, rust_vec_len, data_len),
+        30 => {
+            wire__crate__api__recording__set_replay_buffer_impl(port, ptr, rust_vec_len, data_len)
+        }
+        31 => wire__crate__api__camera__set_roi_mode_impl(port, ptr, rust_vec_len, data_len),
+        32 => wire__crate__api__camera__set_target_fps_impl(port, ptr, rust_vec_len, data_len),
+        33 => {
+            wire__crate__api__camera__set_temporal_smoothing_impl(port, ptr, rust_vec_len, data_len)
+        }
+        34 => wire__crate__api__recording__start_dataset_capture_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        35 => wire__crate__api__recording__start_recording_impl(port, ptr, rust_vec_len, data_len),
+        36 => wire__crate__api__recording__start_session_capture_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        37 => wire__crate__api__recording__start_timelapse_impl(port, ptr, rust_vec_len, data_len),
+        38 => wire__crate__api__recording__stop_dataset_capture_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        39 => wire__crate__api__recording__stop_recording_impl(port, ptr, rust_vec_len, data_len),
+        40 => wire__crate__api__recording__stop_session_capture_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        41 => wire__crate__api__recording__stop_timelapse_impl(port, ptr, rust_vec_len, data_len),
+        42 => wire__crate__api__camera__stream_camera_impl(port, ptr, rust_vec_len, data_len),
+        43 => {
+            wire__crate__api__camera__stream_camera_shared_impl(port, ptr, rust_vec_len, data_len)
+        }
+        44 => {
+            wire__crate__api__metrics__stream_pipeline_stats_impl(port, ptr, rust_vec_len, data_len)
+        }
+        45 => wire__crate__api__logging__subscribe_logs_impl(port, ptr, rust_vec_len, data_len),
+        46 => wire__crate__api__camera__take_snapshot_impl(port, ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        7 => wire__crate__api__simple__get_image_impl(ptr, rust_vec_len, data_len),
+        8 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
+        25 => wire__crate__api__logging__set_log_level_impl(ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::camera::BurstFrame {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.image.into_into_dart().into_dart(),
+            self.timestamp_us.into_into_dart().into_dart(),
+            self.sharpness.into_into_dart().into_dart(),
+            self.exposure.into_into_dart().into_dart(),
+            self.mask_quality.into_into_dart().into_dart(),
+            self.score.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::camera::BurstFrame
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::camera::BurstFrame>
+    for crate::api::camera::BurstFrame
+{
+    fn into_into_dart(self) -> crate::api::camera::BurstFrame {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::camera::BurstOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.frames.into_into_dart().into_dart(),
+            self.frames_before.into_into_dart().into_dart(),
+            self.format.into_into_dart().into_dart(),
+            self.quality.into_into_dart().into_dart(),
+            self.return_all.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::camera::BurstOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::camera::BurstOptions>
+    for crate::api::camera::BurstOptions
+{
+    fn into_into_dart(self) -> crate::api::camera::BurstOptions {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::camera::Cameras {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::camera::Cameras {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::camera::Cameras>
+    for crate::api::camera::Cameras
+{
+    fn into_into_dart(self) -> crate::api::camera::Cameras {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::recording::ClipFormat {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Gif => 0.into_dart(),
+            Self::WebP => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::recording::ClipFormat
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::recording::ClipFormat>
+    for crate::api::recording::ClipFormat
+{
+    fn into_into_dart(self) -> crate::api::recording::ClipFormat {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::recording::ClipOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.path.into_into_dart().into_dart(),
+            self.format.into_into_dart().into_dart(),
+            self.seconds.into_into_dart().into_dart(),
+            self.fps.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.quality.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::recording::ClipOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::recording::ClipOptions>
+    for crate::api::recording::ClipOptions
+{
+    fn into_into_dart(self) -> crate::api::recording::ClipOptions {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::recording::ClipProgress {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.stage.into_into_dart().into_dart(),
+            self.fraction.into_into_dart().into_dart(),
+            self.frames.into_into_dart().into_dart(),
+            self.bytes.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::recording::ClipProgress
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::recording::ClipProgress>
+    for crate::api::recording::ClipProgress
+{
+    fn into_into_dart(self) -> crate::api::recording::ClipProgress {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::recording::ClipStage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Capturing => 0.into_dart(),
+            Self::Encoding => 1.into_dart(),
+            Self::Done => 2.into_dart(),
+            Self::Failed => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::recording::ClipStage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::recording::ClipStage>
+    for crate::api::recording::ClipStage
+{
+    fn into_into_dart(self) -> crate::api::recording::ClipStage {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::recording::DatasetOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.directory.into_into_dart().into_dart(),
+            self.every_frames.into_into_dart().into_dart(),
+            self.max_confidence.into_into_dart().into_dart(),
+            self.low_confidence_spacing.into_into_dart().into_dart(),
+            self.max_frames.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::recording::DatasetOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::recording::DatasetOptions>
+    for crate::api::recording::DatasetOptions
+{
+    fn into_into_dart(self) -> crate::api::recording::DatasetOptions {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::recording::DatasetSummary {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.frames_saved.into_into_dart().into_dart(),
+            self.interval_frames.into_into_dart().into_dart(),
+            self.low_confidence_frames.into_into_dart().into_dart(),
+            self.flagged_frames.into_into_dart().into_dart(),
+            self.frames_dropped.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::recording::DatasetSummary
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::recording::DatasetSummary>
+    for crate::api::recording::DatasetSummary
+{
+    fn into_into_dart(self) -> crate::api::recording::DatasetSummary {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::camera::FrameAllocationStats {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.frames.into_into_dart().into_dart(),
+            self.allocations.into_into_dart().into_dart(),
+            self.last_frame_allocations.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::camera::FrameAllocationStats
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::camera::FrameAllocationStats>
+    for crate::api::camera::FrameAllocationStats
+{
+    fn into_into_dart(self) -> crate::api::camera::FrameAllocationStats {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::camera::FrameEffects {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.blur.into_into_dart().into_dart(),
+            self.background_replacement.into_into_dart().into_dart(),
+            self.mask_overlay.into_into_dart().into_dart(),
+            self.roi.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::camera::FrameEffects
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::camera::FrameEffects>
+    for crate::api::camera::FrameEffects
+{
+    fn into_into_dart(self) -> crate::api::camera::FrameEffects {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::camera::FrameInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.stride.into_into_dart().into_dart(),
+            self.pixel_format.into_into_dart().into_dart(),
+            self.capture_timestamp_us.into_into_dart().into_dart(),
+            self.sequence.into_into_dart().into_dart(),
+            self.latency_us.into_into_dart().into_dart(),
+            self.effects.into_into_dart().into_dart(),
+            self.quality_level.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::camera::FrameInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::camera::FrameInfo>
+    for crate::api::camera::FrameInfo
+{
+    fn into_into_dart(self) -> crate::api::camera::FrameInfo {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::camera::FrameSlot {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.generation.into_into_dart().into_dart(),
+            self.index.into_into_dart().into_dart(),
+            self.address.into_into_dart().into_dart(),
+            self.len.into_into_dart().into_dart(),
+            self.info.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::camera::FrameSlot {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::camera::FrameSlot>
+    for crate::api::camera::FrameSlot
+{
+    fn into_into_dart(self) -> crate::api::camera::FrameSlot {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::effects::ImageEffects {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.blur.into_into_dart().into_dart(),
+            self.background.into_into_dart().into_dart(),
+            self.debug.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::effects::ImageEffects
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::effects::ImageEffects>
+    for crate::api::effects::ImageEffects
+{
+    fn into_into_dart(self) -> crate::api::effects::ImageEffects {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::logging::LogEntry {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.level.into_into_dart().into_dart(),
+            self.target.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.timestamp_us.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::logging::LogEntry {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::logging::LogEntry>
+    for crate::api::logging::LogEntry
+{
+    fn into_into_dart(self) -> crate::api::logging::LogEntry {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::logging::LogLevel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Error => 0.into_dart(),
+            Self::Warn => 1.into_dart(),
+            Self::Info => 2.into_dart(),
+            Self::Debug => 3.into_dart(),
+            Self::Trace => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::logging::LogLevel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::logging::LogLevel>
+    for crate::api::logging::LogLevel
+{
+    fn into_into_dart(self) -> crate::api::logging::LogLevel {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::camera::MaskBenchmark {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.reference_preprocess_us.into_into_dart().into_dart(),
+            self.reference_postprocess_us.into_into_dart().into_dart(),
+            self.fused_preprocess_us.into_into_dart().into_dart(),
+            self.fused_postprocess_us.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::camera::MaskBenchmark
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::camera::MaskBenchmark>
+    for crate::api::camera::MaskBenchmark
+{
+    fn into_into_dart(self) -> crate::api::camera::MaskBenchmark {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::metrics::PipelineStats {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.captured_frames.into_into_dart().into_dart(),
+            self.processed_frames.into_into_dart().into_dart(),
+            self.dropped_frames.into_into_dart().into_dart(),
+            self.capture_fps.into_into_dart().into_dart(),
+            self.output_fps.into_into_dart().into_dart(),
+            self.decode.into_into_dart().into_dart(),
+            self.preprocess.into_into_dart().into_dart(),
+            self.inference.into_into_dart().into_dart(),
+            self.postprocess.into_into_dart().into_dart(),
+            self.composite.into_into_dart().into_dart(),
+            self.encode.into_into_dart().into_dart(),
+            self.sink.into_into_dart().into_dart(),
+            self.end_to_end.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::metrics::PipelineStats
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::metrics::PipelineStats>
+    for crate::api::metrics::PipelineStats
+{
+    fn into_into_dart(self) -> crate::api::metrics::PipelineStats {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::camera::PixelFormat {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Rgba8888 => 0.into_dart(),
+            Self::Bgra8888 => 1.into_dart(),
+            Self::Rgb888 => 2.into_dart(),
+            Self::Nv12 => 3.into_dart(),
+            Self::I420 => 4.into_dart(),
+            Self::Jpeg => 5.into_dart(),
+            Self::Png => 6.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::camera::PixelFormat
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::camera::PixelFormat>
+    for crate::api::camera::PixelFormat
+{
+    fn into_into_dart(self) -> crate::api::camera::PixelFormat {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::camera::PowerMode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Normal => 0.into_dart(),
+            Self::LowPower => 1.into_dart(),
+            Self::Idle => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::camera::PowerMode {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::camera::PowerMode>
+    for crate::api::camera::PowerMode
+{
+    fn into_into_dart(self) -> crate::api::camera::PowerMode {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::camera::ProcessedFrame {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.info.into_into_dart().into_dart(),
+            self.pixels.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::camera::ProcessedFrame
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::camera::ProcessedFrame>
+    for crate::api::camera::ProcessedFrame
+{
+    fn into_into_dart(self) -> crate::api::camera::ProcessedFrame {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::recording::RecordingFormat {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Y4m => 0.into_dart(),
+            Self::MjpegAvi => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::recording::RecordingFormat
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::recording::RecordingFormat>
+    for crate::api::recording::RecordingFormat
+{
+    fn into_into_dart(self) -> crate::api::recording::RecordingFormat {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::recording::RecordingOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.path.into_into_dart().into_dart(),
+            self.format.into_into_dart().into_dart(),
+            self.fps.into_into_dart().into_dart(),
+            self.quality.into_into_dart().into_dart(),
+            self.queue_depth.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::recording::RecordingOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::recording::RecordingOptions>
+    for crate::api::recording::RecordingOptions
+{
+    fn into_into_dart(self) -> crate::api::recording::RecordingOptions {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::recording::RecordingSummary {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.frames_written.into_into_dart().into_dart(),
+            self.frames_dropped.into_into_dart().into_dart(),
+            self.duration_us.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::recording::RecordingSummary
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::recording::RecordingSummary>
+    for crate::api::recording::RecordingSummary
+{
+    fn into_into_dart(self) -> crate::api::recording::RecordingSummary {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::recording::ReplayFormat {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Y4m => 0.into_dart(),
+            Self::MjpegAvi => 1.into_dart(),
+            Self::PngSequence => 2.into_dart(),
+            Self::JpegSequence => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::recording::ReplayFormat
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::recording::ReplayFormat>
+    for crate::api::recording::ReplayFormat
+{
+    fn into_into_dart(self) -> crate::api::recording::ReplayFormat {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::recording::ReplayOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.seconds.into_into_dart().into_dart(),
+            self.max_memory_mb.into_into_dart().into_dart(),
+            self.jpeg_quality.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::recording::ReplayOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::recording::ReplayOptions>
+    for crate::api::recording::ReplayOptions
+{
+    fn into_into_dart(self) -> crate::api::recording::ReplayOptions {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::recording::ReplaySaveOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.path.into_into_dart().into_dart(),
+            self.format.into_into_dart().into_dart(),
+            self.seconds.into_into_dart().into_dart(),
+            self.fps.into_into_dart().into_dart(),
+            self.quality.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::recording::ReplaySaveOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::recording::ReplaySaveOptions>
+    for crate::api::recording::ReplaySaveOptions
+{
+    fn into_into_dart(self) -> crate::api::recording::ReplaySaveOptions {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::recording::SessionCaptureSummary {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.frames.into_into_dart().into_dart(),
+            self.settings_changes.into_into_dart().into_dart(),
+            self.bytes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::recording::SessionCaptureSummary
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::recording::SessionCaptureSummary>
+    for crate::api::recording::SessionCaptureSummary
+{
+    fn into_into_dart(self) -> crate::api::recording::SessionCaptureSummary {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::camera::Snapshot {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.image.into_into_dart().into_dart(),
+            self.raw.into_into_dart().into_dart(),
+            self.mask.into_into_dart().into_dart(),
+            self.effects.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::camera::Snapshot {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::camera::Snapshot>
+    for crate::api::camera::Snapshot
+{
+    fn into_into_dart(self) -> crate::api::camera::Snapshot {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::camera::SnapshotFormat {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Png => 0.into_dart(),
+            Self::Jpeg => 1.into_dart(),
+            Self::WebP => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::camera::SnapshotFormat
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::camera::SnapshotFormat>
+    for crate::api::camera::SnapshotFormat
+{
+    fn into_into_dart(self) -> crate::api::camera::SnapshotFormat {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::camera::SnapshotOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.format.into_into_dart().into_dart(),
+            self.quality.into_into_dart().into_dart(),
+            self.include_raw.into_into_dart().into_dart(),
+            self.include_mask.into_into_dart().into_dart(),
+            self.full_resolution.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::camera::SnapshotOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::camera::SnapshotOptions>
+    for crate::api::camera::SnapshotOptions
+{
+    fn into_into_dart(self) -> crate::api::camera::SnapshotOptions {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::metrics::StageStats {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.last_us.into_into_dart().into_dart(),
+            self.mean_us.into_into_dart().into_dart(),
+            self.p50_us.into_into_dart().into_dart(),
+            self.p95_us.into_into_dart().into_dart(),
+            self.p99_us.into_into_dart().into_dart(),
+            self.max_us.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::metrics::StageStats
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::metrics::StageStats>
+    for crate::api::metrics::StageStats
+{
+    fn into_into_dart(self) -> crate::api::metrics::StageStats {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::recording::TimelapseOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.directory.into_into_dart().into_dart(),
+            self.interval_ms.into_into_dart().into_dart(),
+            self.every_frames.into_into_dart().into_dart(),
+            self.format.into_into_dart().into_dart(),
+            self.quality.into_into_dart().into_dart(),
+            self.pause_without_person.into_into_dart().into_dart(),
+            self.max_frames.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::recording::TimelapseOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::recording::TimelapseOptions>
+    for crate::api::recording::TimelapseOptions
+{
+    fn into_into_dart(self) -> crate::api::recording::TimelapseOptions {
+        self
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::recording::TimelapseSummary {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.frames_saved.into_into_dart().into_dart(),
+            self.frames_without_person.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::recording::TimelapseSummary
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::recording::TimelapseSummary>
+    for crate::api::recording::TimelapseSummary
+{
+    fn into_into_dart(self) -> crate::api::recording::TimelapseSummary {
+        self
+    }
+}
+
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        crate::api::recording::ClipProgress,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<crate::api::camera::FrameSlot, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<crate::api::logging::LogEntry, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<crate::api::metrics::PipelineStats, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
 }
 
-impl SseDecode for bool {
+impl SseEncode
+    for StreamSink<crate::api::camera::ProcessedFrame, flutter_rust_bridge::for_generated::SseCodec>
+{
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
     }
 }
 
-impl SseDecode for crate::api::camera::Cameras {
+impl SseEncode for String {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <String>::sse_decode(deserializer);
-        let mut var_name = <String>::sse_decode(deserializer);
-        return crate::api::camera::Cameras {
-            id: var_id,
-            name: var_name,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
     }
 }
 
-impl SseDecode for Vec<crate::api::camera::Cameras> {
+impl SseEncode for bool {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::camera::Cameras>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseEncode for crate::api::camera::BurstFrame {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.width, serializer);
+        <u32>::sse_encode(self.height, serializer);
+        <Vec<u8>>::sse_encode(self.image, serializer);
+        <u64>::sse_encode(self.timestamp_us, serializer);
+        <f64>::sse_encode(self.sharpness, serializer);
+        <f64>::sse_encode(self.exposure, serializer);
+        <f64>::sse_encode(self.mask_quality, serializer);
+        <f64>::sse_encode(self.score, serializer);
     }
 }
 
-impl SseDecode for u32 {
+impl SseEncode for crate::api::camera::BurstOptions {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.frames, serializer);
+        <u32>::sse_encode(self.frames_before, serializer);
+        <crate::api::camera::SnapshotFormat>::sse_encode(self.format, serializer);
+        <u8>::sse_encode(self.quality, serializer);
+        <bool>::sse_encode(self.return_all, serializer);
     }
 }
 
-impl SseDecode for u8 {
+impl SseEncode for crate::api::camera::Cameras {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.name, serializer);
     }
 }
 
-impl SseDecode for () {
+impl SseEncode for crate::api::recording::ClipFormat {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::recording::ClipFormat::Gif => 0,
+                crate::api::recording::ClipFormat::WebP => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
 }
 
-impl SseDecode for i32 {
+impl SseEncode for crate::api::recording::ClipOptions {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.path, serializer);
+        <crate::api::recording::ClipFormat>::sse_encode(self.format, serializer);
+        <f64>::sse_encode(self.seconds, serializer);
+        <u32>::sse_encode(self.fps, serializer);
+        <u32>::sse_encode(self.width, serializer);
+        <u8>::sse_encode(self.quality, serializer);
     }
 }
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__camera__check_for_cameras_impl(port, ptr, rust_vec_len, data_len),
-        4 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
-        5 => wire__crate__api__camera__init_cams_impl(port, ptr, rust_vec_len, data_len),
-        6 => wire__crate__api__camera__set_background_impl(port, ptr, rust_vec_len, data_len),
-        7 => wire__crate__api__camera__set_debug_impl(port, ptr, rust_vec_len, data_len),
-        8 => wire__crate__api__camera__set_mask_impl(port, ptr, rust_vec_len, data_len),
-        9 => wire__crate__api__camera__stream_camera_impl(port, ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode for crate::api::recording::ClipProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::recording::ClipStage>::sse_encode(self.stage, serializer);
+        <f64>::sse_encode(self.fraction, serializer);
+        <u32>::sse_encode(self.frames, serializer);
+        <u64>::sse_encode(self.bytes, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
     }
 }
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        2 => wire__crate__api__simple__get_image_impl(ptr, rust_vec_len, data_len),
-        3 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode for crate::api::recording::ClipStage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::recording::ClipStage::Capturing => 0,
+                crate::api::recording::ClipStage::Encoding => 1,
+                crate::api::recording::ClipStage::Done => 2,
+                crate::api::recording::ClipStage::Failed => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-// Section: rust2dart
+impl SseEncode for crate::api::recording::DatasetOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.directory, serializer);
+        <u32>::sse_encode(self.every_frames, serializer);
+        <f32>::sse_encode(self.max_confidence, serializer);
+        <u32>::sse_encode(self.low_confidence_spacing, serializer);
+        <u32>::sse_encode(self.max_frames, serializer);
+    }
+}
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::camera::Cameras {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.name.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+impl SseEncode for crate::api::recording::DatasetSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.frames_saved, serializer);
+        <u32>::sse_encode(self.interval_frames, serializer);
+        <u32>::sse_encode(self.low_confidence_frames, serializer);
+        <u32>::sse_encode(self.flagged_frames, serializer);
+        <u32>::sse_encode(self.frames_dropped, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::camera::Cameras {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::camera::Cameras>
-    for crate::api::camera::Cameras
-{
-    fn into_into_dart(self) -> crate::api::camera::Cameras {
-        self
+
+impl SseEncode for f32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f32::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+impl SseEncode for f64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(format!("{:?}", self), serializer);
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseEncode for StreamSink<Vec<u8>, flutter_rust_bridge::for_generated::SseCodec> {
+impl SseEncode for crate::api::camera::FrameAllocationStats {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        unimplemented!("")
+        <u64>::sse_encode(self.frames, serializer);
+        <u64>::sse_encode(self.allocations, serializer);
+        <u64>::sse_encode(self.last_frame_allocations, serializer);
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for crate::api::camera::FrameEffects {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <bool>::sse_encode(self.blur, serializer);
+        <bool>::sse_encode(self.background_replacement, serializer);
+        <bool>::sse_encode(self.mask_overlay, serializer);
+        <bool>::sse_encode(self.roi, serializer);
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for crate::api::camera::FrameInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <u32>::sse_encode(self.width, serializer);
+        <u32>::sse_encode(self.height, serializer);
+        <u32>::sse_encode(self.stride, serializer);
+        <crate::api::camera::PixelFormat>::sse_encode(self.pixel_format, serializer);
+        <u64>::sse_encode(self.capture_timestamp_us, serializer);
+        <u64>::sse_encode(self.sequence, serializer);
+        <u64>::sse_encode(self.latency_us, serializer);
+        <crate::api::camera::FrameEffects>::sse_encode(self.effects, serializer);
+        <u32>::sse_encode(self.quality_level, serializer);
     }
 }
 
-impl SseEncode for crate::api::camera::Cameras {
+impl SseEncode for crate::api::camera::FrameSlot {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.id, serializer);
-        <String>::sse_encode(self.name, serializer);
+        <u64>::sse_encode(self.generation, serializer);
+        <u32>::sse_encode(self.index, serializer);
+        <u64>::sse_encode(self.address, serializer);
+        <u32>::sse_encode(self.len, serializer);
+        <crate::api::camera::FrameInfo>::sse_encode(self.info, serializer);
+    }
+}
+
+impl SseEncode for crate::api::effects::ImageEffects {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.blur, serializer);
+        <Option<Vec<u8>>>::sse_encode(self.background, serializer);
+        <bool>::sse_encode(self.debug, serializer);
+    }
+}
+
+impl SseEncode for Vec<crate::api::camera::BurstFrame> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::camera::BurstFrame>::sse_encode(item, serializer);
+        }
     }
 }
 
@@ -558,6 +3678,306 @@ impl SseEncode for Vec<u8> {
     }
 }
 
+impl SseEncode for crate::api::logging::LogEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::logging::LogLevel>::sse_encode(self.level, serializer);
+        <String>::sse_encode(self.target, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <u64>::sse_encode(self.timestamp_us, serializer);
+    }
+}
+
+impl SseEncode for crate::api::logging::LogLevel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::logging::LogLevel::Error => 0,
+                crate::api::logging::LogLevel::Warn => 1,
+                crate::api::logging::LogLevel::Info => 2,
+                crate::api::logging::LogLevel::Debug => 3,
+                crate::api::logging::LogLevel::Trace => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::camera::MaskBenchmark {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.reference_preprocess_us, serializer);
+        <u64>::sse_encode(self.reference_postprocess_us, serializer);
+        <u64>::sse_encode(self.fused_preprocess_us, serializer);
+        <u64>::sse_encode(self.fused_postprocess_us, serializer);
+    }
+}
+
+impl SseEncode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::camera::FrameAllocationStats> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::camera::FrameAllocationStats>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::metrics::PipelineStats> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::metrics::PipelineStats>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<Vec<u8>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<u8>>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for crate::api::metrics::PipelineStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.captured_frames, serializer);
+        <u64>::sse_encode(self.processed_frames, serializer);
+        <u64>::sse_encode(self.dropped_frames, serializer);
+        <f64>::sse_encode(self.capture_fps, serializer);
+        <f64>::sse_encode(self.output_fps, serializer);
+        <crate::api::metrics::StageStats>::sse_encode(self.decode, serializer);
+        <crate::api::metrics::StageStats>::sse_encode(self.preprocess, serializer);
+        <crate::api::metrics::StageStats>::sse_encode(self.inference, serializer);
+        <crate::api::metrics::StageStats>::sse_encode(self.postprocess, serializer);
+        <crate::api::metrics::StageStats>::sse_encode(self.composite, serializer);
+        <crate::api::metrics::StageStats>::sse_encode(self.encode, serializer);
+        <crate::api::metrics::StageStats>::sse_encode(self.sink, serializer);
+        <crate::api::metrics::StageStats>::sse_encode(self.end_to_end, serializer);
+    }
+}
+
+impl SseEncode for crate::api::camera::PixelFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::camera::PixelFormat::Rgba8888 => 0,
+                crate::api::camera::PixelFormat::Bgra8888 => 1,
+                crate::api::camera::PixelFormat::Rgb888 => 2,
+                crate::api::camera::PixelFormat::Nv12 => 3,
+                crate::api::camera::PixelFormat::I420 => 4,
+                crate::api::camera::PixelFormat::Jpeg => 5,
+                crate::api::camera::PixelFormat::Png => 6,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::camera::PowerMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::camera::PowerMode::Normal => 0,
+                crate::api::camera::PowerMode::LowPower => 1,
+                crate::api::camera::PowerMode::Idle => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::camera::ProcessedFrame {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::camera::FrameInfo>::sse_encode(self.info, serializer);
+        <Vec<u8>>::sse_encode(self.pixels, serializer);
+    }
+}
+
+impl SseEncode for crate::api::recording::RecordingFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::recording::RecordingFormat::Y4m => 0,
+                crate::api::recording::RecordingFormat::MjpegAvi => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::recording::RecordingOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.path, serializer);
+        <crate::api::recording::RecordingFormat>::sse_encode(self.format, serializer);
+        <u32>::sse_encode(self.fps, serializer);
+        <u8>::sse_encode(self.quality, serializer);
+        <u32>::sse_encode(self.queue_depth, serializer);
+    }
+}
+
+impl SseEncode for crate::api::recording::RecordingSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.frames_written, serializer);
+        <u64>::sse_encode(self.frames_dropped, serializer);
+        <u64>::sse_encode(self.duration_us, serializer);
+    }
+}
+
+impl SseEncode for crate::api::recording::ReplayFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::recording::ReplayFormat::Y4m => 0,
+                crate::api::recording::ReplayFormat::MjpegAvi => 1,
+                crate::api::recording::ReplayFormat::PngSequence => 2,
+                crate::api::recording::ReplayFormat::JpegSequence => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::recording::ReplayOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.seconds, serializer);
+        <u32>::sse_encode(self.max_memory_mb, serializer);
+        <u8>::sse_encode(self.jpeg_quality, serializer);
+    }
+}
+
+impl SseEncode for crate::api::recording::ReplaySaveOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.path, serializer);
+        <crate::api::recording::ReplayFormat>::sse_encode(self.format, serializer);
+        <u32>::sse_encode(self.seconds, serializer);
+        <u32>::sse_encode(self.fps, serializer);
+        <u8>::sse_encode(self.quality, serializer);
+    }
+}
+
+impl SseEncode for crate::api::recording::SessionCaptureSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.frames, serializer);
+        <u64>::sse_encode(self.settings_changes, serializer);
+        <u64>::sse_encode(self.bytes, serializer);
+    }
+}
+
+impl SseEncode for crate::api::camera::Snapshot {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.width, serializer);
+        <u32>::sse_encode(self.height, serializer);
+        <Vec<u8>>::sse_encode(self.image, serializer);
+        <Option<Vec<u8>>>::sse_encode(self.raw, serializer);
+        <Option<Vec<u8>>>::sse_encode(self.mask, serializer);
+        <crate::api::camera::FrameEffects>::sse_encode(self.effects, serializer);
+    }
+}
+
+impl SseEncode for crate::api::camera::SnapshotFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::camera::SnapshotFormat::Png => 0,
+                crate::api::camera::SnapshotFormat::Jpeg => 1,
+                crate::api::camera::SnapshotFormat::WebP => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::camera::SnapshotOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::camera::SnapshotFormat>::sse_encode(self.format, serializer);
+        <u8>::sse_encode(self.quality, serializer);
+        <bool>::sse_encode(self.include_raw, serializer);
+        <bool>::sse_encode(self.include_mask, serializer);
+        <bool>::sse_encode(self.full_resolution, serializer);
+    }
+}
+
+impl SseEncode for crate::api::metrics::StageStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.last_us, serializer);
+        <u64>::sse_encode(self.mean_us, serializer);
+        <u64>::sse_encode(self.p50_us, serializer);
+        <u64>::sse_encode(self.p95_us, serializer);
+        <u64>::sse_encode(self.p99_us, serializer);
+        <u64>::sse_encode(self.max_us, serializer);
+    }
+}
+
+impl SseEncode for crate::api::recording::TimelapseOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.directory, serializer);
+        <u64>::sse_encode(self.interval_ms, serializer);
+        <u32>::sse_encode(self.every_frames, serializer);
+        <crate::api::camera::SnapshotFormat>::sse_encode(self.format, serializer);
+        <u8>::sse_encode(self.quality, serializer);
+        <bool>::sse_encode(self.pause_without_person, serializer);
+        <u32>::sse_encode(self.max_frames, serializer);
+    }
+}
+
+impl SseEncode for crate::api::recording::TimelapseSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.frames_saved, serializer);
+        <u64>::sse_encode(self.frames_without_person, serializer);
+    }
+}
+
 impl SseEncode for u32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -565,6 +3985,13 @@ impl SseEncode for u32 {
     }
 }
 
+impl SseEncode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u64::<NativeEndian>(self).unwrap();
+    }
+}
+
 impl SseEncode for u8 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
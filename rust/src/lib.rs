@@ -1,3 +1,7 @@
 pub mod api;
+pub mod batch;
 mod frb_generated;
+mod media;
 mod ml;
+mod pipeline;
+pub mod runner;
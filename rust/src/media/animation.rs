@@ -0,0 +1,184 @@
+// Animated GIF and WebP encoding, for short looping clips.
+
+use image::codecs::gif::{GifEncoder, Repeat};
+use image::codecs::webp::WebPEncoder;
+use image::{Delay, ExtendedColorType, Frame, ImageEncoder, ImageError, ImageResult, RgbaImage};
+
+use super::convert::rgba_to_rgb;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnimationFormat {
+    /// 256 colors palette per frame, `speed` from 1, best, to 30, fastest.
+    Gif { speed: u8 },
+    /// Lossless frames, larger files than GIF but without banding.
+    WebP,
+}
+
+pub struct AnimationFrame {
+    pub image: RgbaImage,
+    pub delay_ms: u32,
+}
+
+/// Encode looping frames, calling `progress` with the number of frames done.
+pub fn encode(
+    frames: Vec<AnimationFrame>,
+    format: AnimationFormat,
+    mut progress: impl FnMut(usize),
+) -> ImageResult<Vec<u8>> {
+    let mut out = Vec::new();
+    match format {
+        AnimationFormat::Gif { speed } => {
+            let mut encoder = GifEncoder::new_with_speed(&mut out, speed.clamp(1, 30) as i32);
+            encoder.set_repeat(Repeat::Infinite)?;
+            for (i, frame) in frames.into_iter().enumerate() {
+                let delay = Delay::from_numer_denom_ms(frame.delay_ms, 1);
+                encoder.encode_frame(Frame::from_parts(frame.image, 0, 0, delay))?;
+                progress(i + 1);
+            }
+        }
+        AnimationFormat::WebP => encode_webp(&frames, &mut out, progress)?,
+    }
+    Ok(out)
+}
+
+// The image crate only writes still WebP, so each frame is encoded alone and its
+// bitstream moved to an animation frame. See
+// https://developers.google.com/speed/webp/docs/riff_container#animation
+fn encode_webp(
+    frames: &[AnimationFrame],
+    out: &mut Vec<u8>,
+    mut progress: impl FnMut(usize),
+) -> ImageResult<()> {
+    let Some(first) = frames.first() else {
+        return Ok(());
+    };
+    let (width, height) = first.image.dimensions();
+
+    out.extend_from_slice(b"RIFF\0\0\0\0WEBP");
+    let mut header = vec![0x02, 0, 0, 0]; // animation flag
+    header.extend_from_slice(&u24(width - 1));
+    header.extend_from_slice(&u24(height - 1));
+    write_chunk(out, b"VP8X", &header);
+    write_chunk(out, b"ANIM", &[0, 0, 0, 0, 0, 0]); // background color, loop forever
+
+    let mut rgb = Vec::new();
+    let mut still = Vec::new();
+    for (i, frame) in frames.iter().enumerate() {
+        let (w, h) = frame.image.dimensions();
+        rgb.clear();
+        rgba_to_rgb(frame.image.as_raw(), &mut rgb);
+        still.clear();
+        WebPEncoder::new_lossless(&mut still).write_image(&rgb, w, h, ExtendedColorType::Rgb8)?;
+        // RIFF header, then a single VP8L chunk.
+        if still.get(12..16) != Some(b"VP8L") {
+            return Err(ImageError::IoError(std::io::Error::other(
+                "Unexpected WebP layout",
+            )));
+        }
+
+        let mut anmf = Vec::with_capacity(16 + still.len() - 12);
+        anmf.extend_from_slice(&u24(0)); // x
+        anmf.extend_from_slice(&u24(0)); // y
+        anmf.extend_from_slice(&u24(w - 1));
+        anmf.extend_from_slice(&u24(h - 1));
+        anmf.extend_from_slice(&u24(frame.delay_ms.min(0xFF_FFFF)));
+        anmf.push(0b10); // no blending, no disposal
+        anmf.extend_from_slice(&still[12..]);
+        write_chunk(out, b"ANMF", &anmf);
+        progress(i + 1);
+    }
+
+    let riff_size = (out.len() - 8) as u32;
+    out[4..8].copy_from_slice(&riff_size.to_le_bytes());
+    Ok(())
+}
+
+fn u24(value: u32) -> [u8; 3] {
+    let bytes = value.to_le_bytes();
+    [bytes[0], bytes[1], bytes[2]]
+}
+
+fn write_chunk(out: &mut Vec<u8>, id: &[u8; 4], data: &[u8]) {
+    out.extend_from_slice(id);
+    out.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    out.extend_from_slice(data);
+    if data.len() % 2 == 1 {
+        out.push(0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use image::codecs::gif::GifDecoder;
+    use image::codecs::webp::WebPDecoder;
+    use image::{AnimationDecoder, Rgba};
+
+    use super::*;
+
+    fn frames() -> Vec<AnimationFrame> {
+        [Rgba([255, 0, 0, 255]), Rgba([0, 0, 255, 255])]
+            .into_iter()
+            .enumerate()
+            .map(|(i, color)| AnimationFrame {
+                image: RgbaImage::from_pixel(5, 3, color),
+                delay_ms: 100 * (i as u32 + 1),
+            })
+            .collect()
+    }
+
+    fn chunks(data: &[u8]) -> Vec<(&[u8], &[u8])> {
+        let mut chunks = Vec::new();
+        let mut rest = &data[12..];
+        while rest.len() >= 8 {
+            let size = u32::from_le_bytes(rest[4..8].try_into().unwrap()) as usize;
+            chunks.push((&rest[..4], &rest[8..8 + size]));
+            rest = &rest[(8 + size + size % 2).min(rest.len())..];
+        }
+        chunks
+    }
+
+    #[test]
+    fn webp_container_layout() {
+        let mut done = 0;
+        let data = encode(frames(), AnimationFormat::WebP, |n| done = n).unwrap();
+        assert_eq!(done, 2);
+        assert_eq!(&data[..4], b"RIFF");
+        assert_eq!(&data[8..12], b"WEBP");
+        let riff_size = u32::from_le_bytes(data[4..8].try_into().unwrap()) as usize;
+        assert_eq!(riff_size, data.len() - 8);
+
+        let chunks = chunks(&data);
+        let ids: Vec<&[u8]> = chunks.iter().map(|(id, _)| *id).collect();
+        assert_eq!(ids, [b"VP8X", b"ANIM", b"ANMF", b"ANMF"]);
+        let vp8x = chunks[0].1;
+        assert_eq!(vp8x[0], 0x02);
+        assert_eq!(
+            (&vp8x[4..7], &vp8x[7..10]),
+            (&[4, 0, 0][..], &[2, 0, 0][..])
+        );
+        let anmf = chunks[3].1;
+        assert_eq!(&anmf[12..15], &u24(200));
+        assert_eq!(&anmf[16..20], b"VP8L");
+    }
+
+    #[test]
+    fn webp_decodes_as_animation() {
+        let data = encode(frames(), AnimationFormat::WebP, |_| {}).unwrap();
+        let decoder = WebPDecoder::new(Cursor::new(data)).unwrap();
+        let decoded = decoder.into_frames().collect_frames().unwrap();
+        assert_eq!(decoded.len(), 2);
+        assert_eq!(decoded[1].delay().numer_denom_ms(), (200, 1));
+        assert_eq!(decoded[1].buffer().get_pixel(2, 1), &Rgba([0, 0, 255, 255]));
+    }
+
+    #[test]
+    fn gif_keeps_frames_and_delays() {
+        let data = encode(frames(), AnimationFormat::Gif { speed: 30 }, |_| {}).unwrap();
+        let decoder = GifDecoder::new(Cursor::new(data)).unwrap();
+        let decoded = decoder.into_frames().collect_frames().unwrap();
+        assert_eq!(decoded.len(), 2);
+        assert_eq!(decoded[0].delay().numer_denom_ms(), (100, 1));
+    }
+}
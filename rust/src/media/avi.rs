@@ -0,0 +1,284 @@
+// Motion JPEG in an AVI 1.0 container.
+// Header sizes and frame counts are written as zeros, then patched in `finish`.
+
+use std::io::{self, Seek, SeekFrom, Write};
+
+use image::codecs::jpeg::JpegEncoder;
+use image::{ExtendedColorType, ImageEncoder};
+
+use super::convert::rgba_to_rgb;
+use super::video::VideoWriter;
+
+const AVIF_HASINDEX: u32 = 0x10;
+const AVIIF_KEYFRAME: u32 = 0x10;
+// RIFF sizes are 32 bits, AVI 1.0 files stop a bit short of 4 GiB.
+const MAX_FILE_SIZE: u64 = u32::MAX as u64 - (64 << 20);
+
+struct IndexEntry {
+    offset: u32,
+    size: u32,
+}
+
+pub struct MjpegAviWriter<W: Write + Seek> {
+    out: W,
+    quality: u8,
+    width: u32,
+    height: u32,
+    rgb: Vec<u8>,
+    jpeg: Vec<u8>,
+    index: Vec<IndexEntry>,
+    max_frame_size: u32,
+    // Positions of the fields patched in `finish`.
+    total_frames_pos: u64,
+    length_pos: u64,
+    suggested_buffer_pos: [u64; 2],
+    movi_pos: u64,
+}
+
+impl<W: Write + Seek> MjpegAviWriter<W> {
+    pub fn new(
+        mut out: W,
+        width: u32,
+        height: u32,
+        fps: u32,
+        quality: u8,
+    ) -> io::Result<MjpegAviWriter<W>> {
+        let fps = fps.max(1);
+        out.write_all(b"RIFF\0\0\0\0AVI ")?;
+
+        // LIST hdrl: avih (8 + 56) and LIST strl (12 + strh 8 + 56 + strf 8 + 40).
+        out.write_all(b"LIST")?;
+        write_u32(&mut out, 4 + 64 + 124)?;
+        out.write_all(b"hdrl")?;
+
+        out.write_all(b"avih")?;
+        write_u32(&mut out, 56)?;
+        write_u32(&mut out, 1_000_000 / fps)?; // microseconds per frame
+        write_u32(&mut out, 0)?; // max bytes per second
+        write_u32(&mut out, 0)?; // padding granularity
+        write_u32(&mut out, AVIF_HASINDEX)?;
+        let total_frames_pos = out.stream_position()?;
+        write_u32(&mut out, 0)?; // total frames
+        write_u32(&mut out, 0)?; // initial frames
+        write_u32(&mut out, 1)?; // streams
+        let suggested_buffer_avih = out.stream_position()?;
+        write_u32(&mut out, 0)?; // suggested buffer size
+        write_u32(&mut out, width)?;
+        write_u32(&mut out, height)?;
+        out.write_all(&[0; 16])?; // reserved
+
+        out.write_all(b"LIST")?;
+        write_u32(&mut out, 4 + 64 + 48)?;
+        out.write_all(b"strl")?;
+
+        out.write_all(b"strh")?;
+        write_u32(&mut out, 56)?;
+        out.write_all(b"vidsMJPG")?;
+        write_u32(&mut out, 0)?; // flags
+        write_u32(&mut out, 0)?; // priority and language
+        write_u32(&mut out, 0)?; // initial frames
+        write_u32(&mut out, 1)?; // scale
+        write_u32(&mut out, fps)?; // rate, frames per second is rate / scale
+        write_u32(&mut out, 0)?; // start
+        let length_pos = out.stream_position()?;
+        write_u32(&mut out, 0)?; // length in frames
+        let suggested_buffer_strh = out.stream_position()?;
+        write_u32(&mut out, 0)?; // suggested buffer size
+        write_u32(&mut out, u32::MAX)?; // quality, default
+        write_u32(&mut out, 0)?; // sample size, varies
+        for v in [0, 0, width as u16, height as u16] {
+            out.write_all(&v.to_le_bytes())?; // frame rectangle
+        }
+
+        out.write_all(b"strf")?;
+        write_u32(&mut out, 40)?;
+        write_u32(&mut out, 40)?; // BITMAPINFOHEADER size
+        write_u32(&mut out, width)?;
+        write_u32(&mut out, height)?;
+        out.write_all(&1u16.to_le_bytes())?; // planes
+        out.write_all(&24u16.to_le_bytes())?; // bits per pixel
+        out.write_all(b"MJPG")?;
+        write_u32(&mut out, width * height * 3)?;
+        out.write_all(&[0; 16])?; // resolution and palette
+
+        let movi_pos = out.stream_position()?;
+        out.write_all(b"LIST\0\0\0\0movi")?;
+
+        Ok(MjpegAviWriter {
+            out,
+            quality: quality.clamp(1, 100),
+            width,
+            height,
+            rgb: Vec::new(),
+            jpeg: Vec::new(),
+            index: Vec::new(),
+            max_frame_size: 0,
+            total_frames_pos,
+            length_pos,
+            suggested_buffer_pos: [suggested_buffer_avih, suggested_buffer_strh],
+            movi_pos,
+        })
+    }
+}
+
+impl<W: Write + Seek + Send> VideoWriter for MjpegAviWriter<W> {
+    fn write_frame(&mut self, rgba_data: &[u8]) -> io::Result<()> {
+        self.rgb.clear();
+        rgba_to_rgb(rgba_data, &mut self.rgb);
+        self.jpeg.clear();
+        JpegEncoder::new_with_quality(&mut self.jpeg, self.quality)
+            .write_image(&self.rgb, self.width, self.height, ExtendedColorType::Rgb8)
+            .map_err(io::Error::other)?;
+
+        let position = self.out.stream_position()?;
+        let index_size = (self.index.len() as u64 + 1) * 16;
+        if position + self.jpeg.len() as u64 + index_size > MAX_FILE_SIZE {
+            return Err(io::Error::new(
+                io::ErrorKind::StorageFull,
+                "AVI files are limited to 4 GiB",
+            ));
+        }
+
+        let size = self.jpeg.len() as u32;
+        self.out.write_all(b"00dc")?;
+        write_u32(&mut self.out, size)?;
+        self.out.write_all(&self.jpeg)?;
+        if size % 2 == 1 {
+            self.out.write_all(&[0])?;
+        }
+
+        // Index offsets are relative to the `movi` fourcc.
+        self.index.push(IndexEntry {
+            offset: (position - (self.movi_pos + 8)) as u32,
+            size,
+        });
+        self.max_frame_size = self.max_frame_size.max(size);
+        Ok(())
+    }
+
+    fn finish(mut self: Box<Self>) -> io::Result<()> {
+        let movi_end = self.out.stream_position()?;
+
+        self.out.write_all(b"idx1")?;
+        write_u32(&mut self.out, self.index.len() as u32 * 16)?;
+        for entry in &self.index {
+            self.out.write_all(b"00dc")?;
+            write_u32(&mut self.out, AVIIF_KEYFRAME)?;
+            write_u32(&mut self.out, entry.offset)?;
+            write_u32(&mut self.out, entry.size)?;
+        }
+        let end = self.out.stream_position()?;
+
+        let frames = self.index.len() as u32;
+        let patches = [
+            (4, (end - 8) as u32),
+            (self.movi_pos + 4, (movi_end - self.movi_pos - 8) as u32),
+            (self.total_frames_pos, frames),
+            (self.length_pos, frames),
+            (self.suggested_buffer_pos[0], self.max_frame_size + 8),
+            (self.suggested_buffer_pos[1], self.max_frame_size + 8),
+        ];
+        for (position, value) in patches {
+            self.out.seek(SeekFrom::Start(position))?;
+            write_u32(&mut self.out, value)?;
+        }
+        self.out.seek(SeekFrom::Start(end))?;
+        self.out.flush()
+    }
+}
+
+fn write_u32(out: &mut impl Write, value: u32) -> io::Result<()> {
+    out.write_all(&value.to_le_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn u32_at(data: &[u8], position: usize) -> u32 {
+        u32::from_le_bytes(data[position..position + 4].try_into().unwrap())
+    }
+
+    fn position(data: &[u8], fourcc: &[u8; 4]) -> usize {
+        data.windows(4).position(|w| w == fourcc).unwrap()
+    }
+
+    #[test]
+    fn layout() {
+        let (width, height) = (8, 6);
+        let mut data = Vec::new();
+        let mut writer =
+            Box::new(MjpegAviWriter::new(Cursor::new(&mut data), width, height, 25, 90).unwrap());
+        let frames = [[255, 0, 0, 255], [0, 0, 255, 255]];
+        for pixel in frames {
+            writer
+                .write_frame(&pixel.repeat((width * height) as usize))
+                .unwrap();
+        }
+        writer.finish().unwrap();
+
+        assert_eq!(&data[..4], b"RIFF");
+        assert_eq!(u32_at(&data, 4) as usize, data.len() - 8);
+        assert_eq!(&data[8..12], b"AVI ");
+        let avih = position(&data, b"avih") + 8;
+        assert_eq!(u32_at(&data, avih), 40_000);
+        assert_eq!(u32_at(&data, avih + 16), 2);
+        assert_eq!((u32_at(&data, avih + 32), u32_at(&data, avih + 36)), (8, 6));
+        let strh = position(&data, b"strh") + 8;
+        assert_eq!(&data[strh..strh + 8], b"vidsMJPG");
+        assert_eq!(
+            (u32_at(&data, strh + 20), u32_at(&data, strh + 24)),
+            (1, 25)
+        );
+        assert_eq!(u32_at(&data, strh + 32), 2);
+
+        // The hdrl list ends where the movi list starts.
+        let hdrl = position(&data, b"hdrl") - 8;
+        let movi = position(&data, b"movi") - 8;
+        assert_eq!(hdrl + 8 + u32_at(&data, hdrl + 4) as usize, movi);
+        let idx1 = movi + 8 + u32_at(&data, movi + 4) as usize;
+        assert_eq!(&data[idx1..idx1 + 4], b"idx1");
+        assert_eq!(u32_at(&data, idx1 + 4), 32);
+        assert_eq!(idx1 + 8 + 32, data.len());
+
+        let mut max_size = 0;
+        for (i, expected) in frames.iter().enumerate() {
+            let entry = idx1 + 8 + i * 16;
+            assert_eq!(&data[entry..entry + 4], b"00dc");
+            assert_eq!(u32_at(&data, entry + 4), AVIIF_KEYFRAME);
+            // Offsets are from the `movi` fourcc.
+            let chunk = movi + 8 + u32_at(&data, entry + 8) as usize;
+            let size = u32_at(&data, entry + 12);
+            assert_eq!(&data[chunk..chunk + 4], b"00dc");
+            assert_eq!(u32_at(&data, chunk + 4), size);
+            let jpeg = &data[chunk + 8..chunk + 8 + size as usize];
+            let image = image::load_from_memory_with_format(jpeg, image::ImageFormat::Jpeg)
+                .unwrap()
+                .into_rgb8();
+            assert_eq!(image.dimensions(), (8, 6));
+            let pixel = image.get_pixel(4, 3);
+            for c in 0..3 {
+                assert!(pixel[c].abs_diff(expected[c]) < 8, "{pixel:?}");
+            }
+            max_size = max_size.max(size);
+        }
+        assert_eq!(u32_at(&data, avih + 28), max_size + 8);
+        assert_eq!(u32_at(&data, strh + 36), max_size + 8);
+    }
+
+    #[test]
+    fn chunks_are_word_aligned() {
+        let mut data = Vec::new();
+        let mut writer =
+            Box::new(MjpegAviWriter::new(Cursor::new(&mut data), 4, 4, 30, 50).unwrap());
+        for value in [0, 90, 180] {
+            writer.write_frame(&[value; 4 * 4 * 4]).unwrap();
+        }
+        writer.finish().unwrap();
+        let idx1 = position(&data, b"idx1");
+        for i in 0..3 {
+            assert_eq!(u32_at(&data, idx1 + 8 + i * 16 + 8) % 2, 0);
+        }
+    }
+}
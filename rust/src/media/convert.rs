@@ -0,0 +1,181 @@
+// Conversions from the RGBA frames of the pipeline to the formats sent to Dart.
+
+use image::codecs::jpeg::JpegEncoder;
+use image::codecs::png::{CompressionType, FilterType, PngEncoder};
+use image::{ExtendedColorType, ImageEncoder, ImageResult};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    #[default]
+    Rgba,
+    Bgra,
+    Rgb,
+    /// Y plane then interleaved UV at half resolution.
+    Nv12,
+    /// Y plane then U and V planes at half resolution.
+    I420,
+    Jpeg {
+        quality: u8,
+    },
+    Png,
+}
+
+impl OutputFormat {
+    /// Bytes per row of the first plane, 0 for compressed formats.
+    pub fn stride(&self, width: u32) -> u32 {
+        match self {
+            OutputFormat::Rgba | OutputFormat::Bgra => width * 4,
+            OutputFormat::Rgb => width * 3,
+            OutputFormat::Nv12 | OutputFormat::I420 => width,
+            OutputFormat::Jpeg { .. } | OutputFormat::Png => 0,
+        }
+    }
+}
+
+/// Converts RGBA frames, keeping its intermediate buffer between calls.
+#[derive(Default)]
+pub struct FrameEncoder {
+    rgb: Vec<u8>,
+}
+
+impl FrameEncoder {
+    /// Write `rgba_data` converted to `format` in `out`, replacing its content.
+    pub fn encode(
+        &mut self,
+        rgba_data: &[u8],
+        width: u32,
+        height: u32,
+        format: OutputFormat,
+        out: &mut Vec<u8>,
+    ) -> ImageResult<()> {
+        out.clear();
+        match format {
+            OutputFormat::Rgba => out.extend_from_slice(rgba_data),
+            OutputFormat::Bgra => out.extend(
+                rgba_data
+                    .chunks_exact(4)
+                    .flat_map(|p| [p[2], p[1], p[0], p[3]]),
+            ),
+            OutputFormat::Rgb => rgba_to_rgb(rgba_data, out),
+            OutputFormat::Nv12 => rgba_to_yuv420(rgba_data, width, height, true, out),
+            OutputFormat::I420 => rgba_to_yuv420(rgba_data, width, height, false, out),
+            OutputFormat::Jpeg { quality } => {
+                // The JPEG encoder only takes RGB.
+                self.rgb.clear();
+                rgba_to_rgb(rgba_data, &mut self.rgb);
+                JpegEncoder::new_with_quality(&mut *out, quality.clamp(1, 100)).encode(
+                    &self.rgb,
+                    width,
+                    height,
+                    ExtendedColorType::Rgb8,
+                )?;
+            }
+            OutputFormat::Png => {
+                PngEncoder::new_with_quality(
+                    &mut *out,
+                    CompressionType::Fast,
+                    FilterType::Adaptive,
+                )
+                .write_image(rgba_data, width, height, ExtendedColorType::Rgba8)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub fn rgba_to_rgb(rgba_data: &[u8], out: &mut Vec<u8>) {
+    out.extend(rgba_data.chunks_exact(4).flat_map(|p| [p[0], p[1], p[2]]));
+}
+
+/// BT.601 limited range 4:2:0, chroma is the average of each 2x2 block.
+pub fn rgba_to_yuv420(
+    rgba_data: &[u8],
+    width: u32,
+    height: u32,
+    interleaved: bool,
+    out: &mut Vec<u8>,
+) {
+    let (width, height) = (width as usize, height as usize);
+    let (chroma_w, chroma_h) = (width.div_ceil(2), height.div_ceil(2));
+
+    out.extend(rgba_data.chunks_exact(4).map(|p| {
+        let (r, g, b) = (p[0] as i32, p[1] as i32, p[2] as i32);
+        (((66 * r + 129 * g + 25 * b + 128) >> 8) + 16) as u8
+    }));
+
+    let plane = chroma_w * chroma_h;
+    let chroma_start = out.len();
+    out.resize(chroma_start + 2 * plane, 0);
+    let chroma = &mut out[chroma_start..];
+
+    for cy in 0..chroma_h {
+        for cx in 0..chroma_w {
+            let (mut r, mut g, mut b, mut n) = (0, 0, 0, 0);
+            for y in (cy * 2)..(cy * 2 + 2).min(height) {
+                for x in (cx * 2)..(cx * 2 + 2).min(width) {
+                    let idx = (y * width + x) * 4;
+                    r += rgba_data[idx] as i32;
+                    g += rgba_data[idx + 1] as i32;
+                    b += rgba_data[idx + 2] as i32;
+                    n += 1;
+                }
+            }
+            let (r, g, b) = (r / n, g / n, b / n);
+            let u = (((-38 * r - 74 * g + 112 * b + 128) >> 8) + 128) as u8;
+            let v = (((112 * r - 94 * g - 18 * b + 128) >> 8) + 128) as u8;
+
+            let idx = cy * chroma_w + cx;
+            if interleaved {
+                chroma[idx * 2] = u;
+                chroma[idx * 2 + 1] = v;
+            } else {
+                chroma[idx] = u;
+                chroma[plane + idx] = v;
+            }
+        }
+    }
+}
+
+/// Inverse of `rgba_to_yuv420` for planar data, each chroma sample covers a 2x2 block.
+pub fn yuv420_to_rgba(yuv: &[u8], width: u32, height: u32, out: &mut [u8]) {
+    let width = width as usize;
+    let (chroma_w, chroma_h) = (width.div_ceil(2), (height as usize).div_ceil(2));
+    let (y_plane, chroma) = yuv.split_at(width * height as usize);
+    let (u_plane, v_plane) = chroma.split_at(chroma_w * chroma_h);
+
+    for (y, out_row) in out.chunks_exact_mut(width * 4).enumerate() {
+        for (x, o) in out_row.chunks_exact_mut(4).enumerate() {
+            let c = y_plane[y * width + x] as i32 - 16;
+            let idx = (y / 2) * chroma_w + x / 2;
+            let d = u_plane[idx] as i32 - 128;
+            let e = v_plane[idx] as i32 - 128;
+            o[0] = ((298 * c + 409 * e + 128) >> 8).clamp(0, 255) as u8;
+            o[1] = ((298 * c - 100 * d - 208 * e + 128) >> 8).clamp(0, 255) as u8;
+            o[2] = ((298 * c + 516 * d + 128) >> 8).clamp(0, 255) as u8;
+            o[3] = 255;
+        }
+    }
+}
+
+/// Halve both dimensions of a RGBA frame, each pixel is the average of a 2x2 block.
+/// Odd last rows and columns are dropped.
+pub fn rgba_half_size(rgba_data: &[u8], width: u32, height: u32, out: &mut [u8]) {
+    let width = width as usize;
+    let (out_w, out_h) = (width / 2, height as usize / 2);
+    debug_assert_eq!(out.len(), out_w * out_h * 4);
+
+    for (oy, out_row) in out.chunks_exact_mut(out_w * 4).enumerate() {
+        let row0 = &rgba_data[oy * 2 * width * 4..];
+        let row1 = &rgba_data[(oy * 2 + 1) * width * 4..];
+        for (ox, o) in out_row.chunks_exact_mut(4).enumerate() {
+            let idx = ox * 8;
+            for c in 0..4 {
+                let sum = row0[idx + c] as u32
+                    + row0[idx + 4 + c] as u32
+                    + row1[idx + c] as u32
+                    + row1[idx + 4 + c] as u32;
+                o[c] = ((sum + 2) >> 2) as u8;
+            }
+        }
+    }
+}
@@ -0,0 +1,6 @@
+pub mod animation;
+pub mod avi;
+pub mod convert;
+pub mod still;
+pub mod video;
+pub mod y4m;
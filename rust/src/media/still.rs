@@ -0,0 +1,98 @@
+// Still image encoding, for snapshots, and decoding of the images given to effects.
+// Unlike the stream formats these favor size and quality over speed.
+
+use std::io::{BufRead, Seek};
+use std::path::Path;
+
+use image::codecs::jpeg::JpegEncoder;
+use image::codecs::png::PngEncoder;
+use image::codecs::webp::WebPEncoder;
+use image::{
+    DynamicImage, ExtendedColorType, ImageDecoder, ImageEncoder, ImageReader, ImageResult,
+};
+
+use super::convert::rgba_to_rgb;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StillFormat {
+    Png,
+    Jpeg {
+        quality: u8,
+    },
+    /// Lossless, the image crate has no lossy WebP encoder.
+    WebP,
+}
+
+impl StillFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            StillFormat::Png => "png",
+            StillFormat::Jpeg { .. } => "jpg",
+            StillFormat::WebP => "webp",
+        }
+    }
+}
+
+/// Decode an image upright, phone photos are often stored sideways with an EXIF orientation.
+pub fn decode_oriented<R: BufRead + Seek>(reader: ImageReader<R>) -> ImageResult<DynamicImage> {
+    let mut decoder = reader.into_decoder()?;
+    let orientation = decoder.orientation()?;
+    let mut image = DynamicImage::from_decoder(decoder)?;
+    image.apply_orientation(orientation);
+    Ok(image)
+}
+
+/// `decode_oriented` for an image file.
+pub fn open_oriented(path: &Path) -> ImageResult<DynamicImage> {
+    decode_oriented(ImageReader::open(path)?.with_guessed_format()?)
+}
+
+/// Encode a RGBA image. JPEG drops the alpha channel.
+pub fn encode_rgba(
+    rgba_data: &[u8],
+    width: u32,
+    height: u32,
+    format: StillFormat,
+) -> ImageResult<Vec<u8>> {
+    if let StillFormat::Jpeg { .. } = format {
+        let mut rgb = Vec::with_capacity(rgba_data.len() / 4 * 3);
+        rgba_to_rgb(rgba_data, &mut rgb);
+        return encode(&rgb, width, height, ExtendedColorType::Rgb8, format);
+    }
+    encode(rgba_data, width, height, ExtendedColorType::Rgba8, format)
+}
+
+/// Encode a mask as a grayscale image, from black for background to white for the person.
+pub fn encode_mask(
+    mask: &[f32],
+    width: u32,
+    height: u32,
+    format: StillFormat,
+) -> ImageResult<Vec<u8>> {
+    let luma: Vec<u8> = mask
+        .iter()
+        .map(|&m| (m.clamp(0.0, 1.0) * 255.0 + 0.5) as u8)
+        .collect();
+    encode(&luma, width, height, ExtendedColorType::L8, format)
+}
+
+fn encode(
+    pixels: &[u8],
+    width: u32,
+    height: u32,
+    color: ExtendedColorType,
+    format: StillFormat,
+) -> ImageResult<Vec<u8>> {
+    let mut out = Vec::new();
+    match format {
+        StillFormat::Png => PngEncoder::new(&mut out).write_image(pixels, width, height, color)?,
+        StillFormat::Jpeg { quality } => {
+            JpegEncoder::new_with_quality(&mut out, quality.clamp(1, 100))
+                .write_image(pixels, width, height, color)?
+        }
+        StillFormat::WebP => {
+            WebPEncoder::new_lossless(&mut out).write_image(pixels, width, height, color)?
+        }
+    }
+    Ok(out)
+}
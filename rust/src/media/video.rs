@@ -0,0 +1,75 @@
+// Video files written by recordings. Both containers are constant frame rate.
+
+use std::fs::File;
+use std::io::{self, BufWriter};
+
+use super::avi::MjpegAviWriter;
+use super::y4m::Y4mWriter;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VideoFormat {
+    /// Uncompressed 4:2:0, lossless apart from the chroma subsampling.
+    Y4m,
+    MjpegAvi {
+        quality: u8,
+    },
+}
+
+pub trait VideoWriter: Send {
+    /// Append a RGBA frame, of the size given when creating the writer.
+    fn write_frame(&mut self, rgba_data: &[u8]) -> io::Result<()>;
+
+    /// Complete the headers and flush the file.
+    fn finish(self: Box<Self>) -> io::Result<()>;
+}
+
+pub fn create(
+    format: VideoFormat,
+    file: File,
+    width: u32,
+    height: u32,
+    fps: u32,
+) -> io::Result<Box<dyn VideoWriter>> {
+    let file = BufWriter::new(file);
+    Ok(match format {
+        VideoFormat::Y4m => Box::new(Y4mWriter::new(file, width, height, fps)?),
+        VideoFormat::MjpegAvi { quality } => {
+            Box::new(MjpegAviWriter::new(file, width, height, fps, quality)?)
+        }
+    })
+}
+
+/// Places timestamped frames on the constant rate of a file.
+pub struct ConstantRate {
+    fps: u64,
+    start_us: Option<u64>,
+    written: u64,
+}
+
+impl ConstantRate {
+    pub fn new(fps: u32) -> ConstantRate {
+        ConstantRate {
+            fps: fps.max(1) as u64,
+            start_us: None,
+            written: 0,
+        }
+    }
+
+    /// How many times to write the frame at `time_us`: more than once to fill in for
+    /// missing frames, 0 if it comes before the next slot.
+    pub fn repeats(&mut self, time_us: u64) -> u64 {
+        let start = *self.start_us.get_or_insert(time_us);
+        let due = (time_us.saturating_sub(start) * self.fps + 500_000) / 1_000_000 + 1;
+        let repeats = due.saturating_sub(self.written);
+        self.written += repeats;
+        repeats
+    }
+
+    pub fn frames(&self) -> u64 {
+        self.written
+    }
+
+    pub fn duration_us(&self) -> u64 {
+        self.written * 1_000_000 / self.fps
+    }
+}
@@ -0,0 +1,214 @@
+// YUV4MPEG2 reader and writer, see https://wiki.multimedia.cx/index.php/YUV4MPEG2
+
+use std::io::{self, BufRead, Write};
+
+use super::convert::{rgba_to_yuv420, yuv420_to_rgba};
+use super::video::VideoWriter;
+
+// Larger frame sizes are taken as a broken header rather than allocated.
+const MAX_SIZE: u32 = 16384;
+
+pub struct Y4mWriter<W: Write> {
+    out: W,
+    width: u32,
+    height: u32,
+    yuv: Vec<u8>,
+}
+
+impl<W: Write> Y4mWriter<W> {
+    pub fn new(mut out: W, width: u32, height: u32, fps: u32) -> io::Result<Y4mWriter<W>> {
+        // Same conversion as the I420 stream format, BT.601 limited range.
+        writeln!(
+            out,
+            "YUV4MPEG2 W{width} H{height} F{fps}:1 Ip A1:1 C420jpeg XCOLORRANGE=LIMITED"
+        )?;
+        Ok(Y4mWriter {
+            out,
+            width,
+            height,
+            yuv: Vec::new(),
+        })
+    }
+}
+
+impl<W: Write + Send> VideoWriter for Y4mWriter<W> {
+    fn write_frame(&mut self, rgba_data: &[u8]) -> io::Result<()> {
+        self.yuv.clear();
+        rgba_to_yuv420(rgba_data, self.width, self.height, false, &mut self.yuv);
+        self.out.write_all(b"FRAME\n")?;
+        self.out.write_all(&self.yuv)
+    }
+
+    fn finish(mut self: Box<Self>) -> io::Result<()> {
+        self.out.flush()
+    }
+}
+
+/// Reads 4:2:0 files, like the ones written by recordings or `ffmpeg -pix_fmt yuv420p`.
+pub struct Y4mReader<R: BufRead> {
+    input: R,
+    width: u32,
+    height: u32,
+    /// Frame rate as a fraction, 0/0 when the header doesn't say.
+    fps: (u32, u32),
+    line: Vec<u8>,
+    yuv: Vec<u8>,
+}
+
+impl<R: BufRead> Y4mReader<R> {
+    pub fn new(mut input: R) -> io::Result<Y4mReader<R>> {
+        let mut header = Vec::new();
+        input.read_until(b'\n', &mut header)?;
+        let header = String::from_utf8_lossy(&header);
+        let mut params = header.split_ascii_whitespace();
+        if params.next() != Some("YUV4MPEG2") {
+            return Err(invalid("Not a YUV4MPEG2 file"));
+        }
+        let (mut width, mut height, mut fps) = (0, 0, (0, 0));
+        for param in params {
+            // Split after the first character, which may not be ASCII in a broken header.
+            let Some(key) = param.chars().next() else {
+                continue;
+            };
+            let value = &param[key.len_utf8()..];
+            match key {
+                'W' => width = value.parse().map_err(|_| invalid("Invalid width"))?,
+                'H' => height = value.parse().map_err(|_| invalid("Invalid height"))?,
+                'F' => {
+                    let (num, den) = value.split_once(':').unwrap_or((value, "1"));
+                    fps = (num.parse().unwrap_or(0), den.parse().unwrap_or(0));
+                }
+                // 8 bit 4:2:0, the chroma siting variants only differ slightly.
+                'C' if !matches!(value, "420" | "420jpeg" | "420paldv" | "420mpeg2") => {
+                    return Err(invalid("Only 8 bit 4:2:0 YUV4MPEG2 files are supported"));
+                }
+                'I' if value != "p" && value != "?" => {
+                    return Err(invalid("Only progressive YUV4MPEG2 files are supported"));
+                }
+                _ => {}
+            }
+        }
+        if width == 0 || height == 0 {
+            return Err(invalid("YUV4MPEG2 header without a frame size"));
+        }
+        if width > MAX_SIZE || height > MAX_SIZE {
+            return Err(invalid("YUV4MPEG2 frame size too large"));
+        }
+        // The RGBA frames are the largest buffers, 32 bit targets can't always hold them.
+        (width as usize)
+            .checked_mul(height as usize)
+            .and_then(|pixels| pixels.checked_mul(4))
+            .ok_or_else(|| invalid("YUV4MPEG2 frame size too large"))?;
+        Ok(Y4mReader {
+            input,
+            width,
+            height,
+            fps,
+            line: Vec::new(),
+            yuv: Vec::new(),
+        })
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn fps(&self) -> (u32, u32) {
+        self.fps
+    }
+
+    /// Read the next frame as RGBA into `out`. Returns false at the end of the file.
+    pub fn read_frame(&mut self, out: &mut Vec<u8>) -> io::Result<bool> {
+        self.line.clear();
+        if self.input.read_until(b'\n', &mut self.line)? == 0 {
+            return Ok(false);
+        }
+        if !self.line.starts_with(b"FRAME") {
+            return Err(invalid("Missing YUV4MPEG2 frame header"));
+        }
+        let (width, height) = (self.width as usize, self.height as usize);
+        let chroma = width.div_ceil(2) * height.div_ceil(2);
+        self.yuv.resize(width * height + 2 * chroma, 0);
+        self.input.read_exact(&mut self.yuv)?;
+
+        out.resize(width * height * 4, 0);
+        yuv420_to_rgba(&self.yuv, self.width, self.height, out);
+        Ok(true)
+    }
+}
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    fn header(params: &str) -> io::Result<Y4mReader<Cursor<Vec<u8>>>> {
+        Y4mReader::new(Cursor::new(format!("YUV4MPEG2 {params}\n").into_bytes()))
+    }
+
+    #[test]
+    fn round_trip() {
+        let (width, height) = (6, 4);
+        let frames: Vec<Vec<u8>> = [[200, 40, 40, 255], [40, 200, 120, 255]]
+            .iter()
+            .map(|color| color.repeat(width * height))
+            .collect();
+        let mut file = Vec::new();
+        let mut writer: Box<dyn VideoWriter> =
+            Box::new(Y4mWriter::new(&mut file, width as u32, height as u32, 25).unwrap());
+        for frame in &frames {
+            writer.write_frame(frame).unwrap();
+        }
+        writer.finish().unwrap();
+
+        let mut reader = Y4mReader::new(Cursor::new(file)).unwrap();
+        assert_eq!((reader.width(), reader.height()), (6, 4));
+        assert_eq!(reader.fps(), (25, 1));
+        let mut pixels = Vec::new();
+        for frame in &frames {
+            assert!(reader.read_frame(&mut pixels).unwrap());
+            assert_eq!(pixels.len(), frame.len());
+            // Limited range YUV loses a little precision.
+            assert!(pixels.iter().zip(frame).all(|(&a, &b)| a.abs_diff(b) <= 3));
+        }
+        assert!(!reader.read_frame(&mut pixels).unwrap());
+    }
+
+    #[test]
+    fn header_without_frame_rate() {
+        let reader = header("W2 H2 C420").unwrap();
+        assert_eq!(reader.fps(), (0, 0));
+    }
+
+    #[test]
+    fn only_8_bit_420_is_accepted() {
+        for colorspace in ["420", "420jpeg", "420paldv", "420mpeg2"] {
+            assert!(header(&format!("W2 H2 C{colorspace}")).is_ok());
+        }
+        for colorspace in ["420p10", "422", "444"] {
+            assert!(header(&format!("W2 H2 C{colorspace}")).is_err());
+        }
+        assert!(header("W2 H2 It").is_err());
+    }
+
+    #[test]
+    fn broken_headers_are_errors() {
+        assert!(header("W2 H2 \u{e9}x").is_ok());
+        assert!(header("W\u{e9} H2").is_err());
+        assert!(header("H2").is_err());
+        assert!(header("W0 H2").is_err());
+        assert!(header("W16385 H2").is_err());
+        assert!(header("W2 H4294967295").is_err());
+        assert!(header("W16384 H16384").is_ok());
+        assert!(Y4mReader::new(Cursor::new(b"RIFF".to_vec())).is_err());
+    }
+}
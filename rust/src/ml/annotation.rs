@@ -0,0 +1,343 @@
+// Mask encodings for training datasets: COCO run-length encoding and outline polygons.
+// Masks are thresholded like the rest of the pipeline, a pixel belongs to the person above
+// `PERSON_THRESHOLD`.
+
+use super::roi::PERSON_THRESHOLD;
+
+// Outlines of smaller blobs are left out of the polygons, they are noise more than people.
+const MIN_POLYGON_AREA: usize = 64;
+// Largest distance in pixels between an outline and its simplified polygon.
+const SIMPLIFY_TOLERANCE: f32 = 1.0;
+
+// Neighbors clockwise from the west, with y going down.
+const NEIGHBORS: [(i32, i32); 8] = [
+    (-1, 0),
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+];
+
+/// The person in a mask, in the shapes COCO annotations use.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaskAnnotation {
+    /// Pixels of the person.
+    pub area: u64,
+    /// `[x, y, width, height]`, `None` without a person.
+    pub bbox: Option<[u32; 4]>,
+    /// Compressed COCO RLE counts, see `encode_rle`.
+    pub rle: String,
+    /// Outer outlines as flat `[x1, y1, x2, y2, ...]` lists, holes are not cut out.
+    pub polygons: Vec<Vec<f32>>,
+}
+
+pub fn annotate(mask: &[f32], width: usize, height: usize) -> MaskAnnotation {
+    let inside: Vec<bool> = mask.iter().map(|&v| v > PERSON_THRESHOLD).collect();
+    let (mut min_x, mut min_y, mut max_x, mut max_y) = (usize::MAX, usize::MAX, 0, 0);
+    let mut area = 0;
+    for (i, _) in inside.iter().enumerate().filter(|(_, &inside)| inside) {
+        let (x, y) = (i % width, i / width);
+        min_x = min_x.min(x);
+        max_x = max_x.max(x);
+        min_y = min_y.min(y);
+        max_y = max_y.max(y);
+        area += 1;
+    }
+    MaskAnnotation {
+        area,
+        bbox: (area > 0).then(|| {
+            [min_x, min_y, max_x - min_x + 1, max_y - min_y + 1].map(|value| value as u32)
+        }),
+        rle: encode_rle(&inside, width, height),
+        polygons: outlines(&inside, width, height)
+            .iter()
+            .map(|outline| {
+                simplify(outline, SIMPLIFY_TOLERANCE)
+                    .iter()
+                    // Through the pixel centers.
+                    .flat_map(|&(x, y)| [x as f32 + 0.5, y as f32 + 0.5])
+                    .collect()
+            })
+            .filter(|polygon: &Vec<f32>| polygon.len() >= 6)
+            .collect(),
+    }
+}
+
+/// Runs of alternating outside and inside pixels in column-major order, starting with outside,
+/// written as the compressed string of the COCO API (`pycocotools.mask.decode` reads it).
+pub fn encode_rle(inside: &[bool], width: usize, height: usize) -> String {
+    let mut counts = Vec::new();
+    let (mut current, mut run) = (false, 0i64);
+    for x in 0..width {
+        for y in 0..height {
+            if inside[y * width + x] != current {
+                counts.push(run);
+                (current, run) = (!current, 0);
+            }
+            run += 1;
+        }
+    }
+    counts.push(run);
+
+    // Each count is stored as the difference with the count two runs before, in signed
+    // 5-bit groups offset into printable ASCII.
+    let mut encoded = String::new();
+    for i in 0..counts.len() {
+        let mut value = if i > 2 {
+            counts[i] - counts[i - 2]
+        } else {
+            counts[i]
+        };
+        loop {
+            let mut group = (value & 0x1f) as u8;
+            value >>= 5;
+            let more = if group & 0x10 != 0 {
+                value != -1
+            } else {
+                value != 0
+            };
+            if more {
+                group |= 0x20;
+            }
+            encoded.push((group + 48) as char);
+            if !more {
+                break;
+            }
+        }
+    }
+    encoded
+}
+
+// Outer boundary pixels of each 8-connected blob, in clockwise order.
+fn outlines(inside: &[bool], width: usize, height: usize) -> Vec<Vec<(usize, usize)>> {
+    let at = |x: i32, y: i32| {
+        x >= 0
+            && y >= 0
+            && (x as usize) < width
+            && (y as usize) < height
+            && inside[y as usize * width + x as usize]
+    };
+    let mut seen = vec![false; inside.len()];
+    let mut outlines = Vec::new();
+    let mut stack = Vec::new();
+
+    for start in 0..inside.len() {
+        if !inside[start] || seen[start] {
+            continue;
+        }
+        // The first pixel of a blob in raster order is on its outline.
+        let mut area = 0;
+        seen[start] = true;
+        stack.push(start);
+        while let Some(i) = stack.pop() {
+            area += 1;
+            let (x, y) = ((i % width) as i32, (i / width) as i32);
+            for (dx, dy) in NEIGHBORS {
+                let (nx, ny) = (x + dx, y + dy);
+                if !at(nx, ny) {
+                    continue;
+                }
+                let n = ny as usize * width + nx as usize;
+                if !seen[n] {
+                    seen[n] = true;
+                    stack.push(n);
+                }
+            }
+        }
+        if area >= MIN_POLYGON_AREA {
+            outlines.push(trace(start % width, start / width, at));
+        }
+    }
+    outlines
+}
+
+// Moore neighbor tracing from the first pixel of a blob, its west neighbor is outside.
+fn trace(x: usize, y: usize, at: impl Fn(i32, i32) -> bool) -> Vec<(usize, usize)> {
+    let start = (x as i32, y as i32);
+    let step = |(x, y): (i32, i32), from: usize| {
+        (1..8).map(|k| (from + k) % 8).find_map(|direction| {
+            let (dx, dy) = NEIGHBORS[direction];
+            let next = (x + dx, y + dy);
+            if !at(next.0, next.1) {
+                return None;
+            }
+            // The last outside neighbor checked, seen from the next pixel.
+            let (bx, by) = NEIGHBORS[(direction + 7) % 8];
+            let back = (x + bx - next.0, y + by - next.1);
+            let back = NEIGHBORS.iter().position(|&n| n == back).unwrap();
+            Some((next, back))
+        })
+    };
+    let Some(first) = step(start, 0) else {
+        return vec![(x, y)];
+    };
+
+    let mut outline = vec![(x, y)];
+    let (mut current, mut back) = first;
+    // Done when leaving the start the same way as the first time.
+    while let Some((next, next_back)) = step(current, back) {
+        if current == start && next == first.0 {
+            break;
+        }
+        outline.push((current.0 as usize, current.1 as usize));
+        (current, back) = (next, next_back);
+    }
+    outline
+}
+
+// Douglas-Peucker on a closed outline, split at the point farthest from the first one.
+fn simplify(outline: &[(usize, usize)], tolerance: f32) -> Vec<(usize, usize)> {
+    if outline.len() < 4 {
+        return outline.to_vec();
+    }
+    let point = |i: usize| {
+        let (x, y) = outline[i % outline.len()];
+        (x as f32, y as f32)
+    };
+    let distance = |a: (f32, f32), b: (f32, f32)| (a.0 - b.0).hypot(a.1 - b.1);
+    let far = (1..outline.len())
+        .max_by(|&a, &b| distance(point(0), point(a)).total_cmp(&distance(point(0), point(b))))
+        .unwrap();
+
+    let mut keep = vec![false; outline.len() + 1];
+    keep[0] = true;
+    keep[far] = true;
+    let mut pending = vec![(0, far), (far, outline.len())];
+    while let Some((first, last)) = pending.pop() {
+        let (a, b) = (point(first), point(last));
+        let length = distance(a, b).max(f32::EPSILON);
+        let farthest = (first + 1..last)
+            .map(|i| {
+                let p = point(i);
+                let cross = (b.0 - a.0) * (p.1 - a.1) - (b.1 - a.1) * (p.0 - a.0);
+                (i, cross.abs() / length)
+            })
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+        if let Some((i, _)) = farthest.filter(|&(_, d)| d > tolerance) {
+            keep[i] = true;
+            pending.push((first, i));
+            pending.push((i, last));
+        }
+    }
+    (0..outline.len())
+        .filter(|&i| keep[i])
+        .map(|i| outline[i])
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(width: usize, height: usize, x: [usize; 2], y: [usize; 2]) -> Vec<f32> {
+        (0..width * height)
+            .map(|i| {
+                let (px, py) = (i % width, i / width);
+                let inside = (x[0]..x[1]).contains(&px) && (y[0]..y[1]).contains(&py);
+                if inside {
+                    1.0
+                } else {
+                    0.0
+                }
+            })
+            .collect()
+    }
+
+    fn inside(mask: &[f32]) -> Vec<bool> {
+        mask.iter().map(|&v| v > PERSON_THRESHOLD).collect()
+    }
+
+    // Expected strings are from `rleToString` of the COCO API's maskApi.c, which
+    // `pycocotools.mask.encode` returns as `counts`.
+    #[test]
+    fn rle_matches_the_coco_api() {
+        assert_eq!(encode_rle(&[false; 6], 3, 2), "6");
+        assert_eq!(encode_rle(&[true; 6], 3, 2), "06");
+        let mask = rect(10, 10, [2, 6], [3, 8]);
+        assert_eq!(encode_rle(&inside(&mask), 10, 10), "g05500000U1");
+        // Counts above 15 take more than one character, differences are negative.
+        let mask = rect(100, 100, [20, 60], [10, 90]);
+        let expected = format!("jn1`2d{}fl3", "0".repeat(78));
+        assert_eq!(encode_rle(&inside(&mask), 100, 100), expected);
+    }
+
+    #[test]
+    fn rectangle_annotation() {
+        let mask = rect(20, 20, [4, 16], [5, 15]);
+        let annotation = annotate(&mask, 20, 20);
+        assert_eq!(annotation.area, 120);
+        assert_eq!(annotation.bbox, Some([4, 5, 12, 10]));
+        // Straight edges simplify to the corners.
+        assert_eq!(
+            annotation.polygons,
+            [vec![4.5, 5.5, 15.5, 5.5, 15.5, 14.5, 4.5, 14.5]]
+        );
+    }
+
+    #[test]
+    fn empty_mask_annotation() {
+        let annotation = annotate(&[0.2; 64], 8, 8);
+        assert_eq!((annotation.area, annotation.bbox), (0, None));
+        assert_eq!(annotation.rle, "P2");
+        assert!(annotation.polygons.is_empty());
+    }
+
+    #[test]
+    fn small_blobs_have_no_polygon() {
+        let mut mask = rect(40, 20, [2, 14], [2, 14]);
+        // A 3x3 speck, counted in the area but too small for an outline.
+        for (i, value) in rect(40, 20, [30, 33], [5, 8]).into_iter().enumerate() {
+            mask[i] += value;
+        }
+        let annotation = annotate(&mask, 40, 20);
+        assert_eq!(annotation.area, 144 + 9);
+        assert_eq!(annotation.bbox, Some([2, 2, 31, 12]));
+        assert_eq!(annotation.polygons.len(), 1);
+    }
+
+    #[test]
+    fn outlines_go_around_each_blob() {
+        let mut mask = inside(&rect(30, 12, [1, 10], [1, 10]));
+        for (i, value) in inside(&rect(30, 12, [15, 28], [2, 11]))
+            .into_iter()
+            .enumerate()
+        {
+            mask[i] |= value;
+        }
+        let outlines = outlines(&mask, 30, 12);
+        assert_eq!(outlines.len(), 2);
+        // Every boundary pixel once, starting at the top left corner.
+        assert_eq!(outlines[0].len(), 4 * 8);
+        assert_eq!(outlines[0][0], (1, 1));
+        assert_eq!(outlines[0][1], (2, 1));
+        assert_eq!(outlines[1].len(), 2 * (13 + 9) - 4);
+        assert!(outlines[1]
+            .iter()
+            .all(|&(x, y)| x == 15 || x == 27 || y == 2 || y == 10));
+    }
+
+    #[test]
+    fn trace_of_a_diagonal() {
+        let pixels = [(0, 0), (1, 1), (2, 2)];
+        let at = |x: i32, y: i32| pixels.contains(&(x, y));
+        // Back down the same pixels to close the outline.
+        assert_eq!(trace(0, 0, at), [(0, 0), (1, 1), (2, 2), (1, 1)]);
+        assert_eq!(trace(5, 5, |x, y| (x, y) == (5, 5)), [(5, 5)]);
+    }
+
+    #[test]
+    fn simplify_keeps_corners_within_tolerance() {
+        let mut outline: Vec<(usize, usize)> = (0..10).map(|x| (x, 0)).collect();
+        outline.extend((1..10).map(|y| (9, y)));
+        outline.extend((0..9).rev().map(|x| (x, 9)));
+        outline.extend((1..9).rev().map(|y| (0, y)));
+        assert_eq!(simplify(&outline, 1.0), [(0, 0), (9, 0), (9, 9), (0, 9)]);
+        // A one pixel bump is within the tolerance.
+        outline[4] = (4, 1);
+        assert_eq!(simplify(&outline, 1.0), [(0, 0), (9, 0), (9, 9), (0, 9)]);
+        assert_eq!(simplify(&outline, 0.5).len(), 7);
+    }
+}
@@ -0,0 +1,454 @@
+// Row parallel compositing kernels.
+// Blending is done in 8 bit fixed point, inner loops stay free of divisions and
+// float conversions so the compiler can vectorize them.
+
+use std::sync::{Arc, Mutex};
+
+use rayon::prelude::*;
+use rayon::{ThreadPool, ThreadPoolBuilder};
+
+static POOL: Mutex<Option<Arc<ThreadPool>>> = Mutex::new(None);
+
+/// Number of threads used by the kernels, 0 for one per core.
+pub fn set_threads(threads: usize) {
+    *POOL.lock().unwrap() = Some(build_pool(threads));
+}
+
+fn pool() -> Arc<ThreadPool> {
+    POOL.lock()
+        .unwrap()
+        .get_or_insert_with(|| build_pool(0))
+        .clone()
+}
+
+fn build_pool(threads: usize) -> Arc<ThreadPool> {
+    Arc::new(
+        ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .thread_name(|i| format!("compositing-{i}"))
+            .build()
+            .expect("Can't start compositing threads"),
+    )
+}
+
+// The mask is read `offset` pixels right and down of the pixel it applies to, clamped to the frame.
+fn mask_row(mask: &[f32], width: usize, y: usize, offset: (usize, usize)) -> &[f32] {
+    let height = mask.len() / width;
+    let mask_y = (y + offset.1).min(height - 1);
+    &mask[mask_y * width..(mask_y + 1) * width]
+}
+
+/// Blend `foreground` over `background` with the mask as alpha. Alpha channel comes from the foreground.
+pub fn blend(
+    foreground: &[u8],
+    background: &[u8],
+    mask: &[f32],
+    width: usize,
+    offset: (usize, usize),
+    out: &mut [u8],
+) {
+    let stride = width * 4;
+    pool().install(|| {
+        out.par_chunks_mut(stride)
+            .zip(foreground.par_chunks(stride))
+            .zip(background.par_chunks(stride))
+            .enumerate()
+            .for_each(|(y, ((out_row, fg_row), bg_row))| {
+                let mask_row = mask_row(mask, width, y, offset);
+                let pixels = out_row
+                    .chunks_exact_mut(4)
+                    .zip(fg_row.chunks_exact(4))
+                    .zip(bg_row.chunks_exact(4));
+                for (x, ((o, fg), bg)) in pixels.enumerate() {
+                    let m = mask_row[(x + offset.0).min(width - 1)];
+                    // 0..=256, so a full mask keeps the foreground unchanged.
+                    let a = (m.clamp(0.0, 1.0) * 256.0 + 0.5) as u32;
+                    let inv_a = 256 - a;
+                    for c in 0..3 {
+                        o[c] = ((fg[c] as u32 * a + bg[c] as u32 * inv_a) >> 8) as u8;
+                    }
+                    o[3] = fg[3];
+                }
+            });
+    });
+}
+
+/// Tint the pixels where the mask is above 0.5 in green.
+pub fn overlay(
+    rgba_data: &[u8],
+    mask: &[f32],
+    width: usize,
+    offset: (usize, usize),
+    out: &mut [u8],
+) {
+    let stride = width * 4;
+    pool().install(|| {
+        out.par_chunks_mut(stride)
+            .zip(rgba_data.par_chunks(stride))
+            .enumerate()
+            .for_each(|(y, (out_row, in_row))| {
+                let mask_row = mask_row(mask, width, y, offset);
+                let pixels = out_row.chunks_exact_mut(4).zip(in_row.chunks_exact(4));
+                for (x, (o, p)) in pixels.enumerate() {
+                    if mask_row[(x + offset.0).min(width - 1)] > 0.5 {
+                        o[0] = p[0] >> 1;
+                        o[1] = (p[1] >> 1) + 127;
+                        o[2] = p[2] >> 1;
+                    } else {
+                        o[..3].copy_from_slice(&p[..3]);
+                    }
+                    o[3] = p[3];
+                }
+            });
+    });
+}
+
+/// Keep the pixels and multiply their alpha by the mask, making the background transparent.
+pub fn cut_out(
+    rgba_data: &[u8],
+    mask: &[f32],
+    width: usize,
+    offset: (usize, usize),
+    out: &mut [u8],
+) {
+    let stride = width * 4;
+    pool().install(|| {
+        out.par_chunks_mut(stride)
+            .zip(rgba_data.par_chunks(stride))
+            .enumerate()
+            .for_each(|(y, (out_row, in_row))| {
+                let mask_row = mask_row(mask, width, y, offset);
+                let pixels = out_row.chunks_exact_mut(4).zip(in_row.chunks_exact(4));
+                for (x, (o, p)) in pixels.enumerate() {
+                    let m = mask_row[(x + offset.0).min(width - 1)];
+                    let a = (m.clamp(0.0, 1.0) * 256.0 + 0.5) as u32;
+                    o[..3].copy_from_slice(&p[..3]);
+                    o[3] = ((p[3] as u32 * a) >> 8) as u8;
+                }
+            });
+    });
+}
+
+/// The mask moved by `offset` like the kernels read it, so each value lines up with the
+/// frame pixel it applies to.
+pub fn align_mask(mask: &[f32], width: usize, offset: (usize, usize)) -> Vec<f32> {
+    let mut out = vec![0.0; mask.len()];
+    pool().install(|| {
+        out.par_chunks_mut(width)
+            .enumerate()
+            .for_each(|(y, out_row)| {
+                let mask_row = mask_row(mask, width, y, offset);
+                for (x, o) in out_row.iter_mut().enumerate() {
+                    *o = mask_row[(x + offset.0).min(width - 1)];
+                }
+            });
+    });
+    out
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BlurQuality {
+    /// A gaussian kernel, the same as `image::imageops::blur`.
+    #[default]
+    Exact,
+    /// Three box passes, close to a gaussian.
+    Gaussian,
+    /// A single box pass, about three times cheaper but with square looking highlights.
+    Box,
+}
+
+/// Gaussian blur, exact or approximated with box blurs.
+/// Keeps its buffers from one call to the next.
+#[derive(Default)]
+pub struct BoxBlur {
+    a: Vec<u8>,
+    b: Vec<u8>,
+    kernel: Vec<f32>,
+}
+
+impl BoxBlur {
+    pub fn apply(
+        &mut self,
+        rgba_data: &[u8],
+        width: usize,
+        height: usize,
+        sigma: f32,
+        quality: BlurQuality,
+    ) -> &[u8] {
+        match quality {
+            BlurQuality::Exact => self.gaussian(rgba_data, width, height, sigma),
+            BlurQuality::Gaussian => self.passes(rgba_data, width, height, &box_radii::<3>(sigma)),
+            BlurQuality::Box => self.passes(rgba_data, width, height, &box_radii::<1>(sigma)),
+        }
+    }
+
+    fn passes(&mut self, rgba_data: &[u8], width: usize, height: usize, radii: &[usize]) -> &[u8] {
+        self.a.resize(rgba_data.len(), 0);
+        self.b.resize(rgba_data.len(), 0);
+
+        // Horizontal passes, then the vertical ones as horizontal passes on the transposed frame.
+        // The result of each pass ends up in `a`.
+        box_blur_rows(rgba_data, &mut self.a, width, radii[0]);
+        for &radius in &radii[1..] {
+            box_blur_rows(&self.a, &mut self.b, width, radius);
+            std::mem::swap(&mut self.a, &mut self.b);
+        }
+        transpose(&self.a, &mut self.b, width, height);
+        std::mem::swap(&mut self.a, &mut self.b);
+        for &radius in radii {
+            box_blur_rows(&self.a, &mut self.b, height, radius);
+            std::mem::swap(&mut self.a, &mut self.b);
+        }
+        transpose(&self.a, &mut self.b, height, width);
+
+        &self.b
+    }
+
+    fn gaussian(&mut self, rgba_data: &[u8], width: usize, height: usize, sigma: f32) -> &[u8] {
+        gaussian_kernel(sigma, &mut self.kernel);
+        self.a.resize(rgba_data.len(), 0);
+        self.b.resize(rgba_data.len(), 0);
+
+        convolve_rows(rgba_data, &mut self.a, width, &self.kernel);
+        transpose(&self.a, &mut self.b, width, height);
+        convolve_rows(&self.b, &mut self.a, height, &self.kernel);
+        transpose(&self.a, &mut self.b, height, width);
+
+        &self.b
+    }
+}
+
+// Normalized weights over the kernel size `image::imageops::blur` picks for `sigma`.
+fn gaussian_kernel(sigma: f32, kernel: &mut Vec<f32>) {
+    let sigma = if sigma > 0.0 { sigma } else { 0.8 };
+    let size = ((((sigma - 0.8) / 0.3 + 1.0) * 2.0 + 1.0).max(3.0) as usize) | 1;
+    let center = (size / 2) as f32;
+    kernel.clear();
+    kernel.extend((0..size).map(|x| (-0.5 * ((x as f32 - center) / sigma).powi(2)).exp()));
+    let sum: f32 = kernel.iter().sum();
+    kernel.iter_mut().for_each(|weight| *weight /= sum);
+}
+
+// Rows of `src` convolved with `kernel`, pixels past the edges repeat the edge one.
+fn convolve_rows(src: &[u8], dst: &mut [u8], width: usize, kernel: &[f32]) {
+    let stride = width * 4;
+    let radius = kernel.len() / 2;
+    let last = width - 1;
+
+    pool().install(|| {
+        dst.par_chunks_mut(stride)
+            .zip(src.par_chunks(stride))
+            .for_each(|(dst_row, src_row)| {
+                for x in 0..width {
+                    let mut sum = [0f32; 4];
+                    for (k, &weight) in kernel.iter().enumerate() {
+                        let sx = (x + k).saturating_sub(radius).min(last) * 4;
+                        for c in 0..4 {
+                            sum[c] += src_row[sx + c] as f32 * weight;
+                        }
+                    }
+                    for c in 0..4 {
+                        dst_row[x * 4 + c] = (sum[c] + 0.5).min(255.0) as u8;
+                    }
+                }
+            });
+    });
+}
+
+// Box sizes giving the closest match to a gaussian with `N` passes, from
+// "Fast Almost-Gaussian Filtering", Kovesi 2010.
+fn box_radii<const N: usize>(sigma: f32) -> [usize; N] {
+    let passes = N as f32;
+    let ideal = (12.0 * sigma * sigma / passes + 1.0).sqrt();
+    let mut lower = ideal.floor() as i32;
+    if lower % 2 == 0 {
+        lower -= 1;
+    }
+    let lower = lower.max(1);
+    let upper = lower + 2;
+    let l = lower as f32;
+    let lower_count = ((12.0 * sigma * sigma - passes * l * l - 4.0 * passes * l - 3.0 * passes)
+        / (-4.0 * l - 4.0))
+        .round() as usize;
+
+    std::array::from_fn(|i| {
+        let size = if i < lower_count { lower } else { upper };
+        (size as usize - 1) / 2
+    })
+}
+
+fn box_blur_rows(src: &[u8], dst: &mut [u8], width: usize, radius: usize) {
+    let stride = width * 4;
+    let window = (2 * radius + 1) as u32;
+    // Division by the window size as a 16 bit fixed point multiplication.
+    let scale = (1 << 16) / window;
+    let last = width - 1;
+
+    pool().install(|| {
+        dst.par_chunks_mut(stride)
+            .zip(src.par_chunks(stride))
+            .for_each(|(dst_row, src_row)| {
+                let mut sum = [0u32; 4];
+                for i in 0..window as usize {
+                    let x = i.saturating_sub(radius).min(last);
+                    for c in 0..4 {
+                        sum[c] += src_row[x * 4 + c] as u32;
+                    }
+                }
+                for x in 0..width {
+                    let add = (x + radius + 1).min(last) * 4;
+                    let sub = x.saturating_sub(radius) * 4;
+                    for c in 0..4 {
+                        dst_row[x * 4 + c] = ((sum[c] * scale + (1 << 15)) >> 16).min(255) as u8;
+                        sum[c] = sum[c] + src_row[add + c] as u32 - src_row[sub + c] as u32;
+                    }
+                }
+            });
+    });
+}
+
+// `dst` gets `src` with rows and columns swapped, `width` and `height` are the ones of `src`.
+fn transpose(src: &[u8], dst: &mut [u8], width: usize, height: usize) {
+    pool().install(|| {
+        dst.par_chunks_mut(height * 4)
+            .enumerate()
+            .for_each(|(x, dst_row)| {
+                for (y, pixel) in dst_row.chunks_exact_mut(4).enumerate() {
+                    let idx = (y * width + x) * 4;
+                    pixel.copy_from_slice(&src[idx..idx + 4]);
+                }
+            });
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ml::test_util::{noise, noise_bytes};
+
+    const WIDTH: usize = 640;
+    const HEIGHT: usize = 480;
+    const OFFSET: (usize, usize) = (28, 18);
+
+    // Scalar implementations the kernels replaced.
+    fn reference_blend(rgba_data: &[u8], background_rgba_data: &[u8], mask: &[f32]) -> Vec<u8> {
+        let mut result = vec![0u8; WIDTH * HEIGHT * 4];
+        for i in 0..WIDTH * HEIGHT {
+            let x = i % WIDTH;
+            let y = i / WIDTH;
+            let mask_x = (x + OFFSET.0).min(WIDTH - 1);
+            let mask_y = (y + OFFSET.1).min(HEIGHT - 1);
+            let alpha = mask[mask_y * WIDTH + mask_x];
+            let inv_alpha = 1.0 - alpha;
+            let idx = i * 4;
+            for c in 0..3 {
+                result[idx + c] = ((rgba_data[idx + c] as f32 * alpha)
+                    + (background_rgba_data[idx + c] as f32 * inv_alpha))
+                    as u8;
+            }
+            result[idx + 3] = rgba_data[idx + 3];
+        }
+        result
+    }
+
+    fn reference_overlay(rgba_data: &[u8], mask: &[f32]) -> Vec<u8> {
+        let mut result = rgba_data.to_vec();
+        for i in 0..WIDTH * HEIGHT {
+            let mask_x = (i % WIDTH + OFFSET.0).min(WIDTH - 1);
+            let mask_y = (i / WIDTH + OFFSET.1).min(HEIGHT - 1);
+            if mask[mask_y * WIDTH + mask_x] > 0.5 {
+                let idx = i * 4;
+                result[idx] = (result[idx] as f32 * 0.5) as u8;
+                result[idx + 1] = ((result[idx + 1] as f32 * 0.5) + 127.0) as u8;
+                result[idx + 2] = (result[idx + 2] as f32 * 0.5) as u8;
+            }
+        }
+        result
+    }
+
+    fn frame(seed: u32) -> Vec<u8> {
+        noise_bytes(seed, WIDTH * HEIGHT * 4)
+    }
+
+    fn mask(seed: u32) -> Vec<f32> {
+        noise(seed, WIDTH * HEIGHT)
+            .into_iter()
+            .enumerate()
+            .map(|(i, v)| match i % 4 {
+                0 => 0.0,
+                1 => 1.0,
+                _ => (v % 10_001) as f32 / 10_000.0,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn blend_matches_scalar_within_one() {
+        let (fg, bg, mask) = (frame(1), frame(2), mask(3));
+        let expected = reference_blend(&fg, &bg, &mask);
+
+        for threads in [1, 4] {
+            set_threads(threads);
+            let mut out = vec![0u8; fg.len()];
+            blend(&fg, &bg, &mask, WIDTH, OFFSET, &mut out);
+
+            let max_error = out
+                .iter()
+                .zip(&expected)
+                .map(|(&a, &b)| a.abs_diff(b))
+                .max()
+                .unwrap();
+            assert!(
+                max_error <= 1,
+                "max error {max_error} with {threads} threads"
+            );
+        }
+    }
+
+    #[test]
+    fn overlay_matches_scalar() {
+        let (rgba, mask) = (frame(4), mask(5));
+        let mut out = vec![0u8; rgba.len()];
+        overlay(&rgba, &mask, WIDTH, OFFSET, &mut out);
+        assert!(out == reference_overlay(&rgba, &mask));
+    }
+
+    #[test]
+    fn aligned_mask_needs_no_offset() {
+        let (rgba, mask) = (frame(7), mask(8));
+        let mut expected = vec![0u8; rgba.len()];
+        cut_out(&rgba, &mask, WIDTH, OFFSET, &mut expected);
+        let aligned = align_mask(&mask, WIDTH, OFFSET);
+        let mut out = vec![0u8; rgba.len()];
+        cut_out(&rgba, &aligned, WIDTH, (0, 0), &mut out);
+        assert!(out == expected);
+    }
+
+    #[test]
+    fn box_blur_keeps_flat_frame() {
+        let flat: Vec<u8> = [10, 120, 250, 255].repeat(WIDTH * HEIGHT);
+        let mut blur = BoxBlur::default();
+        for quality in [BlurQuality::Exact, BlurQuality::Gaussian, BlurQuality::Box] {
+            assert!(blur.apply(&flat, WIDTH, HEIGHT, 12.0, quality) == flat.as_slice());
+        }
+    }
+
+    #[test]
+    fn exact_blur_matches_image_crate() {
+        let rgba = frame(6);
+        let expected = image::imageops::blur(
+            &image::RgbaImage::from_raw(WIDTH as u32, HEIGHT as u32, rgba.clone()).unwrap(),
+            6.0,
+        );
+        let mut blur = BoxBlur::default();
+        let blurred = blur.apply(&rgba, WIDTH, HEIGHT, 6.0, BlurQuality::Exact);
+        let max_error = blurred
+            .iter()
+            .zip(expected.as_raw())
+            .map(|(&a, &b)| a.abs_diff(b))
+            .max()
+            .unwrap();
+        // The intermediate pass is rounded to 8 bits.
+        assert!(max_error <= 1, "max error {max_error}");
+    }
+}
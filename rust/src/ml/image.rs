@@ -1,142 +1,430 @@
+use std::time::{Duration, Instant};
+
 use burn::tensor::Tensor;
-use burn_ndarray::{NdArray, NdArrayDevice};
+use burn_ndarray::NdArrayDevice;
 
+use super::composite::{self, BlurQuality, BoxBlur};
+#[cfg(lite_model)]
+use super::lite_model::Model as LiteModel;
+use super::model::{bundled_descriptor, load_selfie_model, Backend, ModelDescriptor, ModelVariant};
+#[cfg(lite_model)]
+use super::model::{lite_descriptor, load_lite_model};
 use super::my_model::Model;
+use super::resize::{resize_mask, rgba_to_input, smooth_mask, Sampler};
+use super::roi::{Rect, RoiTracker};
+
+// (x, y) shift between the mask and a 640x480 camera frame, scaled for other sizes.
+const MASK_OFFSET: (usize, usize) = (28, 18);
+// Radius of the edge smoothing, in frame pixels.
+const REFINE_RADIUS: usize = 2;
+// Weight of a new mask against the previous ones in the temporal smoothing.
+// Lower is steadier, but the mask lags further behind fast motion.
+const TEMPORAL_WEIGHT: f32 = 0.5;
 
-const MASK_OFFSET_X: i32 = 28;
-const MASK_OFFSET_Y: i32 = 18;
-const MODEL_SIZE: u32 = 256;
+/// Time spent in each step of the last `create_mask` call.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MaskTimings {
+    pub preprocess: Duration,
+    pub inference: Duration,
+    pub postprocess: Duration,
+}
 
 pub struct ImageSegmentation {
     device: NdArrayDevice,
-    model: Model<NdArray<f32>>,
+    model: Model<Backend>,
+    descriptor: ModelDescriptor,
+    // Loaded the first time the lite variant is used.
+    #[cfg(lite_model)]
+    lite: Option<LiteModel<Backend>>,
+    variant: ModelVariant,
+    // Buffers and sampling tables reused from one frame to the next.
+    input: Vec<f32>,
+    input_scratch: Vec<f32>,
+    scores: Vec<f32>,
+    mask: Vec<f32>,
+    input_x: Sampler,
+    input_y: Sampler,
+    mask_x: Sampler,
+    mask_y: Sampler,
+    roi: Option<RoiTracker>,
+    refine: bool,
+    refine_scratch: Vec<f32>,
+    // The last mask before edge smoothing, `None` when temporal smoothing is off.
+    previous: Option<Vec<f32>>,
+    timings: MaskTimings,
 }
 
 impl ImageSegmentation {
     pub fn init() -> ImageSegmentation {
+        ImageSegmentation::new(load_selfie_model(), bundled_descriptor())
+    }
+
+    pub fn new(model: Model<Backend>, descriptor: ModelDescriptor) -> ImageSegmentation {
         ImageSegmentation {
             device: NdArrayDevice::default(),
-            model: Model::default(),
+            model,
+            descriptor,
+            #[cfg(lite_model)]
+            lite: None,
+            variant: ModelVariant::Full,
+            input: vec![0.0; descriptor.input_len()],
+            input_scratch: Vec::new(),
+            scores: Vec::new(),
+            mask: Vec::new(),
+            input_x: Sampler::default(),
+            input_y: Sampler::default(),
+            mask_x: Sampler::default(),
+            mask_y: Sampler::default(),
+            roi: None,
+            refine: false,
+            refine_scratch: Vec::new(),
+            previous: None,
+            timings: MaskTimings::default(),
         }
     }
-    pub fn create_mask(&self, rgba_data: Vec<u8>) -> Vec<f32> {
-        use image::DynamicImage;
 
-        let rgba_img = image::RgbaImage::from_raw(640, 480, rgba_data).unwrap();
-        let rgb_img = DynamicImage::ImageRgba8(rgba_img).to_rgb8();
+    /// Only run inference around the person once they are found, see `RoiTracker`.
+    pub fn set_roi_tracking(&mut self, enabled: bool) {
+        if enabled != self.roi.is_some() {
+            self.roi = enabled.then(RoiTracker::default);
+        }
+    }
 
-        let resized_rgb: image::RgbImage = image::imageops::resize(
-            &rgb_img,
-            MODEL_SIZE,
-            MODEL_SIZE,
-            image::imageops::FilterType::Triangle,
-        );
+    /// Smooth the mask edges after upscaling, off by default.
+    pub fn set_refinement(&mut self, enabled: bool) {
+        self.refine = enabled;
+    }
+
+    /// Average each mask with the previous ones, off by default.
+    /// Steadies flickering edges, at the cost of a lag behind fast motion.
+    pub fn set_temporal_smoothing(&mut self, enabled: bool) {
+        if enabled != self.previous.is_some() {
+            self.previous = enabled.then(Vec::new);
+        }
+    }
+
+    /// Model to run from the next mask on, see `ModelVariant`.
+    pub fn set_variant(&mut self, variant: ModelVariant) {
+        self.variant = variant;
+    }
+
+    // Conventions of the model the variant runs.
+    fn active_descriptor(&self) -> ModelDescriptor {
+        #[cfg(lite_model)]
+        if self.variant == ModelVariant::Lite {
+            return lite_descriptor();
+        }
+        self.descriptor
+    }
 
-        let rgb_256 = resized_rgb.into_raw();
+    fn forward(&mut self, input: Tensor<Backend, 4>) -> Tensor<Backend, 4> {
+        #[cfg(lite_model)]
+        if self.variant == ModelVariant::Lite {
+            return self.lite.get_or_insert_with(load_lite_model).forward(input);
+        }
+        self.model.forward(input)
+    }
 
-        let normalized: Vec<f32> = rgb_256.iter().map(|&p| p as f32 / 255.0).collect();
+    /// Forget the last mask and the tracked person.
+    pub fn reset(&mut self) {
+        self.mask.clear();
+        if let Some(previous) = &mut self.previous {
+            previous.clear();
+        }
+        if let Some(roi) = &mut self.roi {
+            roi.reset();
+        }
+    }
+
+    /// Mask of the last `create_mask` call, if it was for a frame of this size.
+    pub fn last_mask(&self, width: u32, height: u32) -> Option<&[f32]> {
+        (!self.mask.is_empty() && self.mask.len() == (width * height) as usize)
+            .then_some(self.mask.as_slice())
+    }
+
+    /// Compute the person mask of a RGBA frame, one value in 0..=1 per pixel.
+    pub fn create_mask(&mut self, rgba_data: &[u8], width: u32, height: u32) -> &[f32] {
+        let (width, height) = (width as usize, height as usize);
+        let descriptor = self.active_descriptor();
+        self.input.resize(descriptor.input_len(), 0.0);
+        let start = Instant::now();
+
+        let region = match &mut self.roi {
+            Some(roi) => {
+                // The tracked box means nothing once the frame size changes.
+                if self.mask.len() != width * height {
+                    roi.reset();
+                }
+                roi.next_region()
+            }
+            None => None,
+        }
+        .unwrap_or(Rect::full(width, height));
+        let offset = region.y * width + region.x;
+
+        self.input_x
+            .update(region.width, descriptor.input_width as usize);
+        self.input_y
+            .update(region.height, descriptor.input_height as usize);
+        rgba_to_input(
+            &rgba_data[offset * 4..],
+            width,
+            &self.input_x,
+            &self.input_y,
+            &descriptor,
+            &mut self.input_scratch,
+            &mut self.input,
+        );
+
+        let preprocessed = Instant::now();
 
         // Start burn inference.
-        let input = Tensor::<NdArray, 1>::from_floats(normalized.as_slice(), &self.device)
-            .reshape([1, 256, 256, 3])
-            .swap_dims(1, 3) // [1, 3, 256, 256]
-            .swap_dims(2, 3);
+        let input = Tensor::<Backend, 1>::from_floats(self.input.as_slice(), &self.device)
+            .reshape(descriptor.input_shape());
+        let output = self.forward(input);
+        let dims = output.dims();
+        let output = output.into_data();
+        let inferred = Instant::now();
 
-        let output = self.model.forward(input);
-        let mask_data = output.into_data().to_vec::<f32>().unwrap();
+        let (out_width, out_height) =
+            descriptor.person_scores(output.as_slice::<f32>().unwrap(), dims, &mut self.scores);
 
-        // Create a mask image and resize it to 640x480.
-        let mask_img = image::ImageBuffer::from_fn(MODEL_SIZE, MODEL_SIZE, |x, y| {
-            let idx = (y * MODEL_SIZE + x) as usize;
-            let val = (mask_data[idx] * 255.0) as u8;
-            image::Luma([val])
-        });
+        // Outside of the tracked region is background.
+        self.mask.resize(width * height, 0.0);
+        if region != Rect::full(width, height) {
+            self.mask.fill(0.0);
+        }
+        self.mask_x.update(out_width, region.width);
+        self.mask_y.update(out_height, region.height);
+        resize_mask(
+            &self.scores,
+            out_width,
+            &self.mask_x,
+            &self.mask_y,
+            &mut self.mask[offset..],
+            width,
+        );
 
-        let resized_mask =
-            image::imageops::resize(&mask_img, 640, 480, image::imageops::FilterType::Triangle);
+        if let Some(roi) = &mut self.roi {
+            roi.update(&self.mask, width, height);
+        }
+        if let Some(previous) = &mut self.previous {
+            if previous.len() == self.mask.len() {
+                for (value, previous) in self.mask.iter_mut().zip(previous.iter()) {
+                    *value = previous + (*value - previous) * TEMPORAL_WEIGHT;
+                }
+            }
+            previous.clear();
+            previous.extend_from_slice(&self.mask);
+        }
+        if self.refine {
+            smooth_mask(
+                &mut self.mask,
+                &mut self.refine_scratch,
+                width,
+                height,
+                REFINE_RADIUS,
+            );
+        }
 
-        // Convert mask to Vec<f32>
-        let mask: Vec<f32> = resized_mask.pixels().map(|p| p[0] as f32 / 255.0).collect();
+        self.timings = MaskTimings {
+            preprocess: preprocessed - start,
+            inference: inferred - preprocessed,
+            postprocess: inferred.elapsed(),
+        };
 
-        mask
+        &self.mask
     }
-}
-
-pub fn blur_background(rgba_data: &[u8], mask: &[f32], blur_sigma: f32) -> Vec<u8> {
-    const WIDTH: u32 = 640;
-    const HEIGHT: u32 = 480;
-    let original = image::RgbaImage::from_raw(WIDTH, HEIGHT, rgba_data.to_vec())
-        .expect("Invalid image dimensions");
-    let blurred = image::imageops::blur(&original, blur_sigma);
 
-    blend_images(rgba_data, blurred.as_raw(), mask)
+    pub fn timings(&self) -> MaskTimings {
+        self.timings
+    }
 }
 
-// note : Duplication from blur_background. to improve.
-pub fn replace_background(rgba_data: &[u8], background_rgba_data: &[u8], mask: &[f32]) -> Vec<u8> {
-    blend_images(rgba_data, background_rgba_data, mask)
+/// Average pre and post processing timings of one mask computation path.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PathTimings {
+    pub preprocess: Duration,
+    pub postprocess: Duration,
 }
 
-fn blend_images(rgba_data: &[u8], background_rgba_data: &[u8], mask: &[f32]) -> Vec<u8> {
+/// Compare the original image crate based path with the fused kernels on a 640x480 frame.
+/// Returns `(reference, fused)`. Inference is left out as it is the same for both.
+pub fn benchmark_mask_processing(iterations: u32) -> (PathTimings, PathTimings) {
     const WIDTH: usize = 640;
     const HEIGHT: usize = 480;
-    const TOTAL_PIXELS: usize = WIDTH * HEIGHT;
-
-    let mut result = vec![0u8; TOTAL_PIXELS * 4];
-
-    for i in 0..TOTAL_PIXELS {
-        let x = (i % WIDTH) as i32;
-        let y = (i / WIDTH) as i32;
-
-        // Calculer l'index du masque avec offset
-        let mask_x = (x + MASK_OFFSET_X).clamp(0, WIDTH as i32 - 1) as usize;
-        let mask_y = (y + MASK_OFFSET_Y).clamp(0, HEIGHT as i32 - 1) as usize;
-        let mask_idx = mask_y * WIDTH + mask_x;
-        let alpha = mask[mask_idx];
-        let inv_alpha = 1.0 - alpha;
-
-        let pixel_idx = i * 4;
-
-        // Blending RGB (sans conversion via get_pixel)
-        result[pixel_idx] = ((rgba_data[pixel_idx] as f32 * alpha)
-            + (background_rgba_data[pixel_idx] as f32 * inv_alpha))
-            as u8;
-        result[pixel_idx + 1] = ((rgba_data[pixel_idx + 1] as f32 * alpha)
-            + (background_rgba_data[pixel_idx + 1] as f32 * inv_alpha))
-            as u8;
-        result[pixel_idx + 2] = ((rgba_data[pixel_idx + 2] as f32 * alpha)
-            + (background_rgba_data[pixel_idx + 2] as f32 * inv_alpha))
-            as u8;
-        result[pixel_idx + 3] = rgba_data[pixel_idx + 3]; // Alpha channel
+    let descriptor = bundled_descriptor();
+    let (input_w, input_h) = (
+        descriptor.input_width as usize,
+        descriptor.input_height as usize,
+    );
+    let iterations = iterations.max(1);
+
+    let frame: Vec<u8> = (0..WIDTH * HEIGHT * 4).map(|i| (i % 251) as u8).collect();
+    let model_output: Vec<f32> = (0..input_w * input_h)
+        .map(|i| (i % 256) as f32 / 255.0)
+        .collect();
+
+    let mut reference = PathTimings::default();
+    for _ in 0..iterations {
+        let start = Instant::now();
+        std::hint::black_box(reference_preprocess(frame.clone()));
+        reference.preprocess += start.elapsed();
+
+        let start = Instant::now();
+        std::hint::black_box(reference_postprocess(&model_output));
+        reference.postprocess += start.elapsed();
     }
 
-    result
+    let mut fused = PathTimings::default();
+    let (mut xs, mut ys) = (Sampler::default(), Sampler::default());
+    let mut input = vec![0.0; descriptor.input_len()];
+    let mut scratch = Vec::new();
+    let mut mask = vec![0.0; WIDTH * HEIGHT];
+    for _ in 0..iterations {
+        let start = Instant::now();
+        xs.update(WIDTH, input_w);
+        ys.update(HEIGHT, input_h);
+        rgba_to_input(
+            &frame,
+            WIDTH,
+            &xs,
+            &ys,
+            &descriptor,
+            &mut scratch,
+            &mut input,
+        );
+        std::hint::black_box(&input);
+        fused.preprocess += start.elapsed();
+
+        let start = Instant::now();
+        xs.update(input_w, WIDTH);
+        ys.update(input_h, HEIGHT);
+        resize_mask(&model_output, input_w, &xs, &ys, &mut mask, WIDTH);
+        std::hint::black_box(&mask);
+        fused.postprocess += start.elapsed();
+    }
+
+    let average = |t: PathTimings| PathTimings {
+        preprocess: t.preprocess / iterations,
+        postprocess: t.postprocess / iterations,
+    };
+    (average(reference), average(fused))
+}
+
+// Previous preprocessing, kept as a baseline for `benchmark_mask_processing`.
+fn reference_preprocess(rgba_data: Vec<u8>) -> Vec<f32> {
+    use image::DynamicImage;
+    const MODEL_SIZE: u32 = 256;
+
+    let rgba_img = image::RgbaImage::from_raw(640, 480, rgba_data).unwrap();
+    let rgb_img = DynamicImage::ImageRgba8(rgba_img).to_rgb8();
+
+    let resized_rgb: image::RgbImage = image::imageops::resize(
+        &rgb_img,
+        MODEL_SIZE,
+        MODEL_SIZE,
+        image::imageops::FilterType::Triangle,
+    );
+
+    resized_rgb
+        .into_raw()
+        .iter()
+        .map(|&p| p as f32 / 255.0)
+        .collect()
+}
+
+// Previous postprocessing, kept as a baseline for `benchmark_mask_processing`.
+fn reference_postprocess(mask_data: &[f32]) -> Vec<f32> {
+    const MODEL_SIZE: u32 = 256;
+    let mask_img = image::ImageBuffer::from_fn(MODEL_SIZE, MODEL_SIZE, |x, y| {
+        let idx = (y * MODEL_SIZE + x) as usize;
+        let val = (mask_data[idx] * 255.0) as u8;
+        image::Luma([val])
+    });
+
+    let resized_mask =
+        image::imageops::resize(&mask_img, 640, 480, image::imageops::FilterType::Triangle);
+
+    resized_mask.pixels().map(|p| p[0] as f32 / 255.0).collect()
+}
+
+/// (x, y) shift of the mask over camera frames of this size, to pass to the effects.
+/// Images from elsewhere, like photos, use `(0, 0)`.
+pub fn camera_mask_offset(width: u32, height: u32) -> (usize, usize) {
+    (
+        MASK_OFFSET.0 * width as usize / 640,
+        MASK_OFFSET.1 * height as usize / 480,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn blur_background(
+    rgba_data: &[u8],
+    mask: &[f32],
+    width: u32,
+    offset: (usize, usize),
+    blur_sigma: f32,
+    blur: &mut BoxBlur,
+    quality: BlurQuality,
+    out: &mut [u8],
+) {
+    let (w, h) = (width as usize, rgba_data.len() / 4 / width as usize);
+    let blurred = blur.apply(rgba_data, w, h, blur_sigma, quality);
+
+    blend_images(rgba_data, blurred, mask, width, offset, out);
+}
+
+// note : Duplication from blur_background. to improve.
+/// `background_rgba_data` must be the size of the frame.
+pub fn replace_background(
+    rgba_data: &[u8],
+    background_rgba_data: &[u8],
+    mask: &[f32],
+    width: u32,
+    offset: (usize, usize),
+    out: &mut [u8],
+) {
+    blend_images(rgba_data, background_rgba_data, mask, width, offset, out);
+}
+
+fn blend_images(
+    rgba_data: &[u8],
+    background_rgba_data: &[u8],
+    mask: &[f32],
+    width: u32,
+    offset: (usize, usize),
+    out: &mut [u8],
+) {
+    composite::blend(
+        rgba_data,
+        background_rgba_data,
+        mask,
+        width as usize,
+        offset,
+        out,
+    );
 }
 
 // Used for debug.
 // apply green overlay on the mask.
-pub fn show_mask_overlay(rgba_data: &[u8], mask: &[f32]) -> Vec<u8> {
-    let mut result = rgba_data.to_vec();
-
-    const WIDTH: i32 = 640;
-    const HEIGHT: i32 = 480;
-
-    for i in 0..(WIDTH * HEIGHT) as usize {
-        let x = (i as i32) % WIDTH;
-        let y = (i as i32) / WIDTH;
-
-        let mask_x = (x + MASK_OFFSET_X).clamp(0, WIDTH - 1);
-        let mask_y = (y + MASK_OFFSET_Y).clamp(0, HEIGHT - 1);
-        let mask_idx = (mask_y * WIDTH + mask_x) as usize;
-        let mask_val = mask[mask_idx];
-
-        if mask_val > 0.5 {
-            let idx = i * 4;
-            result[idx] = (result[idx] as f32 * 0.5) as u8;
-            result[idx + 1] = ((result[idx + 1] as f32 * 0.5) + 127.0) as u8;
-            result[idx + 2] = (result[idx + 2] as f32 * 0.5) as u8;
-        }
-    }
+pub fn show_mask_overlay(
+    rgba_data: &[u8],
+    mask: &[f32],
+    width: u32,
+    offset: (usize, usize),
+    out: &mut [u8],
+) {
+    composite::overlay(rgba_data, mask, width as usize, offset, out);
+}
 
-    result
+/// Make the background transparent, for cut-outs saved as PNG or WebP.
+pub fn cut_out_person(
+    rgba_data: &[u8],
+    mask: &[f32],
+    width: u32,
+    offset: (usize, usize),
+    out: &mut [u8],
+) {
+    composite::cut_out(rgba_data, mask, width as usize, offset, out);
 }
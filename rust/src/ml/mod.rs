@@ -1,7 +1,19 @@
+pub mod annotation;
+pub mod composite;
 pub mod image;
+pub mod model;
+pub mod resize;
+pub mod roi;
+#[cfg(test)]
+mod test_util;
 pub mod my_model {
     include!(concat!(
         env!("OUT_DIR"),
         "/model/selfie_segmentation_converted.rs"
     ));
 }
+// Lighter model given at build time, see `model::ModelVariant`.
+#[cfg(lite_model)]
+pub mod lite_model {
+    include!(concat!(env!("OUT_DIR"), "/lite_model/model.rs"));
+}
@@ -0,0 +1,379 @@
+use std::sync::{Mutex, OnceLock};
+
+use burn::tensor::Tensor;
+use burn_ndarray::{NdArray, NdArrayDevice};
+use serde::Deserialize;
+
+#[cfg(lite_model)]
+use super::lite_model::Model as LiteModel;
+use super::my_model::Model;
+
+pub type Backend = NdArray<f32>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChannelOrder {
+    Rgb,
+    Bgr,
+}
+
+/// Memory layout of the input and output tensors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TensorLayout {
+    Nchw,
+    Nhwc,
+}
+
+/// What the model outputs, and so what is applied to get probabilities.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OutputActivation {
+    /// Already in 0..=1, used as is.
+    Probability,
+    /// Raw logits of a single class, a sigmoid is applied.
+    Sigmoid,
+    /// Raw logits over the channels, a softmax is applied across them.
+    Softmax,
+}
+
+/// Input and output conventions of a segmentation model.
+///
+/// The bundled model gets them from the JSON file named by `ONNX_MODEL_DESCRIPTOR` at build
+/// time, next to `ONNX_MODEL_PATH`, so another ONNX model only needs its descriptor:
+///
+/// ```json
+/// { "input_width": 224, "input_height": 224, "channel_order": "bgr",
+///   "mean": [0.485, 0.456, 0.406], "std": [0.229, 0.224, 0.225],
+///   "layout": "nhwc", "activation": "softmax", "output_channel": 1 }
+/// ```
+///
+/// Fields left out keep the values of `SELFIE_SEGMENTATION`.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ModelDescriptor {
+    pub input_width: u32,
+    pub input_height: u32,
+    pub channel_order: ChannelOrder,
+    /// Applied to the 0..=1 pixel values : `(value - mean) / std`.
+    pub mean: [f32; 3],
+    pub std: [f32; 3],
+    pub layout: TensorLayout,
+    pub activation: OutputActivation,
+    /// Output channel holding the person class.
+    pub output_channel: usize,
+}
+
+impl ModelDescriptor {
+    pub fn input_shape(&self) -> [usize; 4] {
+        let (w, h) = (self.input_width as usize, self.input_height as usize);
+        match self.layout {
+            TensorLayout::Nchw => [1, 3, h, w],
+            TensorLayout::Nhwc => [1, h, w, 3],
+        }
+    }
+
+    pub fn input_len(&self) -> usize {
+        3 * (self.input_width * self.input_height) as usize
+    }
+
+    /// Write the person probability of each output pixel to `out`, row major.
+    /// `dims` is the shape of the output tensor. Returns its `(width, height)`.
+    pub fn person_scores(
+        &self,
+        output: &[f32],
+        dims: [usize; 4],
+        out: &mut Vec<f32>,
+    ) -> (usize, usize) {
+        // Converters often leave single channel outputs in NHWC, accept both layouts for them.
+        // Softmax outputs have several channels, their layout is never guessed.
+        let single = self.activation != OutputActivation::Softmax;
+        let layout = match (self.layout, dims[1], dims[3]) {
+            (TensorLayout::Nchw, channels, 1) if single && channels != 1 => TensorLayout::Nhwc,
+            (TensorLayout::Nhwc, 1, channels) if single && channels != 1 => TensorLayout::Nchw,
+            (layout, _, _) => layout,
+        };
+        let (channels, height, width) = match layout {
+            TensorLayout::Nchw => (dims[1], dims[2], dims[3]),
+            TensorLayout::Nhwc => (dims[3], dims[1], dims[2]),
+        };
+        let plane = width * height;
+        let channel = self.output_channel.min(channels - 1);
+        let at = |pixel: usize, c: usize| match layout {
+            TensorLayout::Nchw => output[c * plane + pixel],
+            TensorLayout::Nhwc => output[pixel * channels + c],
+        };
+
+        out.clear();
+        out.extend((0..plane).map(|pixel| {
+            let value = at(pixel, channel);
+            match self.activation {
+                OutputActivation::Probability => value,
+                OutputActivation::Sigmoid => 1.0 / (1.0 + (-value).exp()),
+                OutputActivation::Softmax => {
+                    let max = (0..channels)
+                        .map(|c| at(pixel, c))
+                        .fold(f32::NEG_INFINITY, f32::max);
+                    let sum: f32 = (0..channels).map(|c| (at(pixel, c) - max).exp()).sum();
+                    (value - max).exp() / sum
+                }
+            }
+        }));
+
+        (width, height)
+    }
+}
+
+impl Default for ModelDescriptor {
+    fn default() -> Self {
+        SELFIE_SEGMENTATION
+    }
+}
+
+/// MediaPipe selfie segmentation, converted from ONNX at build time.
+pub const SELFIE_SEGMENTATION: ModelDescriptor = ModelDescriptor {
+    input_width: 256,
+    input_height: 256,
+    channel_order: ChannelOrder::Rgb,
+    mean: [0.0; 3],
+    std: [1.0; 3],
+    layout: TensorLayout::Nchw,
+    activation: OutputActivation::Probability,
+    output_channel: 0,
+};
+
+/// Which compiled model a segmentation runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ModelVariant {
+    #[default]
+    Full,
+    /// The model given with `ONNX_LITE_MODEL_PATH` at build time, usually on a smaller input.
+    /// The full model runs instead when the crate was built without one.
+    Lite,
+}
+
+/// Whether a lite model was compiled in, see `ModelVariant::Lite`.
+pub fn has_lite_model() -> bool {
+    cfg!(lite_model)
+}
+
+static BUNDLED_DESCRIPTOR: OnceLock<ModelDescriptor> = OnceLock::new();
+
+/// Conventions of the model compiled in, from the descriptor given at build time.
+pub fn bundled_descriptor() -> ModelDescriptor {
+    *BUNDLED_DESCRIPTOR.get_or_init(|| {
+        let json = include_str!(concat!(env!("OUT_DIR"), "/model/descriptor.json"));
+        serde_json::from_str(json).unwrap_or_else(|e| {
+            log::error!("Invalid model descriptor, using the selfie segmentation one: {e}");
+            SELFIE_SEGMENTATION
+        })
+    })
+}
+
+#[cfg(lite_model)]
+static LITE_DESCRIPTOR: OnceLock<ModelDescriptor> = OnceLock::new();
+
+/// Conventions of the lite model, fields left out keep the values of the selfie segmentation.
+#[cfg(lite_model)]
+pub fn lite_descriptor() -> ModelDescriptor {
+    *LITE_DESCRIPTOR.get_or_init(|| {
+        let json = include_str!(concat!(env!("OUT_DIR"), "/lite_model/descriptor.json"));
+        serde_json::from_str(json).unwrap_or_else(|e| {
+            log::error!("Invalid lite model descriptor, using the selfie segmentation one: {e}");
+            SELFIE_SEGMENTATION
+        })
+    })
+}
+
+// Loaded once per process and shared by every stream.
+// Streams get their own clone : with the ndarray backend the weights are
+// reference counted, so cloning is cheap and inference doesn't need a lock.
+static SELFIE_MODEL: OnceLock<Mutex<Model<Backend>>> = OnceLock::new();
+
+/// Load and warm up every model. Safe to call several times.
+pub fn preload() {
+    selfie_model();
+}
+
+/// Return a handle on the shared selfie segmentation model, loading it if needed.
+pub fn load_selfie_model() -> Model<Backend> {
+    selfie_model().lock().unwrap().clone()
+}
+
+fn selfie_model() -> &'static Mutex<Model<Backend>> {
+    SELFIE_MODEL.get_or_init(|| {
+        let device = NdArrayDevice::default();
+        let start = std::time::Instant::now();
+        let model = Model::default();
+        warm_up(&model, &bundled_descriptor(), &device);
+        log::info!("Selfie model ready in {:?}", start.elapsed());
+        Mutex::new(model)
+    })
+}
+
+#[cfg(lite_model)]
+static LITE_MODEL: OnceLock<Mutex<LiteModel<Backend>>> = OnceLock::new();
+
+/// Return a handle on the shared lite model, loading it on first use.
+#[cfg(lite_model)]
+pub fn load_lite_model() -> LiteModel<Backend> {
+    LITE_MODEL
+        .get_or_init(|| {
+            let device = NdArrayDevice::default();
+            let start = std::time::Instant::now();
+            let model = LiteModel::default();
+            let input = Tensor::<Backend, 4>::zeros(lite_descriptor().input_shape(), &device);
+            let _ = model.forward(input).into_data();
+            log::info!("Lite model ready in {:?}", start.elapsed());
+            Mutex::new(model)
+        })
+        .lock()
+        .unwrap()
+        .clone()
+}
+
+// The first forward pass is a lot slower than the next ones,
+// so run it on a dummy input instead of the first camera frame.
+fn warm_up(model: &Model<Backend>, descriptor: &ModelDescriptor, device: &NdArrayDevice) {
+    let input = Tensor::<Backend, 4>::zeros(descriptor.input_shape(), device);
+    let _ = model.forward(input).into_data();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ml::resize::{rgba_to_input, Sampler};
+
+    // A 2x1 frame, red then blue, kept at its size.
+    fn input(descriptor: &ModelDescriptor) -> Vec<f32> {
+        let rgba = [255, 0, 0, 255, 0, 0, 255, 255];
+        let (mut xs, mut ys) = (Sampler::default(), Sampler::default());
+        xs.update(2, 2);
+        ys.update(1, 1);
+        let mut out = vec![0.0; 6];
+        rgba_to_input(&rgba, 2, &xs, &ys, descriptor, &mut Vec::new(), &mut out);
+        out
+    }
+
+    fn descriptor(width: u32, height: u32) -> ModelDescriptor {
+        ModelDescriptor {
+            input_width: width,
+            input_height: height,
+            ..SELFIE_SEGMENTATION
+        }
+    }
+
+    fn assert_close(actual: &[f32], expected: &[f32]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-5, "{actual:?} != {expected:?}");
+        }
+    }
+
+    #[test]
+    fn input_is_normalized_per_channel() {
+        let descriptor = ModelDescriptor {
+            mean: [0.5, 0.25, 0.0],
+            std: [0.5, 0.25, 2.0],
+            ..descriptor(2, 1)
+        };
+        // Planes of R, G then B: (1 - 0.5) / 0.5 and (0 - 0.5) / 0.5, ...
+        assert_close(&input(&descriptor), &[1.0, -1.0, -1.0, -1.0, 0.0, 0.5]);
+    }
+
+    #[test]
+    fn input_follows_channel_order_and_layout() {
+        let bgr = ModelDescriptor {
+            channel_order: ChannelOrder::Bgr,
+            ..descriptor(2, 1)
+        };
+        assert_close(&input(&bgr), &[0.0, 1.0, 0.0, 0.0, 1.0, 0.0]);
+
+        let nhwc = ModelDescriptor {
+            layout: TensorLayout::Nhwc,
+            ..descriptor(2, 1)
+        };
+        assert_close(&input(&nhwc), &[1.0, 0.0, 0.0, 0.0, 0.0, 1.0]);
+        assert_eq!(nhwc.input_shape(), [1, 1, 2, 3]);
+        assert_eq!(descriptor(2, 1).input_shape(), [1, 3, 1, 2]);
+    }
+
+    #[test]
+    fn sigmoid_scores() {
+        let sigmoid = ModelDescriptor {
+            activation: OutputActivation::Sigmoid,
+            ..SELFIE_SEGMENTATION
+        };
+        let mut out = Vec::new();
+        let size = sigmoid.person_scores(&[0.0, 100.0, -100.0], [1, 1, 1, 3], &mut out);
+        assert_eq!(size, (3, 1));
+        assert_close(&out, &[0.5, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn softmax_scores_pick_the_person_channel() {
+        let softmax = ModelDescriptor {
+            activation: OutputActivation::Softmax,
+            output_channel: 1,
+            ..SELFIE_SEGMENTATION
+        };
+        // Two pixels, background then person logits.
+        let nchw = [0.0, 2.0, 0.0, -2.0];
+        let mut out = Vec::new();
+        assert_eq!(softmax.person_scores(&nchw, [1, 2, 1, 2], &mut out), (2, 1));
+        let e = 1.0 / (1.0 + 4f32.exp());
+        assert_close(&out, &[0.5, e]);
+
+        let nhwc = ModelDescriptor {
+            layout: TensorLayout::Nhwc,
+            ..softmax
+        };
+        let interleaved = [0.0, 0.0, 2.0, -2.0];
+        assert_eq!(
+            nhwc.person_scores(&interleaved, [1, 1, 2, 2], &mut out),
+            (2, 1)
+        );
+        assert_close(&out, &[0.5, e]);
+    }
+
+    #[test]
+    fn single_channel_output_in_either_layout() {
+        let mut out = Vec::new();
+        let scores = [0.1, 0.2, 0.3, 0.4, 0.5, 0.6];
+        assert_eq!(
+            SELFIE_SEGMENTATION.person_scores(&scores, [1, 1, 2, 3], &mut out),
+            (3, 2)
+        );
+        assert_close(&out, &scores);
+        assert_eq!(
+            SELFIE_SEGMENTATION.person_scores(&scores, [1, 2, 3, 1], &mut out),
+            (3, 2)
+        );
+        assert_close(&out, &scores);
+    }
+
+    #[test]
+    fn descriptor_json_overrides_the_defaults() {
+        let descriptor: ModelDescriptor = serde_json::from_str(
+            r#"{ "input_width": 224, "channel_order": "bgr", "layout": "nhwc",
+                 "activation": "softmax", "output_channel": 1 }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            descriptor,
+            ModelDescriptor {
+                input_width: 224,
+                channel_order: ChannelOrder::Bgr,
+                layout: TensorLayout::Nhwc,
+                activation: OutputActivation::Softmax,
+                output_channel: 1,
+                ..SELFIE_SEGMENTATION
+            }
+        );
+        assert_eq!(
+            serde_json::from_str::<ModelDescriptor>("{}").unwrap(),
+            SELFIE_SEGMENTATION
+        );
+        assert!(serde_json::from_str::<ModelDescriptor>(r#"{ "size": 1 }"#).is_err());
+    }
+}
@@ -0,0 +1,327 @@
+// Fused resize kernels used around inference.
+// They write into caller-owned buffers so nothing is allocated per frame.
+
+use super::model::{ChannelOrder, ModelDescriptor, TensorLayout};
+
+/// Triangle filter weights along one axis, rebuilt only when the sizes change.
+/// When shrinking, the filter widens with the scale so every source pixel counts, like the
+/// `Triangle` filter of the image crate. Each output value has the same number of taps.
+#[derive(Default)]
+pub struct Sampler {
+    src_len: usize,
+    dst_len: usize,
+    taps: usize,
+    // First source index of each output value.
+    starts: Vec<usize>,
+    // `taps` weights per output value, summing to 1.
+    weights: Vec<f32>,
+}
+
+impl Sampler {
+    pub fn update(&mut self, src_len: usize, dst_len: usize) {
+        if self.src_len == src_len && self.dst_len == dst_len {
+            return;
+        }
+        self.src_len = src_len;
+        self.dst_len = dst_len;
+
+        let scale = src_len as f32 / dst_len as f32;
+        let support = scale.max(1.0);
+        let taps = ((2.0 * support).ceil() as usize + 1).min(src_len);
+        self.taps = taps;
+        self.starts.clear();
+        self.weights.clear();
+        for d in 0..dst_len {
+            let center = (d as f32 + 0.5) * scale;
+            let left = ((center - support).floor().max(0.0) as usize).min(src_len - 1);
+            let right = ((center + support).ceil() as usize).clamp(left + 1, src_len);
+            let start = left.min(src_len - taps);
+            let weight = |i: usize| {
+                let x = (i as f32 + 0.5 - center).abs() / support;
+                if (left..right).contains(&i) {
+                    (1.0 - x).max(0.0)
+                } else {
+                    0.0
+                }
+            };
+            let total: f32 = (start..start + taps).map(weight).sum();
+            self.starts.push(start);
+            if total > 0.0 {
+                self.weights
+                    .extend((start..start + taps).map(|i| weight(i) / total));
+            } else {
+                // Nearest source value.
+                let nearest = (center as usize).min(src_len - 1);
+                self.weights
+                    .extend((start..start + taps).map(|i| (i == nearest) as u8 as f32));
+            }
+        }
+    }
+
+    fn taps(&self, d: usize) -> (usize, &[f32]) {
+        (
+            self.starts[d],
+            &self.weights[d * self.taps..(d + 1) * self.taps],
+        )
+    }
+}
+
+/// Resize an RGBA frame and write it as the model input, normalized and in the model layout.
+///
+/// `width` is the row length of `rgba_data` in pixels, the samplers can cover only part of it
+/// to resize a crop. `out` must hold `descriptor.input_len()` values. `scratch` keeps the
+/// horizontal pass from one call to the next.
+pub fn rgba_to_input(
+    rgba_data: &[u8],
+    width: usize,
+    xs: &Sampler,
+    ys: &Sampler,
+    descriptor: &ModelDescriptor,
+    scratch: &mut Vec<f32>,
+    out: &mut [f32],
+) {
+    let dst_w = xs.dst_len;
+    let plane = dst_w * ys.dst_len;
+    debug_assert_eq!(out.len(), plane * 3);
+
+    let source_channels = match descriptor.channel_order {
+        ChannelOrder::Rgb => [0, 1, 2],
+        ChannelOrder::Bgr => [2, 1, 0],
+    };
+    // `(p / 255 - mean) / std` folded in a single multiply-add.
+    let scale: [f32; 3] = std::array::from_fn(|c| 1.0 / (255.0 * descriptor.std[c]));
+    let offset: [f32; 3] = std::array::from_fn(|c| -descriptor.mean[c] / descriptor.std[c]);
+    let (channel_stride, pixel_stride) = match descriptor.layout {
+        TensorLayout::Nchw => (plane, 1),
+        TensorLayout::Nhwc => (1, 3),
+    };
+
+    // Horizontal pass over the source rows, in model channel order.
+    scratch.resize(ys.src_len * dst_w * 3, 0.0);
+    for (y, row) in scratch.chunks_exact_mut(dst_w * 3).enumerate() {
+        let source = &rgba_data[y * width * 4..];
+        for (ox, pixel) in row.chunks_exact_mut(3).enumerate() {
+            let (start, weights) = xs.taps(ox);
+            let mut sum = [0.0; 3];
+            for (k, &weight) in weights.iter().enumerate() {
+                let p = (start + k) * 4;
+                for (ch, &src) in source_channels.iter().enumerate() {
+                    sum[ch] += source[p + src] as f32 * weight;
+                }
+            }
+            pixel.copy_from_slice(&sum);
+        }
+    }
+
+    // Vertical pass, normalized into the model input.
+    for oy in 0..ys.dst_len {
+        let (start, weights) = ys.taps(oy);
+        for ox in 0..dst_w {
+            let mut sum = [0.0; 3];
+            for (k, &weight) in weights.iter().enumerate() {
+                let p = ((start + k) * dst_w + ox) * 3;
+                for (ch, value) in sum.iter_mut().enumerate() {
+                    *value += scratch[p + ch] * weight;
+                }
+            }
+            let out_idx = (oy * dst_w + ox) * pixel_stride;
+            for ch in 0..3 {
+                out[ch * channel_stride + out_idx] = sum[ch] * scale[ch] + offset[ch];
+            }
+        }
+    }
+}
+
+/// Resize a single channel mask, clamping values to 0..=1.
+///
+/// Rows are written `out_stride` values apart, so the result can land in a region of a larger mask.
+pub fn resize_mask(
+    mask: &[f32],
+    width: usize,
+    xs: &Sampler,
+    ys: &Sampler,
+    out: &mut [f32],
+    out_stride: usize,
+) {
+    let dst_w = xs.dst_len;
+
+    for oy in 0..ys.dst_len {
+        let (y_start, y_weights) = ys.taps(oy);
+        let out_row = &mut out[oy * out_stride..oy * out_stride + dst_w];
+        for (ox, o) in out_row.iter_mut().enumerate() {
+            let (x_start, x_weights) = xs.taps(ox);
+            let mut sum = 0.0;
+            for (ky, &wy) in y_weights.iter().enumerate() {
+                let row = &mask[(y_start + ky) * width + x_start..];
+                let row_sum: f32 = x_weights.iter().zip(row).map(|(w, v)| w * v).sum();
+                sum += row_sum * wy;
+            }
+            *o = sum.clamp(0.0, 1.0);
+        }
+    }
+}
+
+/// Soften the mask edges with a box filter of `radius` pixels, in place.
+/// Removes the stair steps left by upscaling the model output.
+pub fn smooth_mask(
+    mask: &mut [f32],
+    scratch: &mut Vec<f32>,
+    width: usize,
+    height: usize,
+    radius: usize,
+) {
+    scratch.resize(mask.len(), 0.0);
+    let scale = 1.0 / (2 * radius + 1) as f32;
+
+    // Horizontal pass into the scratch buffer, vertical pass back into the mask.
+    for (src, dst) in mask
+        .chunks_exact(width)
+        .zip(scratch.chunks_exact_mut(width))
+    {
+        box_pass(|i| src[i], |i, v| dst[i] = v, width, radius, scale);
+    }
+    for x in 0..width {
+        box_pass(
+            |i| scratch[i * width + x],
+            |i, v| mask[i * width + x] = v,
+            height,
+            radius,
+            scale,
+        );
+    }
+}
+
+// Running sum box filter over `len` values, edges are clamped.
+#[inline(always)]
+fn box_pass(
+    get: impl Fn(usize) -> f32,
+    mut set: impl FnMut(usize, f32),
+    len: usize,
+    radius: usize,
+    scale: f32,
+) {
+    let last = len - 1;
+    let mut sum: f32 = (0..=2 * radius)
+        .map(|i| get(i.saturating_sub(radius).min(last)))
+        .sum();
+    for i in 0..len {
+        set(i, sum * scale);
+        sum += get((i + radius + 1).min(last)) - get(i.saturating_sub(radius));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use image::imageops::{self, FilterType};
+
+    use super::*;
+    use crate::ml::model::SELFIE_SEGMENTATION;
+    use crate::ml::test_util::noise_bytes;
+
+    const WIDTH: usize = 640;
+    const HEIGHT: usize = 480;
+
+    fn to_input(rgba: &[u8], width: usize, height: usize, size: usize) -> Vec<f32> {
+        let (mut xs, mut ys) = (Sampler::default(), Sampler::default());
+        xs.update(width, size);
+        ys.update(height, size);
+        let mut out = vec![0.0; 3 * size * size];
+        let descriptor = SELFIE_SEGMENTATION;
+        rgba_to_input(
+            rgba,
+            width,
+            &xs,
+            &ys,
+            &descriptor,
+            &mut Vec::new(),
+            &mut out,
+        );
+        out
+    }
+
+    #[test]
+    fn input_matches_image_crate_triangle() {
+        let rgba = noise_bytes(1, WIDTH * HEIGHT * 4);
+        let fused = to_input(&rgba, WIDTH, HEIGHT, 256);
+
+        let image = image::RgbaImage::from_raw(WIDTH as u32, HEIGHT as u32, rgba).unwrap();
+        let expected = imageops::resize(&image, 256, 256, FilterType::Triangle);
+        let plane = 256 * 256;
+        let max_error = expected
+            .pixels()
+            .enumerate()
+            .flat_map(|(i, p)| (0..3).map(move |c| (c * plane + i, p[c])))
+            .map(|(idx, v)| (fused[idx] - v as f32 / 255.0).abs())
+            .fold(0.0, f32::max);
+        assert!(max_error <= 1.0 / 255.0, "max error {max_error}");
+    }
+
+    #[test]
+    fn downscale_averages_fine_detail() {
+        // One pixel checkerboard, point sampling would give stripes of black and white.
+        let rgba: Vec<u8> = (0..WIDTH * HEIGHT)
+            .flat_map(|i| {
+                let v = if (i % WIDTH + i / WIDTH).is_multiple_of(2) {
+                    255
+                } else {
+                    0
+                };
+                [v, v, v, 255]
+            })
+            .collect();
+        let input = to_input(&rgba, WIDTH, HEIGHT, 256);
+        for value in input {
+            assert!((value - 0.5).abs() < 0.1, "{value}");
+        }
+    }
+
+    #[test]
+    fn mask_matches_image_crate_triangle() {
+        let size = 256;
+        let scores: Vec<f32> = noise_bytes(2, size * size)
+            .into_iter()
+            .map(|v| v as f32 / 255.0)
+            .collect();
+        let (mut xs, mut ys) = (Sampler::default(), Sampler::default());
+        xs.update(size, WIDTH);
+        ys.update(size, HEIGHT);
+        let mut mask = vec![0.0; WIDTH * HEIGHT];
+        resize_mask(&scores, size, &xs, &ys, &mut mask, WIDTH);
+
+        let image = image::GrayImage::from_fn(size as u32, size as u32, |x, y| {
+            image::Luma([(scores[y as usize * size + x as usize] * 255.0).round() as u8])
+        });
+        let expected = imageops::resize(&image, WIDTH as u32, HEIGHT as u32, FilterType::Triangle);
+        let max_error = expected
+            .pixels()
+            .zip(&mask)
+            .map(|(p, &m)| (m - p[0] as f32 / 255.0).abs())
+            .fold(0.0, f32::max);
+        assert!(max_error <= 1.0 / 255.0, "max error {max_error}");
+    }
+
+    #[test]
+    fn crop_is_resized_in_place() {
+        let rgba = noise_bytes(3, WIDTH * HEIGHT * 4);
+        let (x, y, w, h) = (100, 50, 200, 300);
+        let crop: Vec<u8> = (y..y + h)
+            .flat_map(|row| rgba[(row * WIDTH + x) * 4..(row * WIDTH + x + w) * 4].to_vec())
+            .collect();
+        let from_crop = to_input(&crop, w, h, 64);
+
+        let (mut xs, mut ys) = (Sampler::default(), Sampler::default());
+        xs.update(w, 64);
+        ys.update(h, 64);
+        let mut in_frame = vec![0.0; 3 * 64 * 64];
+        rgba_to_input(
+            &rgba[(y * WIDTH + x) * 4..],
+            WIDTH,
+            &xs,
+            &ys,
+            &SELFIE_SEGMENTATION,
+            &mut Vec::new(),
+            &mut in_frame,
+        );
+        assert_eq!(from_crop, in_frame);
+    }
+}
@@ -0,0 +1,248 @@
+// Region of interest tracking.
+// When the person only covers a small part of the frame, running the model on a crop
+// around them gives a much finer mask than shrinking the whole frame to the model size.
+
+/// Axis aligned rectangle, in frame pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rect {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Rect {
+    pub fn full(width: usize, height: usize) -> Rect {
+        Rect {
+            x: 0,
+            y: 0,
+            width,
+            height,
+        }
+    }
+}
+
+// Mask values above this are counted as part of the person.
+pub const PERSON_THRESHOLD: f32 = 0.5;
+// A person covering less of the frame than this is treated as noise in the mask.
+pub const MIN_PERSON_COVERAGE: f32 = 0.01;
+// Mask values this close to 0 or 1 are counted as confident.
+const CONFIDENT_MARGIN: f32 = 0.1;
+// Margin added on each side of the person box, relative to its size.
+const PADDING: f32 = 0.25;
+// Below this fraction of the frame, tracking is considered lost.
+const MIN_PERSON_AREA: f32 = 0.005;
+// Crops covering more than this fraction of the frame are not worth it.
+const MAX_CROP_AREA: f32 = 0.7;
+// Run a full frame inference from time to time to pick up people entering the frame.
+const REFRESH_INTERVAL: u32 = 30;
+
+#[derive(Default)]
+pub struct RoiTracker {
+    roi: Option<Rect>,
+    frames_since_full: u32,
+}
+
+impl RoiTracker {
+    /// Region to run the next inference on, `None` for the whole frame.
+    pub fn next_region(&mut self) -> Option<Rect> {
+        if self.roi.is_none() || self.frames_since_full >= REFRESH_INTERVAL {
+            self.frames_since_full = 0;
+            return None;
+        }
+        self.frames_since_full += 1;
+        self.roi
+    }
+
+    /// Track the person from the full frame mask of the last inference.
+    pub fn update(&mut self, mask: &[f32], width: usize, height: usize) {
+        self.roi = person_bounds(mask, width, height).and_then(|b| padded(b, width, height));
+    }
+
+    pub fn reset(&mut self) {
+        self.roi = None;
+        self.frames_since_full = 0;
+    }
+}
+
+/// What a mask says about the frame, for picking frames rather than compositing them.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct MaskStats {
+    /// Fraction of the frame where the mask says person.
+    pub coverage: f32,
+    /// Fraction of the frame where the mask is close to 0 or 1, low when the model hesitates.
+    pub confidence: f32,
+}
+
+pub fn mask_stats(mask: &[f32]) -> MaskStats {
+    let (mut person, mut confident) = (0, 0);
+    for &v in mask {
+        person += (v > PERSON_THRESHOLD) as usize;
+        confident += !(CONFIDENT_MARGIN..=1.0 - CONFIDENT_MARGIN).contains(&v) as usize;
+    }
+    let len = mask.len().max(1) as f32;
+    MaskStats {
+        coverage: person as f32 / len,
+        confidence: confident as f32 / len,
+    }
+}
+
+fn person_bounds(mask: &[f32], width: usize, height: usize) -> Option<Rect> {
+    let (mut min_x, mut min_y) = (usize::MAX, usize::MAX);
+    let (mut max_x, mut max_y) = (0, 0);
+    let mut count = 0usize;
+
+    for (y, row) in mask.chunks_exact(width).enumerate() {
+        let mut row_hit = false;
+        for (x, &v) in row.iter().enumerate() {
+            if v > PERSON_THRESHOLD {
+                min_x = min_x.min(x);
+                max_x = max_x.max(x);
+                count += 1;
+                row_hit = true;
+            }
+        }
+        if row_hit {
+            min_y = min_y.min(y);
+            max_y = max_y.max(y);
+        }
+    }
+
+    if (count as f32) < MIN_PERSON_AREA * (width * height) as f32 {
+        return None;
+    }
+    Some(Rect {
+        x: min_x,
+        y: min_y,
+        width: max_x - min_x + 1,
+        height: max_y - min_y + 1,
+    })
+}
+
+// Grow the box by the padding and to the frame aspect ratio, so the crop gets
+// the same distortion as the full frame once resized to the model input.
+fn padded(bounds: Rect, width: usize, height: usize) -> Option<Rect> {
+    let aspect = width as f32 / height as f32;
+    let mut w = bounds.width as f32 * (1.0 + 2.0 * PADDING);
+    let mut h = bounds.height as f32 * (1.0 + 2.0 * PADDING);
+    if w / h > aspect {
+        h = w / aspect;
+    } else {
+        w = h * aspect;
+    }
+
+    if w * h > MAX_CROP_AREA * (width * height) as f32 {
+        return None;
+    }
+
+    let w = (w.ceil() as usize).min(width);
+    let h = (h.ceil() as usize).min(height);
+    let center_x = bounds.x + bounds.width / 2;
+    let center_y = bounds.y + bounds.height / 2;
+    Some(Rect {
+        x: center_x.saturating_sub(w / 2).min(width - w),
+        y: center_y.saturating_sub(h / 2).min(height - h),
+        width: w,
+        height: h,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WIDTH: usize = 640;
+    const HEIGHT: usize = 480;
+
+    // Mask with a person filling `rect`.
+    fn mask(rect: Rect) -> Vec<f32> {
+        (0..WIDTH * HEIGHT)
+            .map(|i| {
+                let (x, y) = (i % WIDTH, i / WIDTH);
+                let inside = (rect.x..rect.x + rect.width).contains(&x)
+                    && (rect.y..rect.y + rect.height).contains(&y);
+                inside as u8 as f32
+            })
+            .collect()
+    }
+
+    fn rect(x: usize, y: usize, width: usize, height: usize) -> Rect {
+        Rect {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn empty_mask_keeps_full_frame() {
+        let empty = vec![0.0; WIDTH * HEIGHT];
+        assert_eq!(person_bounds(&empty, WIDTH, HEIGHT), None);
+
+        let mut tracker = RoiTracker::default();
+        tracker.update(&empty, WIDTH, HEIGHT);
+        assert_eq!(tracker.next_region(), None);
+    }
+
+    #[test]
+    fn bounds_of_the_person() {
+        let person = rect(200, 100, 80, 160);
+        assert_eq!(person_bounds(&mask(person), WIDTH, HEIGHT), Some(person));
+    }
+
+    #[test]
+    fn padding_keeps_aspect_ratio() {
+        let region = padded(rect(280, 160, 80, 160), WIDTH, HEIGHT).unwrap();
+        // 160 * 1.5 = 240 high, 320 wide at 4:3, centered on the person.
+        assert_eq!(region, rect(160, 120, 320, 240));
+    }
+
+    #[test]
+    fn padding_is_clamped_at_frame_edges() {
+        let corner = padded(rect(0, 0, 60, 120), WIDTH, HEIGHT).unwrap();
+        assert_eq!((corner.x, corner.y), (0, 0));
+
+        let far = padded(rect(590, 380, 50, 100), WIDTH, HEIGHT).unwrap();
+        assert_eq!(far.x + far.width, WIDTH);
+        assert_eq!(far.y + far.height, HEIGHT);
+    }
+
+    #[test]
+    fn small_person_falls_back_to_full_frame() {
+        // 0.4% of the frame, below MIN_PERSON_AREA.
+        let tiny = mask(rect(300, 200, 32, 38));
+        assert_eq!(person_bounds(&tiny, WIDTH, HEIGHT), None);
+
+        let mut tracker = RoiTracker::default();
+        tracker.update(&mask(rect(280, 160, 80, 160)), WIDTH, HEIGHT);
+        assert!(tracker.next_region().is_some());
+        tracker.update(&tiny, WIDTH, HEIGHT);
+        assert_eq!(tracker.next_region(), None);
+    }
+
+    #[test]
+    fn large_person_is_not_cropped() {
+        assert_eq!(padded(rect(100, 50, 440, 400), WIDTH, HEIGHT), None);
+    }
+
+    #[test]
+    fn full_frame_inference_every_refresh_interval() {
+        let mut tracker = RoiTracker::default();
+        tracker.update(&mask(rect(280, 160, 80, 160)), WIDTH, HEIGHT);
+        let regions: Vec<_> = (0..=REFRESH_INTERVAL)
+            .map(|_| tracker.next_region())
+            .collect();
+        assert!(regions[..REFRESH_INTERVAL as usize]
+            .iter()
+            .all(Option::is_some));
+        assert_eq!(regions[REFRESH_INTERVAL as usize], None);
+    }
+
+    #[test]
+    fn stats_of_a_mask() {
+        let stats = mask_stats(&[0.0, 1.0, 0.6, 0.95]);
+        assert_eq!(stats.coverage, 0.75);
+        assert_eq!(stats.confidence, 0.75);
+    }
+}
@@ -0,0 +1,18 @@
+// Helpers shared by the tests of the ml modules.
+
+// Small xorshift, enough to get noisy frames without a rand dependency.
+pub fn noise(seed: u32, len: usize) -> Vec<u32> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state
+        })
+        .collect()
+}
+
+pub fn noise_bytes(seed: u32, len: usize) -> Vec<u8> {
+    noise(seed, len).into_iter().map(|v| v as u8).collect()
+}
@@ -0,0 +1,590 @@
+// Session archives, for reproducing bug reports.
+// An archive holds the raw camera frames of a stream with the effect settings and the
+// processing state of each, so replaying it through a `FrameProcessor` gives the same
+// output as the live stream did.
+//
+// The file starts with `MAGIC`, followed by records made of a tag byte, the payload length
+// as a little endian u32, and the payload.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufWriter, Read, Write};
+use std::sync::mpsc::{self, Receiver, SyncSender};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::thread::{self, JoinHandle};
+
+use image::{ImageFormat, RgbaImage};
+use serde::{Deserialize, Serialize};
+
+use crate::media::convert::{FrameEncoder, OutputFormat};
+
+use super::frame::unix_micros;
+use super::pool::PooledFrame;
+use super::processor::EffectSettings;
+
+const MAGIC: &[u8; 8] = b"CAMSESS1";
+const VERSION: u32 = 1;
+// Frames waiting to be written. Past this the stream waits, a lost frame would make
+// the replay diverge.
+const QUEUE_DEPTH: usize = 8;
+// Largest record payload, above any PNG of a camera frame. A corrupt length fails
+// instead of allocating up to 4 GiB.
+const MAX_RECORD_LEN: usize = 256 << 20;
+
+// JSON `ArchiveHeader`.
+const TAG_HEADER: u8 = 1;
+// u32 id, then the image as PNG.
+const TAG_BACKGROUND: u8 = 2;
+// JSON `StoredSettings`, applies to the next frames.
+const TAG_SETTINGS: u8 = 3;
+// `FrameState`, then the raw RGBA frame as PNG.
+const TAG_FRAME: u8 = 4;
+// Empty, the processor starts over from a clean state.
+const TAG_RESET: u8 = 5;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveHeader {
+    pub version: u32,
+    pub camera: u32,
+    pub started_us: u64,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+struct StoredSettings {
+    timestamp_us: u64,
+    blur: bool,
+    /// Id of a background record.
+    background: Option<u32>,
+    debug: bool,
+    roi: bool,
+    // Missing from archives written before it was added.
+    #[serde(default)]
+    refine: bool,
+    #[serde(default)]
+    temporal: bool,
+}
+
+/// How the processor was set for a frame, besides the effect settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrameState {
+    pub timestamp_us: u64,
+    pub sequence: u64,
+    /// Index in the adaptive quality levels.
+    pub quality_level: u8,
+    pub low_power: bool,
+    /// The mask was computed after processing, for a snapshot or a tap, see
+    /// `FrameProcessor::computed_extra_mask`.
+    pub extra_mask: bool,
+}
+
+const FRAME_STATE_LEN: usize = 8 + 8 + 4 + 4 + 3;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CaptureSummary {
+    pub frames: u64,
+    pub settings_changes: u64,
+    pub bytes: u64,
+}
+
+enum Message {
+    Reset,
+    Settings(EffectSettings, u64),
+    Frame {
+        state: FrameState,
+        pixels: Arc<PooledFrame>,
+        width: u32,
+        height: u32,
+    },
+}
+
+/// Writes an archive from the processing thread of the streams on a camera.
+pub struct SessionCapture {
+    sender: Mutex<Option<SyncSender<Message>>>,
+    // Settings of the last archived frame, only changes are written.
+    last_settings: Mutex<Option<EffectSettings>>,
+    writer: Mutex<Option<JoinHandle<io::Result<CaptureSummary>>>>,
+}
+
+impl SessionCapture {
+    pub fn start(file: File, camera: u32) -> io::Result<SessionCapture> {
+        let mut out = BufWriter::new(file);
+        out.write_all(MAGIC)?;
+        let header = ArchiveHeader {
+            version: VERSION,
+            camera,
+            started_us: unix_micros(),
+        };
+        write_record(
+            &mut out,
+            TAG_HEADER,
+            &[&serde_json::to_vec(&header).map_err(io::Error::other)?],
+        )?;
+
+        let (sender, receiver) = mpsc::sync_channel(QUEUE_DEPTH);
+        let writer = thread::Builder::new()
+            .name("session-capture".into())
+            .spawn(move || write_archive(receiver, out))?;
+        Ok(SessionCapture {
+            sender: Mutex::new(Some(sender)),
+            last_settings: Mutex::new(None),
+            writer: Mutex::new(Some(writer)),
+        })
+    }
+
+    /// The processor was reset, replays reset theirs at this point.
+    pub fn reset(&self) {
+        self.last_settings.lock().unwrap().take();
+        self.send(Message::Reset);
+    }
+
+    /// Archive a raw frame with what it was processed with.
+    /// Waits when the writer is behind.
+    pub fn push_frame(
+        &self,
+        settings: &EffectSettings,
+        state: FrameState,
+        pixels: Arc<PooledFrame>,
+        width: u32,
+        height: u32,
+    ) {
+        let mut last_settings = self.last_settings.lock().unwrap();
+        if !last_settings
+            .as_ref()
+            .is_some_and(|last| same_settings(last, settings))
+        {
+            *last_settings = Some(settings.clone());
+            self.send(Message::Settings(settings.clone(), state.timestamp_us));
+        }
+        self.send(Message::Frame {
+            state,
+            pixels,
+            width,
+            height,
+        });
+    }
+
+    fn send(&self, message: Message) {
+        // A disconnected queue means the writer failed, `stop` reports why.
+        if let Some(sender) = self.sender.lock().unwrap().as_ref() {
+            let _ = sender.send(message);
+        }
+    }
+
+    /// Write the queued frames and close the file.
+    pub fn stop(&self) -> io::Result<CaptureSummary> {
+        self.sender.lock().unwrap().take();
+        let writer = self.writer.lock().unwrap().take().ok_or_else(|| {
+            io::Error::new(io::ErrorKind::NotFound, "Session capture already stopped")
+        })?;
+        writer
+            .join()
+            .unwrap_or_else(|_| Err(io::Error::other("Session capture thread panicked")))
+    }
+}
+
+fn same_settings(a: &EffectSettings, b: &EffectSettings) -> bool {
+    let same_background = match (&a.background, &b.background) {
+        (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+        (None, None) => true,
+        _ => false,
+    };
+    same_background
+        && a.blur == b.blur
+        && a.debug == b.debug
+        && a.roi == b.roi
+        && a.refine == b.refine
+        && a.temporal == b.temporal
+}
+
+fn write_archive(
+    receiver: Receiver<Message>,
+    mut out: BufWriter<File>,
+) -> io::Result<CaptureSummary> {
+    let mut summary = CaptureSummary::default();
+    let mut encoder = FrameEncoder::default();
+    let mut png = Vec::new();
+    // Backgrounds written so far, their index is their id.
+    let mut backgrounds: Vec<Arc<RgbaImage>> = Vec::new();
+
+    for message in receiver {
+        match message {
+            Message::Reset => write_record(&mut out, TAG_RESET, &[])?,
+            Message::Settings(settings, timestamp_us) => {
+                let background = match &settings.background {
+                    Some(image) => match backgrounds.iter().position(|b| Arc::ptr_eq(b, image)) {
+                        Some(id) => Some(id as u32),
+                        None => {
+                            let id = backgrounds.len() as u32;
+                            let (width, height) = image.dimensions();
+                            encoder
+                                .encode(image, width, height, OutputFormat::Png, &mut png)
+                                .map_err(io::Error::other)?;
+                            write_record(&mut out, TAG_BACKGROUND, &[&id.to_le_bytes(), &png])?;
+                            backgrounds.push(image.clone());
+                            Some(id)
+                        }
+                    },
+                    None => None,
+                };
+                let stored = StoredSettings {
+                    timestamp_us,
+                    blur: settings.blur,
+                    background,
+                    debug: settings.debug,
+                    roi: settings.roi,
+                    refine: settings.refine,
+                    temporal: settings.temporal,
+                };
+                let json = serde_json::to_vec(&stored).map_err(io::Error::other)?;
+                write_record(&mut out, TAG_SETTINGS, &[&json])?;
+                summary.settings_changes += 1;
+            }
+            Message::Frame {
+                state,
+                pixels,
+                width,
+                height,
+            } => {
+                encoder
+                    .encode(&pixels, width, height, OutputFormat::Png, &mut png)
+                    .map_err(io::Error::other)?;
+                let mut header = [0; FRAME_STATE_LEN];
+                header[0..8].copy_from_slice(&state.timestamp_us.to_le_bytes());
+                header[8..16].copy_from_slice(&state.sequence.to_le_bytes());
+                header[16..20].copy_from_slice(&width.to_le_bytes());
+                header[20..24].copy_from_slice(&height.to_le_bytes());
+                header[24] = state.quality_level;
+                header[25] = state.low_power as u8;
+                header[26] = state.extra_mask as u8;
+                write_record(&mut out, TAG_FRAME, &[&header, &png])?;
+                summary.frames += 1;
+            }
+        }
+    }
+
+    out.flush()?;
+    summary.bytes = out.get_ref().metadata()?.len();
+    Ok(summary)
+}
+
+fn write_record(out: &mut impl Write, tag: u8, parts: &[&[u8]]) -> io::Result<()> {
+    let len: usize = parts.iter().map(|part| part.len()).sum();
+    if len > MAX_RECORD_LEN {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "Session archive record too large",
+        ));
+    }
+    out.write_all(&[tag])?;
+    out.write_all(&(len as u32).to_le_bytes())?;
+    for part in parts {
+        out.write_all(part)?;
+    }
+    Ok(())
+}
+
+pub enum ArchiveEvent {
+    Reset,
+    Settings(EffectSettings),
+    Frame {
+        state: FrameState,
+        pixels: Vec<u8>,
+        width: u32,
+        height: u32,
+    },
+}
+
+pub struct ArchiveReader<R: Read> {
+    input: R,
+    header: ArchiveHeader,
+    backgrounds: HashMap<u32, Arc<RgbaImage>>,
+    payload: Vec<u8>,
+}
+
+impl<R: Read> ArchiveReader<R> {
+    pub fn new(mut input: R) -> io::Result<ArchiveReader<R>> {
+        let mut magic = [0; MAGIC.len()];
+        input.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(invalid("Not a session archive"));
+        }
+        let mut payload = Vec::new();
+        if read_record(&mut input, &mut payload)? != Some(TAG_HEADER) {
+            return Err(invalid("Session archive without a header"));
+        }
+        let header: ArchiveHeader = serde_json::from_slice(&payload).map_err(invalid)?;
+        if header.version != VERSION {
+            return Err(invalid(format!(
+                "Session archive version {} is not supported",
+                header.version
+            )));
+        }
+        Ok(ArchiveReader {
+            input,
+            header,
+            backgrounds: HashMap::new(),
+            payload,
+        })
+    }
+
+    pub fn header(&self) -> &ArchiveHeader {
+        &self.header
+    }
+
+    /// The next event, `None` at the end of the archive.
+    pub fn next_event(&mut self) -> io::Result<Option<ArchiveEvent>> {
+        loop {
+            let Some(tag) = read_record(&mut self.input, &mut self.payload)? else {
+                return Ok(None);
+            };
+            let payload = self.payload.as_slice();
+            let event = match tag {
+                TAG_RESET => ArchiveEvent::Reset,
+                TAG_BACKGROUND if payload.len() >= 4 => {
+                    let id = u32::from_le_bytes(payload[..4].try_into().unwrap());
+                    self.backgrounds
+                        .insert(id, Arc::new(decode_png(&payload[4..])?));
+                    continue;
+                }
+                TAG_SETTINGS => {
+                    let stored: StoredSettings =
+                        serde_json::from_slice(payload).map_err(invalid)?;
+                    let background = match stored.background {
+                        Some(id) => Some(
+                            self.backgrounds
+                                .get(&id)
+                                .cloned()
+                                .ok_or_else(|| invalid("Missing background in session archive"))?,
+                        ),
+                        None => None,
+                    };
+                    ArchiveEvent::Settings(EffectSettings {
+                        blur: stored.blur,
+                        background,
+                        debug: stored.debug,
+                        roi: stored.roi,
+                        refine: stored.refine,
+                        temporal: stored.temporal,
+                    })
+                }
+                TAG_FRAME if payload.len() >= FRAME_STATE_LEN => {
+                    let u64_at =
+                        |i: usize| u64::from_le_bytes(payload[i..i + 8].try_into().unwrap());
+                    let u32_at =
+                        |i: usize| u32::from_le_bytes(payload[i..i + 4].try_into().unwrap());
+                    let state = FrameState {
+                        timestamp_us: u64_at(0),
+                        sequence: u64_at(8),
+                        quality_level: payload[24],
+                        low_power: payload[25] != 0,
+                        extra_mask: payload[26] != 0,
+                    };
+                    let image = decode_png(&payload[FRAME_STATE_LEN..])?;
+                    if image.dimensions() != (u32_at(16), u32_at(20)) {
+                        return Err(invalid("Frame size mismatch in session archive"));
+                    }
+                    let (width, height) = image.dimensions();
+                    ArchiveEvent::Frame {
+                        state,
+                        pixels: image.into_raw(),
+                        width,
+                        height,
+                    }
+                }
+                // Unknown records come from newer versions, they don't change the frames.
+                _ => continue,
+            };
+            return Ok(Some(event));
+        }
+    }
+}
+
+// Read a record into `payload`, returns its tag or `None` at the end of the input.
+fn read_record(input: &mut impl Read, payload: &mut Vec<u8>) -> io::Result<Option<u8>> {
+    let mut head = [0; 5];
+    match input.read_exact(&mut head[..1]) {
+        Ok(()) => {}
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e),
+    }
+    input.read_exact(&mut head[1..])?;
+    let len = u32::from_le_bytes(head[1..].try_into().unwrap()) as usize;
+    if len > MAX_RECORD_LEN {
+        return Err(invalid("Session archive record too large"));
+    }
+    // Grows with the data read, a truncated archive doesn't allocate the whole length.
+    payload.clear();
+    input.take(len as u64).read_to_end(payload)?;
+    if payload.len() != len {
+        return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+    Ok(Some(head[0]))
+}
+
+fn decode_png(data: &[u8]) -> io::Result<RgbaImage> {
+    Ok(image::load_from_memory_with_format(data, ImageFormat::Png)
+        .map_err(invalid)?
+        .to_rgba8())
+}
+
+fn invalid(e: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, e)
+}
+
+static CAPTURES: OnceLock<Mutex<HashMap<u32, Arc<SessionCapture>>>> = OnceLock::new();
+
+fn captures() -> &'static Mutex<HashMap<u32, Arc<SessionCapture>>> {
+    CAPTURES.get_or_init(Default::default)
+}
+
+/// Session capture of the streams on a camera, it keeps going if the stream is restarted.
+pub fn get(id: u32) -> Option<Arc<SessionCapture>> {
+    captures().lock().unwrap().get(&id).cloned()
+}
+
+/// Start a session capture with `start` unless the camera already has one, in which case
+/// `start` isn't called and its file is left alone.
+pub fn register_with(
+    id: u32,
+    start: impl FnOnce() -> io::Result<SessionCapture>,
+) -> io::Result<Arc<SessionCapture>> {
+    let mut captures = captures().lock().unwrap();
+    if captures.contains_key(&id) {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            "Camera already has a session capture",
+        ));
+    }
+    let capture = Arc::new(start()?);
+    captures.insert(id, capture.clone());
+    Ok(capture)
+}
+
+pub fn remove(id: u32) -> Option<Arc<SessionCapture>> {
+    captures().lock().unwrap().remove(&id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pipeline::pool::FramePool;
+    use std::io::{BufReader, Cursor};
+
+    fn state(sequence: u64) -> FrameState {
+        FrameState {
+            timestamp_us: 1_000 + sequence * 33_333,
+            sequence,
+            quality_level: 2,
+            low_power: sequence % 2 == 1,
+            extra_mask: sequence == 0,
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let path = std::env::temp_dir().join(format!("archive-{}.camsession", std::process::id()));
+        let background = Arc::new(RgbaImage::from_pixel(3, 2, image::Rgba([1, 2, 3, 255])));
+        let blur = EffectSettings {
+            blur: true,
+            refine: true,
+            ..Default::default()
+        };
+        let replaced = EffectSettings {
+            background: Some(background.clone()),
+            temporal: true,
+            ..Default::default()
+        };
+        let pool = FramePool::new();
+        let pixels = |value: u8| Arc::new(pool.copy(&[value; 4 * 2 * 4]));
+
+        let capture = SessionCapture::start(File::create(&path).unwrap(), 7).unwrap();
+        capture.push_frame(&blur, state(0), pixels(10), 4, 2);
+        capture.push_frame(&blur, state(1), pixels(11), 4, 2);
+        capture.reset();
+        capture.push_frame(&replaced, state(2), pixels(12), 4, 2);
+        capture.push_frame(&blur, state(3), pixels(13), 4, 2);
+        capture.push_frame(&replaced, state(4), pixels(14), 4, 2);
+        let summary = capture.stop().unwrap();
+        assert_eq!((summary.frames, summary.settings_changes), (5, 4));
+        assert_eq!(summary.bytes, std::fs::metadata(&path).unwrap().len());
+        assert!(capture.stop().is_err());
+
+        let file = BufReader::new(File::open(&path).unwrap());
+        let mut reader = ArchiveReader::new(file).unwrap();
+        assert_eq!(
+            (reader.header().version, reader.header().camera),
+            (VERSION, 7)
+        );
+        let mut frames = Vec::new();
+        let mut settings = Vec::new();
+        let mut resets = 0;
+        while let Some(event) = reader.next_event().unwrap() {
+            match event {
+                ArchiveEvent::Reset => resets += 1,
+                ArchiveEvent::Settings(stored) => settings.push(stored),
+                ArchiveEvent::Frame {
+                    state,
+                    pixels,
+                    width,
+                    height,
+                } => {
+                    assert_eq!((width, height), (4, 2));
+                    assert_eq!(pixels, vec![10 + state.sequence as u8; pixels.len()]);
+                    frames.push(state);
+                }
+            }
+        }
+        std::fs::remove_file(path).unwrap();
+
+        assert_eq!(frames, (0..5).map(state).collect::<Vec<_>>());
+        assert_eq!(resets, 1);
+        assert_eq!(settings.len(), 4);
+        assert!(settings[0].blur && settings[0].refine && settings[0].background.is_none());
+        assert!(settings[1].temporal && !settings[1].blur);
+        // Written once, both settings share the decoded image.
+        let first = settings[1].background.as_ref().unwrap();
+        assert_eq!(**first, *background);
+        assert!(Arc::ptr_eq(first, settings[3].background.as_ref().unwrap()));
+    }
+
+    #[test]
+    fn truncated_records_are_errors() {
+        let mut data = MAGIC.to_vec();
+        write_record(
+            &mut data,
+            TAG_HEADER,
+            &[br#"{"version":1,"camera":0,"started_us":0}"#],
+        )
+        .unwrap();
+        write_record(&mut data, TAG_RESET, &[]).unwrap();
+        let mut reader = ArchiveReader::new(Cursor::new(data.clone())).unwrap();
+        assert!(matches!(reader.next_event(), Ok(Some(ArchiveEvent::Reset))));
+        assert!(reader.next_event().unwrap().is_none());
+
+        // A frame claiming 1 KiB with 3 bytes of it.
+        let mut truncated = data.clone();
+        truncated.push(TAG_FRAME);
+        truncated.extend_from_slice(&1024u32.to_le_bytes());
+        truncated.extend_from_slice(&[0; 3]);
+        let mut reader = ArchiveReader::new(Cursor::new(truncated)).unwrap();
+        reader.next_event().unwrap();
+        let error = reader.next_event().err().unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+
+        let mut corrupt = data;
+        corrupt.push(TAG_FRAME);
+        corrupt.extend_from_slice(&u32::MAX.to_le_bytes());
+        let mut reader = ArchiveReader::new(Cursor::new(corrupt)).unwrap();
+        reader.next_event().unwrap();
+        let error = reader.next_event().err().unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn other_files_are_rejected() {
+        let error = ArchiveReader::new(Cursor::new(b"YUV4MPEG2 W4 H2\n".to_vec()))
+            .err()
+            .unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,238 @@
+// Burst capture: consecutive frames around a trigger, ranked to pick the best one.
+// Catches the frame where the subject is still and not blinking instead of the one
+// at the exact moment of the request.
+
+use std::sync::mpsc::Receiver;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::ml::roi::MIN_PERSON_COVERAGE;
+
+use super::tap::TappedFrame;
+
+// Weights of the sharpness, exposure and mask scores in the total.
+const SHARPNESS_WEIGHT: f64 = 0.5;
+const EXPOSURE_WEIGHT: f64 = 0.25;
+const MASK_WEIGHT: f64 = 0.25;
+// Luma values at or past these are counted as clipped.
+const DARK_CLIP: u8 = 8;
+const BRIGHT_CLIP: u8 = 247;
+
+/// Scores of a frame, all from 0 to 1, higher is better.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FrameScores {
+    /// Laplacian variance relative to the sharpest frame of the burst.
+    pub sharpness: f64,
+    /// How close the mean brightness is to mid gray, less the clipped pixels.
+    pub exposure: f64,
+    /// Confidence of the mask, 0 when nobody is found.
+    pub mask: f64,
+    pub total: f64,
+}
+
+pub struct BurstFrame {
+    pub frame: Arc<TappedFrame>,
+    pub scores: FrameScores,
+}
+
+/// Wait for frames after the ones from the history until there are `count`,
+/// or until `timeout`. Frames of another size than the last one are skipped.
+pub fn collect(
+    history: Vec<Arc<TappedFrame>>,
+    next: Receiver<Arc<TappedFrame>>,
+    count: usize,
+    timeout: Duration,
+) -> Vec<Arc<TappedFrame>> {
+    let mut frames = history;
+    let deadline = Instant::now() + timeout;
+    while frames.len() < count {
+        let Some(remaining) = deadline.checked_duration_since(Instant::now()) else {
+            break;
+        };
+        match next.recv_timeout(remaining) {
+            Ok(frame) => frames.push(frame),
+            Err(_) => break,
+        }
+    }
+    if let Some(last) = frames.last() {
+        let size = (last.width, last.height);
+        frames.retain(|frame| (frame.width, frame.height) == size);
+    }
+    frames
+}
+
+/// Score the frames and sort them best first.
+pub fn rank(frames: Vec<Arc<TappedFrame>>) -> Vec<BurstFrame> {
+    let variances: Vec<f64> = frames
+        .iter()
+        .map(|frame| laplacian_variance(&frame.pixels, frame.width, frame.height))
+        .collect();
+    let sharpest = variances.iter().cloned().fold(0.0, f64::max);
+
+    let mut ranked: Vec<BurstFrame> = frames
+        .into_iter()
+        .zip(variances)
+        .map(|(frame, variance)| {
+            let sharpness = if sharpest > 0.0 {
+                variance / sharpest
+            } else {
+                0.0
+            };
+            let exposure = exposure(&frame.pixels);
+            let mask = frame
+                .mask
+                .filter(|mask| mask.coverage >= MIN_PERSON_COVERAGE)
+                .map_or(0.0, |mask| mask.confidence as f64);
+            let total =
+                SHARPNESS_WEIGHT * sharpness + EXPOSURE_WEIGHT * exposure + MASK_WEIGHT * mask;
+            BurstFrame {
+                frame,
+                scores: FrameScores {
+                    sharpness,
+                    exposure,
+                    mask,
+                    total,
+                },
+            }
+        })
+        .collect();
+    ranked.sort_by(|a, b| b.scores.total.total_cmp(&a.scores.total));
+    ranked
+}
+
+fn luma(p: &[u8]) -> u8 {
+    ((77 * p[0] as u32 + 150 * p[1] as u32 + 29 * p[2] as u32) >> 8) as u8
+}
+
+// Variance of the 4 neighbour Laplacian of the luma, high when there are sharp edges.
+fn laplacian_variance(rgba_data: &[u8], width: u32, height: u32) -> f64 {
+    let (width, height) = (width as usize, height as usize);
+    if width < 3 || height < 3 {
+        return 0.0;
+    }
+    let luma: Vec<i32> = rgba_data.chunks_exact(4).map(|p| luma(p) as i32).collect();
+    let (mut sum, mut sum_sq) = (0.0, 0.0);
+    for y in 1..height - 1 {
+        for x in 1..width - 1 {
+            let i = y * width + x;
+            let lap = (luma[i - 1] + luma[i + 1] + luma[i - width] + luma[i + width] - 4 * luma[i])
+                as f64;
+            sum += lap;
+            sum_sq += lap * lap;
+        }
+    }
+    let n = ((width - 2) * (height - 2)) as f64;
+    let mean = sum / n;
+    sum_sq / n - mean * mean
+}
+
+fn exposure(rgba_data: &[u8]) -> f64 {
+    let (mut total, mut clipped, mut n) = (0u64, 0u64, 0u64);
+    for p in rgba_data.chunks_exact(4) {
+        let y = luma(p);
+        total += y as u64;
+        clipped += (y <= DARK_CLIP || y >= BRIGHT_CLIP) as u64;
+        n += 1;
+    }
+    if n == 0 {
+        return 0.0;
+    }
+    let mean = total as f64 / n as f64 / 255.0;
+    let balance = 1.0 - (mean - 0.5).abs() * 2.0;
+    balance * (1.0 - clipped as f64 / n as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+
+    use super::*;
+    use crate::ml::roi::MaskStats;
+
+    const SIZE: u32 = 16;
+
+    fn frame(pixels: Vec<u8>, timestamp_us: u64) -> TappedFrame {
+        TappedFrame {
+            pixels,
+            width: SIZE,
+            height: SIZE,
+            timestamp_us,
+            captured_at: Instant::now(),
+            mask: None,
+        }
+    }
+
+    fn tapped(pixels: Vec<u8>, timestamp_us: u64) -> Arc<TappedFrame> {
+        Arc::new(frame(pixels, timestamp_us))
+    }
+
+    fn gray(value: impl Fn(u32, u32) -> u8) -> Vec<u8> {
+        (0..SIZE * SIZE)
+            .flat_map(|i| {
+                let v = value(i % SIZE, i / SIZE);
+                [v, v, v, 255]
+            })
+            .collect()
+    }
+
+    // Stripes between 64 and 192, softened when blurred.
+    fn stripes(blurred: bool) -> Vec<u8> {
+        gray(|x, _| match (blurred, x % 4 < 2) {
+            (false, true) => 64,
+            (false, false) => 192,
+            (true, _) => [96, 128, 160, 128][x as usize % 4],
+        })
+    }
+
+    #[test]
+    fn sharp_frame_ranks_above_blurred() {
+        assert!(laplacian_variance(&stripes(false), SIZE, SIZE) > 0.0);
+        assert_eq!(laplacian_variance(&gray(|_, _| 128), SIZE, SIZE), 0.0);
+
+        let ranked = rank(vec![tapped(stripes(true), 1), tapped(stripes(false), 2)]);
+        assert_eq!(ranked[0].frame.timestamp_us, 2);
+        assert_eq!(ranked[0].scores.sharpness, 1.0);
+        assert!(ranked[1].scores.sharpness < 1.0);
+    }
+
+    #[test]
+    fn clipped_frame_scores_lower_exposure() {
+        let balanced = exposure(&gray(|_, _| 128));
+        // The same mean brightness, half of it clipped.
+        let clipped = exposure(&gray(|x, _| if x % 2 == 0 { 0 } else { 255 }));
+        assert!(balanced > 0.95);
+        assert!(clipped < balanced / 2.0);
+        assert!(exposure(&gray(|_, _| 250)) < 0.1);
+    }
+
+    #[test]
+    fn mask_counts_only_with_someone() {
+        let masked = |coverage| {
+            Arc::new(TappedFrame {
+                mask: Some(MaskStats {
+                    coverage,
+                    confidence: 0.9,
+                }),
+                ..frame(gray(|_, _| 128), 0)
+            })
+        };
+        let ranked = rank(vec![masked(0.0), masked(0.5)]);
+        assert_eq!(ranked[0].scores.mask, 0.9f32 as f64);
+        assert_eq!(ranked[1].scores.mask, 0.0);
+    }
+
+    #[test]
+    fn collect_keeps_the_size_of_the_last_frame() {
+        let (sender, receiver) = mpsc::channel();
+        let small = Arc::new(TappedFrame {
+            width: 4,
+            height: 4,
+            ..frame(vec![0; 64], 1)
+        });
+        sender.send(tapped(gray(|_, _| 0), 2)).unwrap();
+        sender.send(tapped(gray(|_, _| 0), 3)).unwrap();
+        let frames = collect(vec![small], receiver, 3, Duration::from_secs(5));
+        let timestamps: Vec<u64> = frames.iter().map(|frame| frame.timestamp_us).collect();
+        assert_eq!(timestamps, [2, 3]);
+    }
+}
@@ -0,0 +1,122 @@
+// Short clips captured from a stream, for animated exports.
+
+use std::sync::mpsc::Receiver;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use image::imageops::FilterType;
+use image::RgbaImage;
+
+use crate::media::animation::AnimationFrame;
+use crate::media::video::ConstantRate;
+
+use super::tap::TappedFrame;
+
+#[derive(Debug, Clone, Copy)]
+pub struct ClipSettings {
+    pub duration: Duration,
+    /// Frames are picked from the stream to get this rate.
+    pub fps: u32,
+    /// Resize to this width keeping the aspect ratio, `None` to keep the stream size.
+    pub width: Option<u32>,
+}
+
+/// Collect frames from a tap for the clip duration, calling `progress` with the
+/// fraction captured. Stops early if the stream ends. The tap should use
+/// `Pace::Fps` with the same rate, so the frames left out are not copied.
+pub fn capture(
+    frames: Receiver<Arc<TappedFrame>>,
+    settings: ClipSettings,
+    mut progress: impl FnMut(f64),
+) -> Vec<AnimationFrame> {
+    let mut rate = ConstantRate::new(settings.fps);
+    let frame_ms = 1000 / settings.fps.max(1);
+    let mut clip: Vec<AnimationFrame> = Vec::new();
+    let mut size = None;
+    let start = Instant::now();
+
+    while let Some(remaining) = settings.duration.checked_sub(start.elapsed()) {
+        // Both a timeout and the stream ending finish the clip.
+        let Ok(frame) = frames.recv_timeout(remaining) else {
+            break;
+        };
+        progress(start.elapsed().as_secs_f64() / settings.duration.as_secs_f64());
+
+        // Skip frames of another size than the first, like snapshot stills.
+        if *size.get_or_insert((frame.width, frame.height)) != (frame.width, frame.height) {
+            continue;
+        }
+        let time_us = frame
+            .captured_at
+            .saturating_duration_since(start)
+            .as_micros() as u64;
+        let repeats = rate.repeats(time_us) as u32;
+        if repeats == 0 {
+            continue;
+        }
+        let Some(image) = RgbaImage::from_raw(frame.width, frame.height, frame.pixels.clone())
+        else {
+            continue;
+        };
+        let image = match settings.width {
+            Some(width) if width != frame.width => {
+                let height = (frame.height * width / frame.width).max(1);
+                image::imageops::resize(&image, width, height, FilterType::Triangle)
+            }
+            _ => image,
+        };
+        clip.push(AnimationFrame {
+            image,
+            delay_ms: repeats * frame_ms,
+        });
+    }
+    progress(1.0);
+    clip
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+
+    use super::*;
+
+    fn frame(width: u32, height: u32, captured_at: Instant) -> Arc<TappedFrame> {
+        Arc::new(TappedFrame {
+            pixels: vec![0; (width * height * 4) as usize],
+            width,
+            height,
+            timestamp_us: 0,
+            captured_at,
+            mask: None,
+        })
+    }
+
+    #[test]
+    fn skipped_frames_lengthen_the_previous_delay() {
+        let (sender, receiver) = mpsc::sync_channel(8);
+        let start = Instant::now();
+        for ms in [0, 100, 300] {
+            sender
+                .send(frame(8, 4, start + Duration::from_millis(ms)))
+                .unwrap();
+        }
+        // Another size, left out.
+        sender
+            .send(frame(4, 4, start + Duration::from_millis(400)))
+            .unwrap();
+        drop(sender);
+
+        let settings = ClipSettings {
+            duration: Duration::from_secs(5),
+            fps: 10,
+            width: Some(4),
+        };
+        let mut last_progress = 0.0;
+        let clip = capture(receiver, settings, |fraction| last_progress = fraction);
+        let delays: Vec<u32> = clip.iter().map(|frame| frame.delay_ms).collect();
+        // The frame at 300 ms also stands for the one expected at 200 ms.
+        assert_eq!(delays, [100, 100, 200]);
+        assert!(clip.iter().all(|frame| frame.image.dimensions() == (4, 2)));
+        assert_eq!(last_progress, 1.0);
+    }
+}
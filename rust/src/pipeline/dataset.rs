@@ -0,0 +1,641 @@
+// Dataset capture: camera frames picked from the stream, saved with their predicted mask to
+// collect training data. Frames are sampled on the stream thread and written by another one,
+// frames are left out rather than slowing down the stream when the disk can't keep up.
+//
+// Layout of the directory:
+//
+//     images/000000.png         the camera frame, before the effects
+//     masks/000000.png          the predicted mask, 8 bit grayscale with 255 for the person
+//     annotations/000000.json   mask outline polygons, RLE and frame metadata
+//     annotations.json          COCO instance annotations of every frame, with RLE masks,
+//                               written when the capture ends
+//
+// Masks, RLE and polygons line up with the image, the shift the effects apply to camera
+// masks (see `camera_mask_offset`) is already done. Masks are grayscale rather than the
+// alpha channel of the image, so the image stays the frame as it was streamed.
+
+use std::collections::HashMap;
+use std::io::{self, Cursor};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::mpsc::{self, Receiver, SyncSender, TrySendError};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::thread::{self, JoinHandle};
+
+use image::{GrayImage, ImageFormat};
+use serde::Serialize;
+
+use crate::media::still::{encode_rgba, StillFormat};
+use crate::ml::annotation::annotate;
+use crate::ml::composite::align_mask;
+use crate::ml::roi::mask_stats;
+
+use super::frame::unix_micros;
+use super::pool::PooledFrame;
+use super::processor::EffectSettings;
+
+const COCO_ANNOTATIONS: &str = "annotations.json";
+// Frames waiting to be written, more are left out.
+const QUEUE_DEPTH: usize = 4;
+const PERSON_CATEGORY: u32 = 1;
+
+#[derive(Debug, Clone)]
+pub struct DatasetSettings {
+    pub directory: PathBuf,
+    /// Save one frame out of this many, 0 to only save frames for the other reasons.
+    pub every_frames: u32,
+    /// Save frames whose mask confidence is below this, see `MaskStats::confidence`.
+    /// Needs the mask of every frame, even without effects.
+    pub max_confidence: Option<f32>,
+    /// Frames skipped after a low confidence frame, the next ones are usually alike.
+    pub low_confidence_spacing: u32,
+    pub max_frames: Option<u32>,
+}
+
+/// Why a frame was saved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SampleReason {
+    Interval,
+    LowConfidence,
+    /// Asked for with `DatasetCapture::flag`.
+    Flagged,
+}
+
+/// The effect settings a frame was streamed with.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct EffectMetadata {
+    pub blur: bool,
+    pub background: bool,
+    pub debug: bool,
+    pub roi: bool,
+    pub refine: bool,
+    pub temporal: bool,
+}
+
+impl From<&EffectSettings> for EffectMetadata {
+    fn from(settings: &EffectSettings) -> Self {
+        EffectMetadata {
+            blur: settings.blur,
+            background: settings.background.is_some(),
+            debug: settings.debug,
+            roi: settings.roi,
+            refine: settings.refine,
+            temporal: settings.temporal,
+        }
+    }
+}
+
+/// A sampled camera frame and the mask the stream predicted for it.
+pub struct DatasetFrame {
+    pub pixels: Arc<PooledFrame>,
+    pub mask: Vec<f32>,
+    /// Shift of the mask over the frame, see `camera_mask_offset`. Saved masks are moved
+    /// by it to line up with the image.
+    pub mask_offset: (usize, usize),
+    pub width: u32,
+    pub height: u32,
+    pub sequence: u64,
+    /// Microseconds since the Unix epoch.
+    pub timestamp_us: u64,
+    pub reason: SampleReason,
+    pub effects: EffectMetadata,
+    pub quality_level: u32,
+    pub low_power: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DatasetSummary {
+    pub frames_saved: u32,
+    pub interval_frames: u32,
+    pub low_confidence_frames: u32,
+    pub flagged_frames: u32,
+    /// Sampled but left out because the writer was behind.
+    pub frames_dropped: u32,
+}
+
+#[derive(Default)]
+struct Sampler {
+    frames_until_interval: u32,
+    frames_until_low_confidence: u32,
+    sampled: u32,
+}
+
+pub struct DatasetCapture {
+    settings: DatasetSettings,
+    flagged: AtomicBool,
+    sampler: Mutex<Sampler>,
+    sender: Mutex<Option<SyncSender<DatasetFrame>>>,
+    dropped: AtomicU32,
+    thread: Mutex<Option<JoinHandle<io::Result<DatasetSummary>>>>,
+}
+
+impl DatasetCapture {
+    /// Create the directories and write the frames pushed until stopped. The directory
+    /// must be empty or missing, frames are numbered from 0 and would replace earlier ones.
+    pub fn start(camera: u32, settings: DatasetSettings) -> io::Result<DatasetCapture> {
+        let used = match std::fs::read_dir(&settings.directory) {
+            Ok(mut entries) => entries.next().is_some(),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => false,
+            Err(e) => return Err(e),
+        };
+        if used {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                "Dataset directory isn't empty",
+            ));
+        }
+        for directory in ["images", "masks", "annotations"] {
+            std::fs::create_dir_all(settings.directory.join(directory))?;
+        }
+        let (sender, receiver) = mpsc::sync_channel(QUEUE_DEPTH);
+        let directory = settings.directory.clone();
+        let thread = thread::Builder::new()
+            .name("dataset".into())
+            .spawn(move || write_frames(camera, receiver, &directory))?;
+        Ok(DatasetCapture {
+            settings,
+            flagged: AtomicBool::new(false),
+            sampler: Mutex::new(Sampler::default()),
+            sender: Mutex::new(Some(sender)),
+            dropped: AtomicU32::new(0),
+            thread: Mutex::new(Some(thread)),
+        })
+    }
+
+    /// Save the next frame whatever the sampling settings.
+    pub fn flag(&self) {
+        self.flagged.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether the current frame should be saved, and why.
+    /// `confidence` is only called for the low confidence sampling.
+    pub fn sample(&self, confidence: impl FnOnce() -> f32) -> Option<SampleReason> {
+        let mut sampler = self.sampler.lock().unwrap();
+        if self
+            .settings
+            .max_frames
+            .is_some_and(|max| sampler.sampled >= max)
+        {
+            return None;
+        }
+        let interval_due = self.settings.every_frames > 0 && sampler.frames_until_interval == 0;
+        sampler.frames_until_interval = if interval_due {
+            self.settings.every_frames - 1
+        } else {
+            sampler.frames_until_interval.saturating_sub(1)
+        };
+
+        let reason = if self.flagged.swap(false, Ordering::Relaxed) {
+            SampleReason::Flagged
+        } else if interval_due {
+            SampleReason::Interval
+        } else {
+            let max = self.settings.max_confidence?;
+            if sampler.frames_until_low_confidence > 0 {
+                sampler.frames_until_low_confidence -= 1;
+                return None;
+            }
+            if confidence() >= max {
+                return None;
+            }
+            sampler.frames_until_low_confidence = self.settings.low_confidence_spacing;
+            SampleReason::LowConfidence
+        };
+        sampler.sampled += 1;
+        Some(reason)
+    }
+
+    /// Queue a sampled frame, it is left out if the writer is behind.
+    pub fn push(&self, frame: DatasetFrame) {
+        let mut sender = self.sender.lock().unwrap();
+        let Some(queue) = sender.as_ref() else {
+            return;
+        };
+        match queue.try_send(frame) {
+            Ok(()) => {}
+            Err(TrySendError::Full(frame)) => {
+                self.dropped.fetch_add(1, Ordering::Relaxed);
+                log::warn!("Dataset writer behind, frame {} left out", frame.sequence);
+            }
+            // The writer failed, `stop` reports why.
+            Err(TrySendError::Disconnected(_)) => {}
+        }
+        // The writer ends once the last frame is written.
+        let sampled = self.sampler.lock().unwrap().sampled;
+        if self.settings.max_frames.is_some_and(|max| sampled >= max) {
+            sender.take();
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.thread
+            .lock()
+            .unwrap()
+            .as_ref()
+            .is_none_or(|thread| thread.is_finished())
+    }
+
+    /// Write the queued frames and return what was saved.
+    pub fn stop(&self) -> io::Result<DatasetSummary> {
+        self.sender.lock().unwrap().take();
+        let Some(thread) = self.thread.lock().unwrap().take() else {
+            return Err(io::Error::other("Dataset capture already stopped"));
+        };
+        let mut summary = thread
+            .join()
+            .map_err(|_| io::Error::other("Dataset capture thread panicked"))??;
+        summary.frames_dropped = self.dropped.load(Ordering::Relaxed);
+        Ok(summary)
+    }
+}
+
+#[derive(Serialize)]
+struct Rle {
+    /// Height then width, like COCO.
+    size: [u32; 2],
+    counts: String,
+}
+
+// Annotations of one frame, next to its image.
+#[derive(Serialize)]
+struct FrameRecord<'a> {
+    index: u32,
+    image: &'a str,
+    mask: &'a str,
+    sequence: u64,
+    timestamp_us: u64,
+    width: u32,
+    height: u32,
+    reason: SampleReason,
+    effects: EffectMetadata,
+    quality_level: u32,
+    low_power: bool,
+    coverage: f32,
+    confidence: f32,
+    area: u64,
+    bbox: Option<[u32; 4]>,
+    rle: &'a Rle,
+    polygons: &'a [Vec<f32>],
+}
+
+#[derive(Serialize)]
+struct Coco {
+    info: CocoInfo,
+    images: Vec<CocoImage>,
+    annotations: Vec<CocoAnnotation>,
+    categories: [CocoCategory; 1],
+}
+
+#[derive(Serialize)]
+struct CocoInfo {
+    description: String,
+    date_created_us: u64,
+}
+
+#[derive(Serialize)]
+struct CocoImage {
+    id: u32,
+    file_name: String,
+    width: u32,
+    height: u32,
+}
+
+#[derive(Serialize)]
+struct CocoAnnotation {
+    id: u32,
+    image_id: u32,
+    category_id: u32,
+    segmentation: Rle,
+    area: u64,
+    bbox: [u32; 4],
+    iscrowd: u8,
+}
+
+#[derive(Serialize)]
+struct CocoCategory {
+    id: u32,
+    name: &'static str,
+    supercategory: &'static str,
+}
+
+fn write_frames(
+    camera: u32,
+    frames: Receiver<DatasetFrame>,
+    directory: &Path,
+) -> io::Result<DatasetSummary> {
+    let mut summary = DatasetSummary::default();
+    let mut coco = Coco {
+        info: CocoInfo {
+            description: format!("Camera {camera} frames with predicted person masks"),
+            date_created_us: unix_micros(),
+        },
+        images: Vec::new(),
+        annotations: Vec::new(),
+        categories: [CocoCategory {
+            id: PERSON_CATEGORY,
+            name: "person",
+            supercategory: "person",
+        }],
+    };
+
+    for frame in frames {
+        if let Err(e) = save_frame(directory, summary.frames_saved, &frame, &mut coco) {
+            // Keep the annotations of the frames saved so far.
+            write_coco(directory, &coco)?;
+            return Err(e);
+        }
+        summary.frames_saved += 1;
+        match frame.reason {
+            SampleReason::Interval => summary.interval_frames += 1,
+            SampleReason::LowConfidence => summary.low_confidence_frames += 1,
+            SampleReason::Flagged => summary.flagged_frames += 1,
+        }
+    }
+    write_coco(directory, &coco)?;
+    log::info!(
+        "Camera {camera} dataset capture ended, {} frames in {}",
+        summary.frames_saved,
+        directory.display()
+    );
+    Ok(summary)
+}
+
+fn save_frame(
+    directory: &Path,
+    index: u32,
+    frame: &DatasetFrame,
+    coco: &mut Coco,
+) -> io::Result<()> {
+    let image = format!("images/{index:06}.png");
+    let mask = format!("masks/{index:06}.png");
+    let (width, height) = (frame.width, frame.height);
+
+    let data =
+        encode_rgba(&frame.pixels, width, height, StillFormat::Png).map_err(io::Error::other)?;
+    std::fs::write(directory.join(&image), data)?;
+    if frame.mask.len() != width as usize * height as usize {
+        return Err(io::Error::other("Mask doesn't match the frame size"));
+    }
+    let aligned = align_mask(&frame.mask, width as usize, frame.mask_offset);
+    let gray = aligned
+        .iter()
+        .map(|&v| (v.clamp(0.0, 1.0) * 255.0).round() as u8);
+    let gray = GrayImage::from_raw(width, height, gray.collect()).unwrap();
+    let mut data = Cursor::new(Vec::new());
+    gray.write_to(&mut data, ImageFormat::Png)
+        .map_err(io::Error::other)?;
+    std::fs::write(directory.join(&mask), data.into_inner())?;
+
+    let stats = mask_stats(&aligned);
+    let annotation = annotate(&aligned, width as usize, height as usize);
+    let rle = Rle {
+        size: [height, width],
+        counts: annotation.rle,
+    };
+    let record = FrameRecord {
+        index,
+        image: &image,
+        mask: &mask,
+        sequence: frame.sequence,
+        timestamp_us: frame.timestamp_us,
+        width,
+        height,
+        reason: frame.reason,
+        effects: frame.effects,
+        quality_level: frame.quality_level,
+        low_power: frame.low_power,
+        coverage: stats.coverage,
+        confidence: stats.confidence,
+        area: annotation.area,
+        bbox: annotation.bbox,
+        rle: &rle,
+        polygons: &annotation.polygons,
+    };
+    let json = serde_json::to_vec_pretty(&record).map_err(io::Error::other)?;
+    std::fs::write(directory.join(format!("annotations/{index:06}.json")), json)?;
+
+    // Frames without a person are kept as negative examples, with no annotation.
+    coco.images.push(CocoImage {
+        id: index,
+        file_name: image,
+        width,
+        height,
+    });
+    if let Some(bbox) = annotation.bbox {
+        coco.annotations.push(CocoAnnotation {
+            id: index,
+            image_id: index,
+            category_id: PERSON_CATEGORY,
+            segmentation: rle,
+            area: annotation.area,
+            bbox,
+            iscrowd: 0,
+        });
+    }
+    Ok(())
+}
+
+// Write to a temporary file then rename, readers never see partial annotations.
+fn write_coco(directory: &Path, coco: &Coco) -> io::Result<()> {
+    let path = directory.join(COCO_ANNOTATIONS);
+    let temp = path.with_extension("json.tmp");
+    let json = serde_json::to_vec(coco).map_err(io::Error::other)?;
+    std::fs::write(&temp, json)?;
+    std::fs::rename(temp, path)
+}
+
+static CAPTURES: OnceLock<Mutex<HashMap<u32, Arc<DatasetCapture>>>> = OnceLock::new();
+
+fn captures() -> &'static Mutex<HashMap<u32, Arc<DatasetCapture>>> {
+    CAPTURES.get_or_init(Default::default)
+}
+
+/// Dataset capture of the streams on a camera, it keeps going if the stream is restarted.
+pub fn get(id: u32) -> Option<Arc<DatasetCapture>> {
+    captures().lock().unwrap().get(&id).cloned()
+}
+
+/// Start a dataset capture with `start` unless the camera already has one running, in
+/// which case `start` isn't called and its directory is left alone. One that ended by
+/// itself is replaced.
+pub fn register_with(
+    id: u32,
+    start: impl FnOnce() -> io::Result<DatasetCapture>,
+) -> io::Result<Arc<DatasetCapture>> {
+    let mut captures = captures().lock().unwrap();
+    if captures.get(&id).is_some_and(|c| !c.is_finished()) {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            "Camera already has a dataset capture",
+        ));
+    }
+    let capture = Arc::new(start()?);
+    captures.insert(id, capture.clone());
+    Ok(capture)
+}
+
+pub fn remove(id: u32) -> Option<Arc<DatasetCapture>> {
+    captures().lock().unwrap().remove(&id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pipeline::pool::FramePool;
+
+    fn settings(directory: PathBuf) -> DatasetSettings {
+        DatasetSettings {
+            directory,
+            every_frames: 0,
+            max_confidence: None,
+            low_confidence_spacing: 0,
+            max_frames: None,
+        }
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("dataset-{}-{name}", std::process::id()))
+    }
+
+    // Why each frame is sampled, for frames of these confidences.
+    fn sample(capture: &DatasetCapture, confidences: &[f32]) -> Vec<Option<SampleReason>> {
+        confidences
+            .iter()
+            .map(|&confidence| capture.sample(|| confidence))
+            .collect()
+    }
+
+    #[test]
+    fn sampling() {
+        use SampleReason::*;
+        let directory = temp_dir("sampling");
+        let capture = DatasetCapture::start(
+            0,
+            DatasetSettings {
+                every_frames: 3,
+                max_confidence: Some(0.5),
+                low_confidence_spacing: 1,
+                max_frames: Some(5),
+                ..settings(directory.clone())
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            sample(&capture, &[0.9, 0.2, 0.2, 0.9, 0.2]),
+            [
+                Some(Interval),
+                Some(LowConfidence),
+                None,
+                Some(Interval),
+                Some(LowConfidence)
+            ]
+        );
+        capture.flag();
+        // The fifth frame reaches `max_frames`.
+        assert_eq!(
+            sample(&capture, &[0.9, 0.2, 0.9]),
+            [Some(Flagged), None, None]
+        );
+        capture.stop().unwrap();
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn frames_are_saved_with_aligned_masks() {
+        let directory = temp_dir("frames");
+        let capture = DatasetCapture::start(3, settings(directory.clone())).unwrap();
+        let (width, height) = (16, 12);
+        // A person over the bottom right quarter, moved up and left by the offset.
+        let mask: Vec<f32> = (0..width * height)
+            .map(|i| (i % width >= 8 && i / width >= 6) as u32 as f32)
+            .collect();
+        let pool = FramePool::new();
+        for (sequence, mask) in [(10, mask), (11, vec![0.0; width * height])] {
+            capture.push(DatasetFrame {
+                pixels: Arc::new(pool.copy(&vec![200; width * height * 4])),
+                mask,
+                mask_offset: (2, 1),
+                width: width as u32,
+                height: height as u32,
+                sequence,
+                timestamp_us: 0,
+                reason: SampleReason::Flagged,
+                effects: (&EffectSettings::default()).into(),
+                quality_level: 0,
+                low_power: false,
+            });
+        }
+        let summary = capture.stop().unwrap();
+        assert_eq!((summary.frames_saved, summary.flagged_frames), (2, 2));
+
+        let saved = image::open(directory.join("masks/000000.png"))
+            .unwrap()
+            .into_luma8();
+        assert_eq!(saved.dimensions(), (16, 12));
+        assert_eq!(saved.get_pixel(6, 5)[0], 255);
+        assert_eq!(saved.get_pixel(5, 5)[0], 0);
+        assert_eq!(saved.get_pixel(6, 4)[0], 0);
+        assert_eq!(saved.get_pixel(15, 11)[0], 255);
+        assert!(directory.join("images/000001.png").exists());
+
+        let record: serde_json::Value = serde_json::from_slice(
+            &std::fs::read(directory.join("annotations/000000.json")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(record["sequence"], 10);
+        assert_eq!(record["bbox"], serde_json::json!([6, 5, 10, 7]));
+        let coco: serde_json::Value =
+            serde_json::from_slice(&std::fs::read(directory.join(COCO_ANNOTATIONS)).unwrap())
+                .unwrap();
+        assert_eq!(coco["images"].as_array().unwrap().len(), 2);
+        // The empty frame is a negative example, without annotation.
+        let annotations = coco["annotations"].as_array().unwrap();
+        assert_eq!(annotations.len(), 1);
+        assert_eq!(annotations[0]["area"], 70);
+        assert_eq!(
+            annotations[0]["segmentation"]["size"],
+            serde_json::json!([12, 16])
+        );
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn masks_of_another_size_are_errors() {
+        let directory = temp_dir("size");
+        let capture = DatasetCapture::start(0, settings(directory.clone())).unwrap();
+        capture.push(DatasetFrame {
+            pixels: Arc::new(FramePool::new().copy(&[0; 4 * 4 * 4])),
+            mask: vec![0.0; 4],
+            mask_offset: (0, 0),
+            width: 4,
+            height: 4,
+            sequence: 0,
+            timestamp_us: 0,
+            reason: SampleReason::Interval,
+            effects: (&EffectSettings::default()).into(),
+            quality_level: 0,
+            low_power: false,
+        });
+        assert!(capture.stop().is_err());
+        // Written anyway, without the frame.
+        assert!(directory.join(COCO_ANNOTATIONS).exists());
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn used_directories_are_refused() {
+        let directory = temp_dir("used");
+        std::fs::create_dir_all(&directory).unwrap();
+        // An empty one is fine.
+        DatasetCapture::start(0, settings(directory.clone()))
+            .unwrap()
+            .stop()
+            .unwrap();
+        let error = DatasetCapture::start(0, settings(directory.clone()))
+            .err()
+            .unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::AlreadyExists);
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -0,0 +1,24 @@
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use super::pool::PooledFrame;
+
+/// A decoded RGBA camera frame and when it was captured.
+pub struct CapturedFrame {
+    pub pixels: PooledFrame,
+    pub width: u32,
+    pub height: u32,
+    /// Counts every frame out of the camera, gaps mean dropped or skipped frames.
+    pub sequence: u64,
+    pub captured_at: Instant,
+    /// Microseconds since the Unix epoch.
+    pub timestamp_us: u64,
+    /// Taken at the highest camera resolution for a snapshot, not sent to the stream.
+    pub still: bool,
+}
+
+pub fn unix_micros() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_micros() as u64)
+        .unwrap_or(0)
+}
@@ -0,0 +1,195 @@
+// Frame handoff from the capture thread to the processing thread.
+
+use std::collections::VecDeque;
+use std::sync::{Condvar, Mutex};
+use std::time::Duration;
+
+use super::frame::CapturedFrame;
+
+/// What to do with a new frame when the queue is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Overflow {
+    /// Drop the oldest queued frame, processing always gets the latest ones.
+    /// Snapshot stills are never dropped, capture waits for them instead.
+    #[default]
+    DropOldest,
+    /// Hold the capture thread until there is room, for consumers that need every frame.
+    Block,
+}
+
+struct Queue {
+    frames: VecDeque<CapturedFrame>,
+    closed: bool,
+}
+
+/// Bounded frame queue, a depth of 1 with `Overflow::DropOldest` keeps only the latest frame.
+pub struct FrameQueue {
+    depth: usize,
+    overflow: Overflow,
+    queue: Mutex<Queue>,
+    // Signaled when a frame is pushed, when one is popped, and on close.
+    changed: Condvar,
+}
+
+/// Result of `FrameQueue::push`.
+pub enum Pushed {
+    Queued,
+    /// The frame went in, and the oldest one was dropped to make room.
+    DroppedOldest,
+    Closed,
+}
+
+impl FrameQueue {
+    pub fn new(depth: usize, overflow: Overflow) -> FrameQueue {
+        FrameQueue {
+            depth: depth.max(1),
+            overflow,
+            queue: Mutex::new(Queue {
+                frames: VecDeque::with_capacity(depth.max(1)),
+                closed: false,
+            }),
+            changed: Condvar::new(),
+        }
+    }
+
+    pub fn push(&self, frame: CapturedFrame) -> Pushed {
+        let mut queue = self.queue.lock().unwrap();
+        let mut dropped = false;
+        while !queue.closed && queue.frames.len() >= self.depth {
+            let droppable = match self.overflow {
+                Overflow::DropOldest => queue.frames.iter().position(|frame| !frame.still),
+                Overflow::Block => None,
+            };
+            match droppable {
+                Some(index) => dropped = queue.frames.remove(index).is_some(),
+                None => queue = self.changed.wait(queue).unwrap(),
+            }
+        }
+        if queue.closed {
+            return Pushed::Closed;
+        }
+        queue.frames.push_back(frame);
+        self.changed.notify_all();
+        if dropped {
+            Pushed::DroppedOldest
+        } else {
+            Pushed::Queued
+        }
+    }
+
+    /// Wait up to `timeout` for a frame. `None` on timeout or once closed and empty.
+    pub fn pop(&self, timeout: Duration) -> Option<CapturedFrame> {
+        let queue = self.queue.lock().unwrap();
+        let (mut queue, _) = self
+            .changed
+            .wait_timeout_while(queue, timeout, |q| q.frames.is_empty() && !q.closed)
+            .unwrap();
+        let frame = queue.frames.pop_front();
+        if frame.is_some() {
+            self.changed.notify_all();
+        }
+        frame
+    }
+
+    /// Wake up both sides, pushes fail from now on.
+    pub fn close(&self) {
+        self.queue.lock().unwrap().closed = true;
+        self.changed.notify_all();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Instant;
+
+    use super::*;
+    use crate::pipeline::pool::FramePool;
+
+    const NO_WAIT: Duration = Duration::ZERO;
+
+    fn frame(pool: &Arc<FramePool>, sequence: u64, still: bool) -> CapturedFrame {
+        CapturedFrame {
+            pixels: pool.get(4),
+            width: 1,
+            height: 1,
+            sequence,
+            captured_at: Instant::now(),
+            timestamp_us: 0,
+            still,
+        }
+    }
+
+    fn sequences(queue: &FrameQueue) -> Vec<u64> {
+        std::iter::from_fn(|| queue.pop(NO_WAIT))
+            .map(|frame| frame.sequence)
+            .collect()
+    }
+
+    #[test]
+    fn keeps_the_latest_frames() {
+        let pool = FramePool::new();
+        let queue = FrameQueue::new(2, Overflow::DropOldest);
+        assert!(matches!(queue.push(frame(&pool, 1, false)), Pushed::Queued));
+        assert!(matches!(queue.push(frame(&pool, 2, false)), Pushed::Queued));
+        assert!(matches!(
+            queue.push(frame(&pool, 3, false)),
+            Pushed::DroppedOldest
+        ));
+        assert_eq!(sequences(&queue), [2, 3]);
+    }
+
+    #[test]
+    fn stills_are_not_dropped() {
+        let pool = FramePool::new();
+        let queue = FrameQueue::new(2, Overflow::DropOldest);
+        queue.push(frame(&pool, 1, true));
+        queue.push(frame(&pool, 2, false));
+        queue.push(frame(&pool, 3, false));
+        assert_eq!(sequences(&queue), [1, 3]);
+    }
+
+    #[test]
+    fn blocking_push_waits_for_room() {
+        let pool = FramePool::new();
+        let queue = Arc::new(FrameQueue::new(1, Overflow::Block));
+        queue.push(frame(&pool, 1, false));
+
+        let pusher = {
+            let (queue, pool) = (queue.clone(), pool.clone());
+            std::thread::spawn(move || matches!(queue.push(frame(&pool, 2, false)), Pushed::Queued))
+        };
+        assert_eq!(queue.pop(Duration::from_secs(5)).unwrap().sequence, 1);
+        assert!(pusher.join().unwrap());
+        assert_eq!(sequences(&queue), [2]);
+    }
+
+    #[test]
+    fn close_wakes_a_blocked_push() {
+        let pool = FramePool::new();
+        let queue = Arc::new(FrameQueue::new(1, Overflow::Block));
+        queue.push(frame(&pool, 1, false));
+
+        let pusher = {
+            let (queue, pool) = (queue.clone(), pool.clone());
+            std::thread::spawn(move || matches!(queue.push(frame(&pool, 2, false)), Pushed::Closed))
+        };
+        std::thread::sleep(Duration::from_millis(20));
+        queue.close();
+        assert!(pusher.join().unwrap());
+    }
+
+    #[test]
+    fn closed_queue_is_drained_then_empty() {
+        let pool = FramePool::new();
+        let queue = FrameQueue::new(2, Overflow::DropOldest);
+        queue.push(frame(&pool, 1, false));
+        queue.close();
+        assert!(matches!(queue.push(frame(&pool, 2, false)), Pushed::Closed));
+        assert_eq!(queue.pop(Duration::from_secs(5)).unwrap().sequence, 1);
+        // Returns at once instead of waiting for the timeout.
+        let start = Instant::now();
+        assert!(queue.pop(Duration::from_secs(5)).is_none());
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+}
@@ -0,0 +1,216 @@
+// Per stream counters and rolling stage timings.
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy)]
+pub enum Stage {
+    Decode,
+    Preprocess,
+    Inference,
+    Postprocess,
+    Composite,
+    Encode,
+    Sink,
+    /// From capture to the frame being handed to Dart.
+    EndToEnd,
+}
+
+const STAGE_COUNT: usize = 8;
+// Samples kept per stage for the percentiles, a few seconds at 30 fps.
+const WINDOW: usize = 256;
+// Frames used to compute the frame rates.
+const FPS_WINDOW: usize = 64;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StageSnapshot {
+    pub last_us: u64,
+    pub mean_us: u64,
+    pub p50_us: u64,
+    pub p95_us: u64,
+    pub p99_us: u64,
+    pub max_us: u64,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MetricsSnapshot {
+    pub captured: u64,
+    pub processed: u64,
+    pub dropped: u64,
+    pub capture_fps: f64,
+    pub output_fps: f64,
+    pub stages: [StageSnapshot; STAGE_COUNT],
+}
+
+impl MetricsSnapshot {
+    pub fn stage(&self, stage: Stage) -> StageSnapshot {
+        self.stages[stage as usize]
+    }
+}
+
+#[derive(Default)]
+struct Windows {
+    stages: [VecDeque<u64>; STAGE_COUNT],
+    captured_at: VecDeque<Instant>,
+    processed_at: VecDeque<Instant>,
+}
+
+#[derive(Default)]
+pub struct PipelineMetrics {
+    captured: AtomicU64,
+    processed: AtomicU64,
+    dropped: AtomicU64,
+    windows: Mutex<Windows>,
+}
+
+impl PipelineMetrics {
+    pub fn frame_captured(&self) {
+        self.captured.fetch_add(1, Ordering::Relaxed);
+        push(
+            &mut self.windows.lock().unwrap().captured_at,
+            Instant::now(),
+            FPS_WINDOW,
+        );
+    }
+
+    pub fn frame_processed(&self) {
+        self.processed.fetch_add(1, Ordering::Relaxed);
+        push(
+            &mut self.windows.lock().unwrap().processed_at,
+            Instant::now(),
+            FPS_WINDOW,
+        );
+    }
+
+    pub fn frame_dropped(&self) {
+        self.dropped.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record(&self, stage: Stage, duration: Duration) {
+        let mut windows = self.windows.lock().unwrap();
+        push(
+            &mut windows.stages[stage as usize],
+            duration.as_micros() as u64,
+            WINDOW,
+        );
+    }
+
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        let windows = self.windows.lock().unwrap();
+        MetricsSnapshot {
+            captured: self.captured.load(Ordering::Relaxed),
+            processed: self.processed.load(Ordering::Relaxed),
+            dropped: self.dropped.load(Ordering::Relaxed),
+            capture_fps: fps(&windows.captured_at),
+            output_fps: fps(&windows.processed_at),
+            stages: std::array::from_fn(|i| stage_snapshot(&windows.stages[i])),
+        }
+    }
+}
+
+fn push<T>(window: &mut VecDeque<T>, value: T, len: usize) {
+    if window.len() == len {
+        window.pop_front();
+    }
+    window.push_back(value);
+}
+
+fn fps(times: &VecDeque<Instant>) -> f64 {
+    match (times.front(), times.back()) {
+        (Some(first), Some(last)) if times.len() > 1 => {
+            let span = last.duration_since(*first).as_secs_f64();
+            if span > 0.0 {
+                (times.len() - 1) as f64 / span
+            } else {
+                0.0
+            }
+        }
+        _ => 0.0,
+    }
+}
+
+fn stage_snapshot(samples: &VecDeque<u64>) -> StageSnapshot {
+    let Some(&last_us) = samples.back() else {
+        return StageSnapshot::default();
+    };
+    let mut sorted: Vec<u64> = samples.iter().copied().collect();
+    sorted.sort_unstable();
+    let percentile = |p: usize| sorted[(sorted.len() - 1) * p / 100];
+
+    StageSnapshot {
+        last_us,
+        mean_us: sorted.iter().sum::<u64>() / sorted.len() as u64,
+        p50_us: percentile(50),
+        p95_us: percentile(95),
+        p99_us: percentile(99),
+        max_us: sorted[sorted.len() - 1],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stage_percentiles() {
+        let metrics = PipelineMetrics::default();
+        // Out of order, the percentiles sort them.
+        for us in (1..=100).rev() {
+            metrics.record(Stage::Inference, Duration::from_micros(us));
+        }
+        let inference = metrics.snapshot().stage(Stage::Inference);
+        assert_eq!(inference.last_us, 1);
+        assert_eq!(inference.mean_us, 50);
+        assert_eq!(
+            (inference.p50_us, inference.p95_us, inference.p99_us),
+            (50, 95, 99)
+        );
+        assert_eq!(inference.max_us, 100);
+        assert_eq!(metrics.snapshot().stage(Stage::Encode).max_us, 0);
+    }
+
+    #[test]
+    fn only_recent_samples_count() {
+        let metrics = PipelineMetrics::default();
+        metrics.record(Stage::Sink, Duration::from_secs(1));
+        for _ in 0..WINDOW {
+            metrics.record(Stage::Sink, Duration::from_micros(10));
+        }
+        assert_eq!(metrics.snapshot().stage(Stage::Sink).max_us, 10);
+    }
+
+    #[test]
+    fn counters() {
+        let metrics = PipelineMetrics::default();
+        for _ in 0..3 {
+            metrics.frame_captured();
+        }
+        metrics.frame_processed();
+        metrics.frame_dropped();
+        let snapshot = metrics.snapshot();
+        assert_eq!(
+            (snapshot.captured, snapshot.processed, snapshot.dropped),
+            (3, 1, 1)
+        );
+        // A single frame has no rate yet.
+        assert_eq!(snapshot.output_fps, 0.0);
+    }
+
+    #[test]
+    fn frame_rate_over_the_window() {
+        let start = Instant::now();
+        let mut times = VecDeque::new();
+        for i in 0..FPS_WINDOW as u64 * 2 {
+            push(
+                &mut times,
+                start + Duration::from_millis(i * 40),
+                FPS_WINDOW,
+            );
+        }
+        assert_eq!(times.len(), FPS_WINDOW);
+        assert!((fps(&times) - 25.0).abs() < 1e-9);
+        assert_eq!(fps(&VecDeque::from([start, start])), 0.0);
+    }
+}
@@ -0,0 +1,19 @@
+pub mod archive;
+pub mod burst;
+pub mod clip;
+pub mod dataset;
+pub mod frame;
+pub mod handoff;
+pub mod metrics;
+pub mod motion;
+pub mod pool;
+pub mod power;
+pub mod processor;
+pub mod quality;
+pub mod recorder;
+pub mod replay;
+pub mod ring;
+pub mod session;
+pub mod snapshot;
+pub mod tap;
+pub mod timelapse;
@@ -0,0 +1,96 @@
+// Cheap scene change detection, used to skip inference on still frames.
+
+// Distance in pixels between samples, on both axes.
+const STEP: usize = 8;
+// Mean luma difference between samples, out of 255, above which the scene moved.
+const THRESHOLD: u32 = 4;
+
+#[derive(Default)]
+pub struct MotionDetector {
+    width: usize,
+    samples: Vec<u8>,
+    // Samples of the last frame that moved, so slow changes add up.
+    reference: Vec<u8>,
+}
+
+impl MotionDetector {
+    /// Compare a RGBA frame with the last one that moved, true if anything moved since.
+    /// The first frame and any size change count as motion.
+    pub fn moved(&mut self, rgba_data: &[u8], width: u32) -> bool {
+        let width = width as usize;
+        self.samples.clear();
+        for row in rgba_data.chunks_exact(width * 4).step_by(STEP) {
+            self.samples.extend(
+                row.chunks_exact(4).step_by(STEP).map(|p| {
+                    ((77 * p[0] as u32 + 150 * p[1] as u32 + 29 * p[2] as u32) >> 8) as u8
+                }),
+            );
+        }
+
+        let moved = self.width != width
+            || self.samples.len() != self.reference.len()
+            || self
+                .samples
+                .iter()
+                .zip(&self.reference)
+                .map(|(&a, &b)| a.abs_diff(b) as u32)
+                .sum::<u32>()
+                > THRESHOLD * self.samples.len() as u32;
+        if moved {
+            self.width = width;
+            std::mem::swap(&mut self.samples, &mut self.reference);
+        }
+        moved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WIDTH: u32 = 64;
+    const HEIGHT: u32 = 48;
+
+    fn frame(value: u8) -> Vec<u8> {
+        [value, value, value, 255].repeat((WIDTH * HEIGHT) as usize)
+    }
+
+    #[test]
+    fn first_frame_and_size_changes_move() {
+        let mut detector = MotionDetector::default();
+        assert!(detector.moved(&frame(100), WIDTH));
+        assert!(!detector.moved(&frame(100), WIDTH));
+        assert!(detector.moved(&frame(100)[..(WIDTH * 32 * 4) as usize], WIDTH));
+        assert!(detector.moved(&frame(100), WIDTH / 2));
+    }
+
+    #[test]
+    fn small_changes_are_still() {
+        let mut detector = MotionDetector::default();
+        detector.moved(&frame(100), WIDTH);
+        assert!(!detector.moved(&frame(103), WIDTH));
+        assert!(detector.moved(&frame(120), WIDTH));
+    }
+
+    #[test]
+    fn slow_changes_add_up() {
+        let mut detector = MotionDetector::default();
+        detector.moved(&frame(100), WIDTH);
+        let moved: Vec<bool> = (1..=5)
+            .map(|i| detector.moved(&frame(100 + i * 2), WIDTH))
+            .collect();
+        // Compared with the first frame, until the difference goes over the threshold.
+        assert_eq!(moved, [false, false, true, false, false]);
+    }
+
+    #[test]
+    fn unsampled_pixels_are_ignored() {
+        let mut detector = MotionDetector::default();
+        detector.moved(&frame(100), WIDTH);
+        let mut changed = frame(100);
+        // Pixel (1, 1) sits between the samples.
+        let i = ((WIDTH + 1) * 4) as usize;
+        changed[i..i + 3].copy_from_slice(&[255; 3]);
+        assert!(!detector.moved(&changed, WIDTH));
+    }
+}
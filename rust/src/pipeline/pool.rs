@@ -0,0 +1,172 @@
+// Recycled frame buffers.
+// Capture decodes into a pooled buffer, processing writes its output in another one,
+// and both go back to the pool once dropped instead of being freed.
+
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+// More than enough for the frames in flight between capture, processing and the sink.
+const MAX_FREE_BUFFERS: usize = 8;
+
+#[derive(Default)]
+pub struct FramePool {
+    free: Mutex<Vec<Vec<u8>>>,
+    allocations: AtomicU64,
+    frames: AtomicU64,
+    last_frame_allocations: AtomicU64,
+    allocations_at_last_frame: AtomicU64,
+}
+
+impl FramePool {
+    pub fn new() -> Arc<FramePool> {
+        Arc::new(FramePool::default())
+    }
+
+    /// A buffer of `len` bytes, recycled when possible. Its content is unspecified.
+    pub fn get(self: &Arc<Self>, len: usize) -> PooledFrame {
+        let recycled = self.free.lock().unwrap().pop();
+        let data = match recycled {
+            Some(mut data) if data.capacity() >= len => {
+                data.resize(len, 0);
+                data
+            }
+            _ => {
+                self.allocations.fetch_add(1, Ordering::Relaxed);
+                vec![0; len]
+            }
+        };
+        PooledFrame {
+            data,
+            pool: self.clone(),
+        }
+    }
+
+    /// A pooled copy of `pixels`, for frames kept once processing took the original.
+    pub fn copy(self: &Arc<Self>, pixels: &[u8]) -> PooledFrame {
+        let mut frame = self.get(pixels.len());
+        frame.copy_from_slice(pixels);
+        frame
+    }
+
+    /// Called once per frame sent, to track the allocations made for it.
+    pub fn frame_done(&self) {
+        let total = self.allocations.load(Ordering::Relaxed);
+        let previous = self
+            .allocations_at_last_frame
+            .swap(total, Ordering::Relaxed);
+        self.last_frame_allocations
+            .store(total.saturating_sub(previous), Ordering::Relaxed);
+        self.frames.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn stats(&self) -> AllocationStats {
+        AllocationStats {
+            frames: self.frames.load(Ordering::Relaxed),
+            allocations: self.allocations.load(Ordering::Relaxed),
+            last_frame_allocations: self.last_frame_allocations.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// A frame buffer that goes back to its pool when dropped.
+pub struct PooledFrame {
+    data: Vec<u8>,
+    pool: Arc<FramePool>,
+}
+
+impl PooledFrame {
+    /// For content of variable length, like encoded frames.
+    pub fn vec_mut(&mut self) -> &mut Vec<u8> {
+        &mut self.data
+    }
+}
+
+impl Deref for PooledFrame {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+impl DerefMut for PooledFrame {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        &mut self.data
+    }
+}
+
+impl Drop for PooledFrame {
+    fn drop(&mut self) {
+        if self.data.capacity() == 0 {
+            return;
+        }
+        let mut free = self.pool.free.lock().unwrap();
+        if free.len() < MAX_FREE_BUFFERS {
+            free.push(std::mem::take(&mut self.data));
+        }
+    }
+}
+
+/// Frame buffers a pool allocated so far.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AllocationStats {
+    pub frames: u64,
+    pub allocations: u64,
+    pub last_frame_allocations: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dropped_frames_are_recycled() {
+        let pool = FramePool::new();
+        for _ in 0..10 {
+            let frame = pool.get(1024);
+            let output = pool.get(1024);
+            drop((frame, output));
+            pool.frame_done();
+        }
+        let stats = pool.stats();
+        assert_eq!(stats.frames, 10);
+        assert_eq!(stats.allocations, 2);
+        assert_eq!(stats.last_frame_allocations, 0);
+    }
+
+    #[test]
+    fn larger_frames_are_allocated() {
+        let pool = FramePool::new();
+        drop(pool.get(16));
+        pool.frame_done();
+        let frame = pool.get(32);
+        assert_eq!(frame.len(), 32);
+        drop(frame);
+        pool.frame_done();
+        assert_eq!(pool.stats().last_frame_allocations, 1);
+
+        // Smaller ones reuse the larger buffer.
+        assert_eq!(pool.get(8).len(), 8);
+        pool.frame_done();
+        assert_eq!(pool.stats().last_frame_allocations, 0);
+    }
+
+    #[test]
+    fn pools_count_separately() {
+        let (a, b) = (FramePool::new(), FramePool::new());
+        drop(a.get(16));
+        a.frame_done();
+        b.frame_done();
+        assert_eq!(a.stats().allocations, 1);
+        assert_eq!(b.stats().allocations, 0);
+    }
+
+    #[test]
+    fn free_list_is_bounded() {
+        let pool = FramePool::new();
+        let frames: Vec<_> = (0..MAX_FREE_BUFFERS + 4).map(|_| pool.get(16)).collect();
+        drop(frames);
+        assert_eq!(pool.free.lock().unwrap().len(), MAX_FREE_BUFFERS);
+    }
+}
@@ -0,0 +1,157 @@
+// Frame rate limit and power modes, shared by every stream.
+
+use std::sync::atomic::{AtomicU32, AtomicU8, Ordering};
+use std::sync::{Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+use super::session::StreamSession;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PowerMode {
+    #[default]
+    Normal,
+    /// Capped frame rate, inference skipped while the scene doesn't move, and run on the
+    /// lite model when there is one.
+    LowPower,
+    /// Camera closed and threads asleep, for when the app is hidden.
+    Idle,
+}
+
+// Frame rate cap of the low power mode.
+const LOW_POWER_FPS: u32 = 15;
+// How often sleeping threads check that their stream is still running.
+const IDLE_CHECK: Duration = Duration::from_millis(250);
+
+// Read on every frame, so kept in atomics. The mutex is only there for the condvar.
+static MODE: AtomicU8 = AtomicU8::new(PowerMode::Normal as u8);
+// 0 for the camera rate.
+static TARGET_FPS: AtomicU32 = AtomicU32::new(0);
+static WAKE_LOCK: Mutex<()> = Mutex::new(());
+static WAKE: Condvar = Condvar::new();
+
+pub fn set_mode(mode: PowerMode) {
+    // Under the lock, so a thread can't miss the wake up between its check and its wait.
+    let _lock = WAKE_LOCK.lock().unwrap();
+    MODE.store(mode as u8, Ordering::Relaxed);
+    WAKE.notify_all();
+}
+
+pub fn mode() -> PowerMode {
+    match MODE.load(Ordering::Relaxed) {
+        m if m == PowerMode::LowPower as u8 => PowerMode::LowPower,
+        m if m == PowerMode::Idle as u8 => PowerMode::Idle,
+        _ => PowerMode::Normal,
+    }
+}
+
+pub fn set_target_fps(fps: u32) {
+    TARGET_FPS.store(fps, Ordering::Relaxed);
+}
+
+/// Frame rate to process at, `None` to follow the camera.
+pub fn frame_rate() -> Option<u32> {
+    let target = TARGET_FPS.load(Ordering::Relaxed);
+    let target = (target > 0).then_some(target);
+    match mode() {
+        PowerMode::LowPower => Some(target.map_or(LOW_POWER_FPS, |t| t.min(LOW_POWER_FPS))),
+        _ => target,
+    }
+}
+
+/// Block while in idle mode. Returns false if the stream stopped meanwhile.
+pub fn wait_while_idle(session: &StreamSession) -> bool {
+    if mode() != PowerMode::Idle {
+        return session.is_running();
+    }
+    let mut lock = WAKE_LOCK.lock().unwrap();
+    while mode() == PowerMode::Idle && session.is_running() {
+        lock = WAKE.wait_timeout(lock, IDLE_CHECK).unwrap().0;
+    }
+    session.is_running()
+}
+
+/// Lets frames through at most at a given rate.
+#[derive(Default)]
+pub struct FrameLimiter {
+    next: Option<Instant>,
+}
+
+impl FrameLimiter {
+    /// Whether a frame arriving `now` should be kept, `fps` being `None` when there is no limit.
+    pub fn accept(&mut self, now: Instant, fps: Option<u32>) -> bool {
+        let Some(fps) = fps else {
+            self.next = None;
+            return true;
+        };
+        let interval = Duration::from_secs(1) / fps.max(1);
+        // Camera frames don't arrive exactly on time, take one that is a bit early
+        // rather than waiting a whole camera frame more.
+        let slack = interval / 4;
+        match self.next {
+            Some(next) if now + slack < next => false,
+            next => {
+                // Restart from now after a gap, instead of letting frames through to catch up.
+                let base = next.filter(|&n| now < n + interval).unwrap_or(now);
+                self.next = Some(base + interval);
+                true
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Frames of a 30 fps camera, in the order the limiter gets them.
+    fn accepted(limiter: &mut FrameLimiter, fps: Option<u32>, frames: u32) -> usize {
+        let start = Instant::now();
+        (0..frames)
+            .filter(|&i| limiter.accept(start + Duration::from_secs(1) * i / 30, fps))
+            .count()
+    }
+
+    #[test]
+    fn limiter_keeps_the_target_rate() {
+        let mut limiter = FrameLimiter::default();
+        assert_eq!(accepted(&mut limiter, Some(15), 30), 15);
+        let mut limiter = FrameLimiter::default();
+        assert_eq!(accepted(&mut limiter, Some(10), 30), 10);
+    }
+
+    #[test]
+    fn limiter_without_target_keeps_everything() {
+        let mut limiter = FrameLimiter::default();
+        assert_eq!(accepted(&mut limiter, None, 30), 30);
+        // Nor faster than the camera with a higher target.
+        assert_eq!(accepted(&mut limiter, Some(60), 30), 30);
+    }
+
+    #[test]
+    fn limiter_does_not_catch_up_after_a_gap() {
+        let mut limiter = FrameLimiter::default();
+        let start = Instant::now();
+        assert!(limiter.accept(start, Some(10)));
+        let later = start + Duration::from_secs(2);
+        assert!(limiter.accept(later, Some(10)));
+        assert!(!limiter.accept(later + Duration::from_millis(33), Some(10)));
+    }
+
+    // The only test changing the global mode, others would see it.
+    #[test]
+    fn low_power_caps_the_frame_rate() {
+        set_target_fps(0);
+        set_mode(PowerMode::LowPower);
+        assert_eq!(mode(), PowerMode::LowPower);
+        assert_eq!(frame_rate(), Some(LOW_POWER_FPS));
+        set_target_fps(10);
+        assert_eq!(frame_rate(), Some(10));
+        set_target_fps(60);
+        assert_eq!(frame_rate(), Some(LOW_POWER_FPS));
+
+        set_mode(PowerMode::Normal);
+        assert_eq!(frame_rate(), Some(60));
+        set_target_fps(0);
+        assert_eq!(frame_rate(), None);
+    }
+}
@@ -0,0 +1,350 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use image::imageops::FilterType;
+use image::RgbaImage;
+
+use crate::media::convert::{rgba_half_size, FrameEncoder, OutputFormat};
+use crate::ml::composite::{BlurQuality, BoxBlur};
+use crate::ml::image::{
+    blur_background, camera_mask_offset, cut_out_person, replace_background, show_mask_overlay,
+    ImageSegmentation, MaskTimings,
+};
+use crate::ml::model::ModelVariant;
+
+use super::motion::MotionDetector;
+use super::pool::{FramePool, PooledFrame};
+use super::quality::{QualityLevel, LEVELS};
+
+const BLUR_SIGMA: f32 = 12.0;
+// In low power mode, still frames reuse the mask up to this many frames.
+const STILL_MASK_FRAMES: u32 = 30;
+
+/// Effects to apply, read from the camera state for each frame.
+#[derive(Clone, Default)]
+pub struct EffectSettings {
+    pub blur: bool,
+    pub background: Option<Arc<RgbaImage>>,
+    pub debug: bool,
+    pub roi: bool,
+    /// Smooth the mask edges, the quality level can still turn it off.
+    pub refine: bool,
+    /// Average the mask over the last frames, see `ImageSegmentation::set_temporal_smoothing`.
+    pub temporal: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AppliedEffects {
+    pub blur: bool,
+    pub background_replacement: bool,
+    pub mask_overlay: bool,
+    pub roi: bool,
+}
+
+/// Time spent on the last frame, `mask` is `None` when no segmentation was needed.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ProcessTimings {
+    pub mask: Option<MaskTimings>,
+    pub composite: Duration,
+    pub encode: Duration,
+}
+
+/// Segmentation and effects of a stream, with the state they keep between frames.
+pub struct FrameProcessor {
+    segmentation: ImageSegmentation,
+    compositor: Compositor,
+    encoder: FrameEncoder,
+    pool: Arc<FramePool>,
+    // Encoded frames are much smaller than RGBA ones, mixing them in `pool` would make
+    // it hand out buffers too small for a frame.
+    encoded_pool: Arc<FramePool>,
+    timings: ProcessTimings,
+    quality: QualityLevel,
+    // Frames since the mask was last computed.
+    mask_age: u32,
+    low_power: bool,
+    // Mask edge smoothing of the last settings.
+    refine: bool,
+    motion: MotionDetector,
+    // Whether the last `process` call used a mask.
+    masked: bool,
+    // Whether `mask` ran the segmentation since the last `process` call.
+    extra_mask: bool,
+    // Frames come from the camera, see `set_camera_frames`.
+    camera_frames: bool,
+}
+
+impl FrameProcessor {
+    pub fn new(pool: Arc<FramePool>) -> FrameProcessor {
+        FrameProcessor {
+            segmentation: ImageSegmentation::init(),
+            compositor: Compositor::default(),
+            encoder: FrameEncoder::default(),
+            pool,
+            encoded_pool: FramePool::new(),
+            timings: ProcessTimings::default(),
+            quality: LEVELS[0],
+            mask_age: 0,
+            low_power: false,
+            refine: false,
+            motion: MotionDetector::default(),
+            masked: false,
+            extra_mask: false,
+            camera_frames: true,
+        }
+    }
+
+    /// Whether frames come from the camera, whose mask needs a shift to line up.
+    /// On by default, turn it off for photos and other images.
+    pub fn set_camera_frames(&mut self, enabled: bool) {
+        self.camera_frames = enabled;
+    }
+
+    /// Shift of the mask over frames of this size, see `camera_mask_offset`.
+    pub fn mask_offset(&self, width: u32, height: u32) -> (usize, usize) {
+        if self.camera_frames {
+            camera_mask_offset(width, height)
+        } else {
+            (0, 0)
+        }
+    }
+
+    pub fn set_quality(&mut self, quality: QualityLevel) {
+        self.quality = quality;
+        self.configure_segmentation();
+    }
+
+    fn configure_segmentation(&mut self) {
+        self.segmentation
+            .set_refinement(self.refine && self.quality.refine_mask);
+        let variant = if self.low_power {
+            ModelVariant::Lite
+        } else {
+            self.quality.model
+        };
+        self.segmentation.set_variant(variant);
+    }
+
+    /// Forget what is kept from one frame to the next, like the last mask and the
+    /// tracked person. What follows then only depends on the next frames.
+    pub fn reset(&mut self) {
+        self.segmentation.reset();
+        self.motion = MotionDetector::default();
+        self.mask_age = 0;
+        self.masked = false;
+        self.extra_mask = false;
+    }
+
+    /// Skip inference while the scene doesn't move, and run the lite model when there is one.
+    pub fn set_low_power(&mut self, enabled: bool) {
+        if enabled != self.low_power {
+            self.low_power = enabled;
+            self.configure_segmentation();
+        }
+    }
+
+    /// Apply the effects to a RGBA frame. The frame itself is returned when there is nothing to do.
+    pub fn process(
+        &mut self,
+        frame: PooledFrame,
+        width: u32,
+        height: u32,
+        settings: &EffectSettings,
+    ) -> (PooledFrame, AppliedEffects) {
+        let mut applied = AppliedEffects::default();
+        self.timings = ProcessTimings::default();
+        self.extra_mask = false;
+        let effect = if settings.debug {
+            Some(Effect::MaskOverlay)
+        } else if settings.blur {
+            Some(Effect::Blur {
+                sigma: BLUR_SIGMA,
+                quality: self.quality.blur,
+            })
+        } else {
+            settings.background.as_ref().map(Effect::Background)
+        };
+        self.masked = effect.is_some();
+        let Some(effect) = effect else {
+            return (frame, applied);
+        };
+        applied.mask_overlay = matches!(effect, Effect::MaskOverlay);
+        applied.blur = matches!(effect, Effect::Blur { .. });
+        applied.background_replacement = matches!(effect, Effect::Background(_));
+
+        self.segmentation.set_roi_tracking(settings.roi);
+        self.segmentation.set_temporal_smoothing(settings.temporal);
+        if settings.refine != self.refine {
+            self.refine = settings.refine;
+            self.configure_segmentation();
+        }
+        applied.roi = settings.roi;
+        self.mask_age += 1;
+        let still = self.low_power
+            && !self.motion.moved(&frame, width)
+            && self.mask_age < STILL_MASK_FRAMES;
+        let reuse = (still || self.mask_age < self.quality.inference_interval)
+            && self.segmentation.last_mask(width, height).is_some();
+        let offset = self.mask_offset(width, height);
+        let mask = if reuse {
+            self.segmentation.last_mask(width, height).unwrap()
+        } else {
+            self.mask_age = 0;
+            self.segmentation.create_mask(&frame, width, height)
+        };
+        let start = Instant::now();
+        let mut output = self.pool.get(frame.len());
+        self.compositor
+            .apply(effect, &frame, mask, width, offset, &mut output);
+        self.timings.composite = start.elapsed();
+        self.timings.mask = (!reuse).then(|| self.segmentation.timings());
+
+        (output, applied)
+    }
+
+    /// Person mask of the last processed frame, `rgba_data`, computing it at most once per frame
+    /// if no effect needed it.
+    pub fn mask(&mut self, rgba_data: &[u8], width: u32, height: u32) -> &[f32] {
+        let computed = self.masked || self.extra_mask;
+        if computed && self.segmentation.last_mask(width, height).is_some() {
+            return self.segmentation.last_mask(width, height).unwrap();
+        }
+        self.extra_mask = true;
+        self.segmentation.create_mask(rgba_data, width, height)
+    }
+
+    /// Mask used by the last `process` call, `None` if it applied no effect.
+    pub fn last_mask(&self, width: u32, height: u32) -> Option<&[f32]> {
+        self.masked
+            .then(|| self.segmentation.last_mask(width, height))
+            .flatten()
+    }
+
+    /// Whether `mask` ran the segmentation after the last `process` call.
+    /// Later frames can reuse that mask, so replays have to compute it too.
+    pub fn computed_extra_mask(&self) -> bool {
+        self.extra_mask
+    }
+
+    /// Halve the frame size when the quality level asks for it.
+    /// Returns the frame with its new width and height.
+    pub fn scale(
+        &mut self,
+        frame: PooledFrame,
+        width: u32,
+        height: u32,
+    ) -> (PooledFrame, u32, u32) {
+        if !self.quality.half_resolution {
+            return (frame, width, height);
+        }
+        let start = Instant::now();
+        let (half_w, half_h) = (width / 2, height / 2);
+        let mut output = self.pool.get((half_w * half_h * 4) as usize);
+        rgba_half_size(&frame, width, height, &mut output);
+        self.timings.composite += start.elapsed();
+        (output, half_w, half_h)
+    }
+
+    pub fn timings(&self) -> ProcessTimings {
+        self.timings
+    }
+
+    /// Convert a processed RGBA frame to the output format.
+    /// Returns the format actually used, the RGBA frame is kept if encoding fails.
+    pub fn encode(
+        &mut self,
+        frame: PooledFrame,
+        width: u32,
+        height: u32,
+        format: OutputFormat,
+    ) -> (PooledFrame, OutputFormat) {
+        if format == OutputFormat::Rgba {
+            return (frame, format);
+        }
+        let start = Instant::now();
+        let mut encoded = self.encoded_pool.get(0);
+        let result = self
+            .encoder
+            .encode(&frame, width, height, format, encoded.vec_mut());
+        self.timings.encode = start.elapsed();
+        match result {
+            Ok(()) => (encoded, format),
+            Err(e) => {
+                log::warn!("Can't encode frame as {format:?}, sending RGBA: {e}");
+                (frame, OutputFormat::Rgba)
+            }
+        }
+    }
+}
+
+/// What `Compositor::apply` does with the mask.
+pub enum Effect<'a> {
+    /// The mask over the frame, to check the segmentation.
+    MaskOverlay,
+    Blur {
+        sigma: f32,
+        quality: BlurQuality,
+    },
+    /// Resized to the frame.
+    Background(&'a Arc<RgbaImage>),
+    /// Transparent background.
+    Cutout,
+}
+
+/// Applies an effect from a mask, keeping the blur buffers and the resized background
+/// from one frame to the next.
+#[derive(Default)]
+pub struct Compositor {
+    blur: BoxBlur,
+    // The background resized to the frame size, with the image it comes from.
+    background: Option<(Arc<RgbaImage>, RgbaImage)>,
+}
+
+impl Compositor {
+    /// `offset` shifts the mask over the frame, see `camera_mask_offset`.
+    pub fn apply(
+        &mut self,
+        effect: Effect,
+        frame: &[u8],
+        mask: &[f32],
+        width: u32,
+        offset: (usize, usize),
+        out: &mut [u8],
+    ) {
+        match effect {
+            Effect::MaskOverlay => show_mask_overlay(frame, mask, width, offset, out),
+            Effect::Blur { sigma, quality } => blur_background(
+                frame,
+                mask,
+                width,
+                offset,
+                sigma,
+                &mut self.blur,
+                quality,
+                out,
+            ),
+            Effect::Background(background) => {
+                let height = (frame.len() / 4 / width as usize) as u32;
+                let background = self.fit_background(background, width, height);
+                replace_background(frame, background, mask, width, offset, out);
+            }
+            Effect::Cutout => cut_out_person(frame, mask, width, offset, out),
+        }
+    }
+
+    // Resize the background to the frame, only again when the image or the frame size changes.
+    fn fit_background(&mut self, background: &Arc<RgbaImage>, width: u32, height: u32) -> &[u8] {
+        let stale = match &self.background {
+            Some((image, resized)) => {
+                !Arc::ptr_eq(image, background) || resized.dimensions() != (width, height)
+            }
+            None => true,
+        };
+        if stale {
+            let resized =
+                image::imageops::resize(background.as_ref(), width, height, FilterType::Lanczos3);
+            self.background = Some((background.clone(), resized));
+        }
+        self.background.as_ref().unwrap().1.as_raw()
+    }
+}
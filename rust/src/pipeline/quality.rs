@@ -0,0 +1,214 @@
+// Adaptive quality.
+// Watches the processing time of each frame against the target frame rate and moves
+// through `LEVELS`, cheaper when over budget and back up once there is headroom.
+
+use std::time::Duration;
+
+use crate::ml::composite::BlurQuality;
+use crate::ml::model::ModelVariant;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QualityLevel {
+    /// Run the segmentation every `inference_interval` frames, reusing the last mask in between.
+    pub inference_interval: u32,
+    pub model: ModelVariant,
+    pub blur: BlurQuality,
+    /// Smooth the mask edges after upscaling, when turned on for the stream.
+    pub refine_mask: bool,
+    /// Send frames at half the camera resolution.
+    pub half_resolution: bool,
+}
+
+/// From full quality to the cheapest settings.
+pub const LEVELS: [QualityLevel; 5] = [
+    QualityLevel {
+        inference_interval: 1,
+        model: ModelVariant::Full,
+        blur: BlurQuality::Exact,
+        refine_mask: true,
+        half_resolution: false,
+    },
+    QualityLevel {
+        inference_interval: 1,
+        model: ModelVariant::Full,
+        blur: BlurQuality::Exact,
+        refine_mask: false,
+        half_resolution: false,
+    },
+    QualityLevel {
+        inference_interval: 2,
+        model: ModelVariant::Full,
+        blur: BlurQuality::Gaussian,
+        refine_mask: false,
+        half_resolution: false,
+    },
+    QualityLevel {
+        inference_interval: 2,
+        model: ModelVariant::Lite,
+        blur: BlurQuality::Box,
+        refine_mask: false,
+        half_resolution: false,
+    },
+    QualityLevel {
+        inference_interval: 3,
+        model: ModelVariant::Lite,
+        blur: BlurQuality::Box,
+        refine_mask: false,
+        half_resolution: true,
+    },
+];
+
+// Weight of the last frame in the average frame time.
+const SMOOTHING: f64 = 0.1;
+// Frames to wait after a change before stepping down again, so the average catches up.
+const STEP_DOWN_FRAMES: u32 = 15;
+// Stepping up waits longer, a wrong step up is more visible than a wrong step down.
+const STEP_UP_FRAMES: u32 = 60;
+// Step up only below this fraction of the frame budget, the next level costs more.
+const HEADROOM: f64 = 0.6;
+
+#[derive(Default)]
+pub struct QualityController {
+    level: usize,
+    average: Option<f64>,
+    frames_at_level: u32,
+}
+
+impl QualityController {
+    /// Index in `LEVELS`, 0 for full quality.
+    pub fn level(&self) -> usize {
+        self.level
+    }
+
+    pub fn settings(&self) -> QualityLevel {
+        LEVELS[self.level]
+    }
+
+    /// Back to full quality, when adaptation is turned off.
+    pub fn reset(&mut self) {
+        *self = QualityController::default();
+    }
+
+    /// Account for the processing time of a frame, returns true when the level changed.
+    pub fn update(&mut self, frame_time: Duration, target_fps: u32) -> bool {
+        let budget = 1.0 / target_fps.max(1) as f64;
+        let frame_time = frame_time.as_secs_f64();
+        let average = match self.average {
+            Some(average) => average + (frame_time - average) * SMOOTHING,
+            None => frame_time,
+        };
+        self.average = Some(average);
+        self.frames_at_level += 1;
+
+        let next = if average > budget
+            && self.frames_at_level >= STEP_DOWN_FRAMES
+            && self.level + 1 < LEVELS.len()
+        {
+            self.level + 1
+        } else if average < budget * HEADROOM
+            && self.frames_at_level >= STEP_UP_FRAMES
+            && self.level > 0
+        {
+            self.level - 1
+        } else {
+            return false;
+        };
+
+        self.level = next;
+        self.frames_at_level = 0;
+        // Start over from the budget, the previous average was measured at another level.
+        self.average = Some(budget * (1.0 + HEADROOM) / 2.0);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FPS: u32 = 30;
+
+    fn ms(ms: u64) -> Duration {
+        Duration::from_millis(ms)
+    }
+
+    // Feed `frames` frames of `frame_time`, returns the levels it went through.
+    fn run(quality: &mut QualityController, frame_time: Duration, frames: u32) -> Vec<usize> {
+        let mut levels = Vec::new();
+        for _ in 0..frames {
+            if quality.update(frame_time, FPS) {
+                levels.push(quality.level());
+            }
+        }
+        levels
+    }
+
+    #[test]
+    fn steps_down_when_over_budget() {
+        let mut quality = QualityController::default();
+        assert!(run(&mut quality, ms(50), STEP_DOWN_FRAMES - 1).is_empty());
+        assert_eq!(run(&mut quality, ms(50), 1), [1]);
+        assert_eq!(quality.settings(), LEVELS[1]);
+    }
+
+    #[test]
+    fn waits_between_steps_and_stops_at_the_cheapest_level() {
+        let mut quality = QualityController::default();
+        let levels = run(&mut quality, ms(100), STEP_DOWN_FRAMES * 10);
+        assert_eq!(levels, [1, 2, 3, 4]);
+        assert_eq!(quality.level(), LEVELS.len() - 1);
+    }
+
+    #[test]
+    fn steps_up_with_headroom() {
+        let mut quality = QualityController::default();
+        run(&mut quality, ms(100), STEP_DOWN_FRAMES);
+        assert_eq!(quality.level(), 1);
+
+        // 5 ms is well under 60% of the 33 ms budget, but stepping up takes longer.
+        assert!(run(&mut quality, ms(5), STEP_DOWN_FRAMES).is_empty());
+        assert_eq!(run(&mut quality, ms(5), STEP_UP_FRAMES), [0]);
+        // Never above full quality.
+        assert!(run(&mut quality, ms(5), STEP_UP_FRAMES * 2).is_empty());
+    }
+
+    #[test]
+    fn holds_between_headroom_and_budget() {
+        let mut quality = QualityController::default();
+        run(&mut quality, ms(100), STEP_DOWN_FRAMES);
+        assert_eq!(quality.level(), 1);
+        // 25 ms fits the budget without leaving enough headroom to step up.
+        assert!(run(&mut quality, ms(25), STEP_UP_FRAMES * 4).is_empty());
+        assert_eq!(quality.level(), 1);
+    }
+
+    #[test]
+    fn isolated_slow_frames_are_smoothed_out() {
+        let mut quality = QualityController::default();
+        for _ in 0..20 {
+            run(&mut quality, ms(10), 9);
+            run(&mut quality, ms(80), 1);
+        }
+        assert_eq!(quality.level(), 0);
+    }
+
+    #[test]
+    fn reset_goes_back_to_full_quality() {
+        let mut quality = QualityController::default();
+        run(&mut quality, ms(100), STEP_DOWN_FRAMES * 3);
+        assert!(quality.level() > 0);
+        quality.reset();
+        assert_eq!(quality.level(), 0);
+    }
+
+    #[test]
+    fn levels_get_cheaper() {
+        for pair in LEVELS.windows(2) {
+            let (better, cheaper) = (pair[0], pair[1]);
+            assert!(cheaper.inference_interval >= better.inference_interval);
+            assert!(better.refine_mask || !cheaper.refine_mask);
+            assert!(!better.half_resolution || cheaper.half_resolution);
+            assert_ne!(better, cheaper);
+        }
+    }
+}
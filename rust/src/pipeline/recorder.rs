@@ -0,0 +1,354 @@
+// Recording of processed frames to a video file.
+// Frames are copied to a bounded queue and written by a dedicated thread,
+// when the writer falls behind frames are dropped instead of slowing the stream.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, SyncSender, TrySendError};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::thread::{self, JoinHandle};
+use std::time::Instant;
+
+use crate::media::video::{self, ConstantRate, VideoFormat, VideoWriter};
+
+use super::pool::{FramePool, PooledFrame};
+
+// Gaps longer than this, like while the stream was idle, are cut out of the recording
+// instead of being filled with copies of the last frame.
+const MAX_GAP_US: u64 = 1_000_000;
+
+enum Message {
+    Frame {
+        pixels: PooledFrame,
+        width: u32,
+        height: u32,
+        // Since the start of the recording, on the monotonic clock.
+        time_us: u64,
+    },
+    Pause(u64),
+    Resume(u64),
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RecordingSummary {
+    /// Frames in the file, including the repeated ones filling in for skipped camera frames.
+    pub frames_written: u64,
+    /// Frames lost because the writer was behind, or because their size changed.
+    pub frames_dropped: u64,
+    pub duration_us: u64,
+}
+
+pub struct Recording {
+    started: Instant,
+    sender: Mutex<Option<SyncSender<Message>>>,
+    paused: AtomicBool,
+    dropped: Arc<AtomicU64>,
+    pool: Arc<FramePool>,
+    writer: Mutex<Option<JoinHandle<io::Result<RecordingSummary>>>>,
+}
+
+impl Recording {
+    /// Create the file and start the writer thread. `fps` is the rate of the file,
+    /// frames are placed on it from their capture timestamps.
+    pub fn start(
+        file: File,
+        format: VideoFormat,
+        fps: u32,
+        queue_depth: usize,
+    ) -> io::Result<Recording> {
+        let (sender, receiver) = mpsc::sync_channel(queue_depth.max(1));
+        let dropped = Arc::new(AtomicU64::new(0));
+        let writer_dropped = dropped.clone();
+        let writer = thread::Builder::new()
+            .name("recorder".into())
+            .spawn(move || write_frames(receiver, file, format, fps.max(1), writer_dropped))?;
+
+        Ok(Recording {
+            started: Instant::now(),
+            sender: Mutex::new(Some(sender)),
+            paused: AtomicBool::new(false),
+            dropped,
+            pool: FramePool::new(),
+            writer: Mutex::new(Some(writer)),
+        })
+    }
+
+    // Wall clock timestamps can jump, frames are placed from when they were captured instead.
+    fn time_us(&self, at: Instant) -> u64 {
+        at.saturating_duration_since(self.started).as_micros() as u64
+    }
+
+    /// Queue a copy of a processed RGBA frame, dropped if the queue is full.
+    pub fn push(&self, rgba_data: &[u8], width: u32, height: u32, captured_at: Instant) {
+        if self.paused.load(Ordering::Relaxed) {
+            return;
+        }
+        // Cloned so `stop` and `set_paused` don't wait on the copy.
+        let Some(sender) = self.sender.lock().unwrap().clone() else {
+            return;
+        };
+        let mut pixels = self.pool.get(rgba_data.len());
+        pixels.copy_from_slice(rgba_data);
+        let frame = Message::Frame {
+            pixels,
+            width,
+            height,
+            time_us: self.time_us(captured_at),
+        };
+        // A disconnected queue means the writer failed, `stop` reports why.
+        if let Err(TrySendError::Full(_)) = sender.try_send(frame) {
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// The paused time is left out of the recording.
+    pub fn set_paused(&self, paused: bool) {
+        if self.paused.swap(paused, Ordering::Relaxed) == paused {
+            return;
+        }
+        let time_us = self.time_us(Instant::now());
+        let message = if paused {
+            Message::Pause(time_us)
+        } else {
+            Message::Resume(time_us)
+        };
+        // Sent after releasing the lock, blocks if the queue is full but this is not on
+        // the frame path.
+        let sender = self.sender.lock().unwrap().clone();
+        if let Some(sender) = sender {
+            let _ = sender.send(message);
+        }
+    }
+
+    /// Write the queued frames and close the file.
+    pub fn stop(&self) -> io::Result<RecordingSummary> {
+        self.sender.lock().unwrap().take();
+        let writer =
+            self.writer.lock().unwrap().take().ok_or_else(|| {
+                io::Error::new(io::ErrorKind::NotFound, "Recording already stopped")
+            })?;
+        writer
+            .join()
+            .unwrap_or_else(|_| Err(io::Error::other("Recording thread panicked")))
+    }
+}
+
+fn write_frames(
+    receiver: Receiver<Message>,
+    file: File,
+    format: VideoFormat,
+    fps: u32,
+    dropped: Arc<AtomicU64>,
+) -> io::Result<RecordingSummary> {
+    let mut file = Some(file);
+    let mut writer: Option<(Box<dyn VideoWriter>, u32, u32)> = None;
+    let mut rate = ConstantRate::new(fps);
+    let frame_us = 1_000_000 / fps as u64;
+
+    // Time cut out of the recording, by pauses and long gaps.
+    let mut offset_us = 0;
+    let mut last_us = None;
+    let mut paused_at = None;
+
+    for message in receiver {
+        let (pixels, width, height, at_us) = match message {
+            Message::Frame {
+                pixels,
+                width,
+                height,
+                time_us,
+            } => (pixels, width, height, time_us),
+            Message::Pause(at) => {
+                paused_at = Some(at);
+                continue;
+            }
+            Message::Resume(at) => {
+                if let Some(paused_at) = paused_at.take() {
+                    offset_us += at.saturating_sub(paused_at);
+                }
+                continue;
+            }
+        };
+
+        let (video, video_width, video_height) = match &mut writer {
+            Some(writer) => writer,
+            // The file is sized by its first frame.
+            None => writer.insert((
+                video::create(format, file.take().unwrap(), width, height, fps)?,
+                width,
+                height,
+            )),
+        };
+        if (width, height) != (*video_width, *video_height) {
+            dropped.fetch_add(1, Ordering::Relaxed);
+            continue;
+        }
+
+        let mut time_us = at_us.saturating_sub(offset_us);
+        if let Some(last_us) = last_us.filter(|&last| time_us > last + MAX_GAP_US) {
+            offset_us += time_us - last_us - frame_us;
+            time_us = last_us + frame_us;
+        }
+        last_us = Some(time_us);
+
+        for _ in 0..rate.repeats(time_us) {
+            video.write_frame(&pixels)?;
+        }
+    }
+
+    if let Some((video, _, _)) = writer {
+        video.finish()?;
+    }
+    Ok(RecordingSummary {
+        frames_written: rate.frames(),
+        frames_dropped: dropped.load(Ordering::Relaxed),
+        duration_us: rate.duration_us(),
+    })
+}
+
+static RECORDINGS: OnceLock<Mutex<HashMap<u32, Arc<Recording>>>> = OnceLock::new();
+
+fn recordings() -> &'static Mutex<HashMap<u32, Arc<Recording>>> {
+    RECORDINGS.get_or_init(Default::default)
+}
+
+/// Recording of the streams on a camera, it keeps going if the stream is restarted.
+pub fn get(id: u32) -> Option<Arc<Recording>> {
+    recordings().lock().unwrap().get(&id).cloned()
+}
+
+/// Start a recording with `start` unless the camera is already recording, in which case
+/// `start` isn't called and its file is left alone.
+pub fn register_with(
+    id: u32,
+    start: impl FnOnce() -> io::Result<Recording>,
+) -> io::Result<Arc<Recording>> {
+    let mut recordings = recordings().lock().unwrap();
+    if recordings.contains_key(&id) {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            "Camera already recording",
+        ));
+    }
+    let recording = Arc::new(start()?);
+    recordings.insert(id, recording.clone());
+    Ok(recording)
+}
+
+pub fn remove(id: u32) -> Option<Arc<Recording>> {
+    recordings().lock().unwrap().remove(&id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::media::y4m::Y4mReader;
+
+    const FPS: u32 = 30;
+
+    fn frame(pool: &Arc<FramePool>, width: u32, time_ms: u64) -> Message {
+        Message::Frame {
+            pixels: pool.get((width * 2 * 4) as usize),
+            width,
+            height: 2,
+            time_us: time_ms * 1000,
+        }
+    }
+
+    // Write the messages to a Y4M file, returns the summary and the frames read back.
+    fn record(name: &str, messages: Vec<Message>) -> (RecordingSummary, u64) {
+        let path = std::env::temp_dir().join(format!("recorder-{}-{name}.y4m", std::process::id()));
+        let (sender, receiver) = mpsc::sync_channel(messages.len());
+        for message in messages {
+            sender.send(message).unwrap();
+        }
+        drop(sender);
+        let dropped = Arc::new(AtomicU64::new(0));
+        let file = File::create(&path).unwrap();
+        let summary = write_frames(receiver, file, VideoFormat::Y4m, FPS, dropped).unwrap();
+
+        let mut reader = Y4mReader::new(io::BufReader::new(File::open(&path).unwrap())).unwrap();
+        let mut pixels = Vec::new();
+        let mut frames = 0;
+        while reader.read_frame(&mut pixels).unwrap() {
+            frames += 1;
+        }
+        std::fs::remove_file(path).unwrap();
+        (summary, frames)
+    }
+
+    #[test]
+    fn skipped_frames_are_filled_in() {
+        let pool = FramePool::new();
+        let (summary, frames) = record(
+            "skipped",
+            vec![
+                frame(&pool, 4, 0),
+                frame(&pool, 4, 33),
+                frame(&pool, 4, 100),
+            ],
+        );
+        // The frame at 100 ms also covers the one expected at 67 ms.
+        assert_eq!(summary.frames_written, 4);
+        assert_eq!(frames, 4);
+        assert_eq!(summary.duration_us, 4 * 1_000_000 / FPS as u64);
+    }
+
+    #[test]
+    fn pauses_are_cut_out() {
+        let pool = FramePool::new();
+        let (summary, _) = record(
+            "paused",
+            vec![
+                frame(&pool, 4, 0),
+                Message::Pause(10),
+                Message::Resume(5_000_010),
+                frame(&pool, 4, 5_033),
+            ],
+        );
+        assert_eq!(summary.frames_written, 2);
+    }
+
+    #[test]
+    fn long_gaps_are_cut_out() {
+        let pool = FramePool::new();
+        let (summary, _) = record(
+            "gap",
+            vec![
+                frame(&pool, 4, 0),
+                frame(&pool, 4, 3_000),
+                frame(&pool, 4, 3_033),
+            ],
+        );
+        assert_eq!(summary.frames_written, 3);
+    }
+
+    #[test]
+    fn frames_of_another_size_are_dropped() {
+        let pool = FramePool::new();
+        let (summary, frames) = record(
+            "resized",
+            vec![frame(&pool, 4, 0), frame(&pool, 8, 33), frame(&pool, 4, 67)],
+        );
+        assert_eq!(summary.frames_dropped, 1);
+        assert_eq!(frames, 3);
+    }
+
+    #[test]
+    fn frames_are_timed_from_the_start() {
+        let file = File::create(
+            std::env::temp_dir().join(format!("recorder-{}-start.y4m", std::process::id())),
+        )
+        .unwrap();
+        let recording = Recording::start(file, VideoFormat::Y4m, FPS, 1).unwrap();
+        assert_eq!(recording.time_us(recording.started), 0);
+        // Frames captured before the recording started are not in the past of the file.
+        let before = recording.started - std::time::Duration::from_millis(5);
+        assert_eq!(recording.time_us(before), 0);
+        let later = recording.started + std::time::Duration::from_millis(40);
+        assert_eq!(recording.time_us(later), 40_000);
+        recording.stop().unwrap();
+    }
+}
@@ -0,0 +1,320 @@
+// Rolling buffer of the last seconds of processed frames, saved on demand.
+
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io;
+use std::path::Path;
+use std::sync::mpsc::{self, Receiver, SyncSender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use image::codecs::jpeg::JpegEncoder;
+use image::{ExtendedColorType, ImageEncoder, ImageResult};
+
+use crate::media::convert::rgba_to_rgb;
+use crate::media::still::{encode_rgba, StillFormat};
+use crate::media::video::{self, ConstantRate, VideoFormat};
+
+use super::pool::{FramePool, PooledFrame};
+
+// A few frames, compression keeps up unless the device is overloaded.
+const COMPRESS_QUEUE_DEPTH: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReplaySettings {
+    pub duration_us: u64,
+    /// Older frames are dropped past this, `usize::MAX` for no limit.
+    pub max_bytes: usize,
+    /// Keep frames as JPEG of this quality instead of RGBA, about ten times smaller.
+    pub jpeg_quality: Option<u8>,
+}
+
+pub enum ReplayPixels {
+    Rgba(Vec<u8>),
+    Jpeg(Vec<u8>),
+}
+
+impl ReplayPixels {
+    fn len(&self) -> usize {
+        match self {
+            ReplayPixels::Rgba(data) | ReplayPixels::Jpeg(data) => data.len(),
+        }
+    }
+
+    /// The frame as RGBA, decoding it if needed.
+    pub fn to_rgba(&self) -> ImageResult<Vec<u8>> {
+        match self {
+            ReplayPixels::Rgba(data) => Ok(data.clone()),
+            ReplayPixels::Jpeg(data) => Ok(image::load_from_memory_with_format(
+                data,
+                image::ImageFormat::Jpeg,
+            )?
+            .to_rgba8()
+            .into_raw()),
+        }
+    }
+}
+
+pub struct ReplayFrame {
+    pub pixels: ReplayPixels,
+    pub width: u32,
+    pub height: u32,
+    pub timestamp_us: u64,
+}
+
+struct Frames {
+    settings: ReplaySettings,
+    // Shared so saving only holds the lock to clone the list.
+    frames: VecDeque<Arc<ReplayFrame>>,
+    bytes: usize,
+}
+
+impl Frames {
+    fn insert(&mut self, frame: ReplayFrame) {
+        let timestamp_us = frame.timestamp_us;
+        self.bytes += frame.pixels.len();
+        self.frames.push_back(Arc::new(frame));
+
+        let oldest_us = timestamp_us.saturating_sub(self.settings.duration_us);
+        while let Some(front) = self.frames.front() {
+            let expired = front.timestamp_us < oldest_us;
+            if !expired && self.bytes <= self.settings.max_bytes {
+                break;
+            }
+            self.bytes -= front.pixels.len();
+            self.frames.pop_front();
+        }
+    }
+}
+
+struct Uncompressed {
+    pixels: PooledFrame,
+    width: u32,
+    height: u32,
+    timestamp_us: u64,
+}
+
+pub struct ReplayBuffer {
+    frames: Arc<Mutex<Frames>>,
+    // Frames waiting for the JPEG thread, compressing takes too long for the processing thread.
+    compressor: Option<SyncSender<Uncompressed>>,
+    pool: Arc<FramePool>,
+}
+
+impl ReplayBuffer {
+    pub fn new(settings: ReplaySettings) -> ReplayBuffer {
+        let frames = Arc::new(Mutex::new(Frames {
+            settings,
+            frames: VecDeque::new(),
+            bytes: 0,
+        }));
+        let compressor = settings.jpeg_quality.map(|quality| {
+            let (sender, receiver) = mpsc::sync_channel(COMPRESS_QUEUE_DEPTH);
+            let frames = frames.clone();
+            // Ends once the buffer is dropped.
+            thread::Builder::new()
+                .name("replay".into())
+                .spawn(move || compress_frames(receiver, frames, quality))
+                .expect("Can't start replay thread");
+            sender
+        });
+        ReplayBuffer {
+            frames,
+            compressor,
+            pool: FramePool::new(),
+        }
+    }
+
+    /// Keep a copy of a processed RGBA frame. With JPEG compression the frame is dropped
+    /// if the compression thread is behind.
+    pub fn push(&self, rgba_data: &[u8], width: u32, height: u32, timestamp_us: u64) {
+        let Some(compressor) = &self.compressor else {
+            self.frames.lock().unwrap().insert(ReplayFrame {
+                pixels: ReplayPixels::Rgba(rgba_data.to_vec()),
+                width,
+                height,
+                timestamp_us,
+            });
+            return;
+        };
+        let mut pixels = self.pool.get(rgba_data.len());
+        pixels.copy_from_slice(rgba_data);
+        let frame = Uncompressed {
+            pixels,
+            width,
+            height,
+            timestamp_us,
+        };
+        if compressor.try_send(frame).is_err() {
+            log::debug!("Replay compression behind, frame {timestamp_us} dropped");
+        }
+    }
+
+    /// Frames of the last `duration_us`, oldest first.
+    pub fn last(&self, duration_us: u64) -> Vec<Arc<ReplayFrame>> {
+        let frames = self.frames.lock().unwrap();
+        let Some(newest) = frames.frames.back() else {
+            return Vec::new();
+        };
+        let oldest_us = newest.timestamp_us.saturating_sub(duration_us);
+        frames
+            .frames
+            .iter()
+            .filter(|frame| frame.timestamp_us >= oldest_us)
+            .cloned()
+            .collect()
+    }
+}
+
+fn compress_frames(receiver: Receiver<Uncompressed>, frames: Arc<Mutex<Frames>>, quality: u8) {
+    let mut rgb = Vec::new();
+    for frame in receiver {
+        rgb.clear();
+        rgba_to_rgb(&frame.pixels, &mut rgb);
+        let mut jpeg = Vec::new();
+        let encoded = JpegEncoder::new_with_quality(&mut jpeg, quality.clamp(1, 100)).write_image(
+            &rgb,
+            frame.width,
+            frame.height,
+            ExtendedColorType::Rgb8,
+        );
+        if let Err(e) = encoded {
+            log::warn!("Can't keep frame {} for replay: {e}", frame.timestamp_us);
+            continue;
+        }
+        frames.lock().unwrap().insert(ReplayFrame {
+            pixels: ReplayPixels::Jpeg(jpeg),
+            width: frame.width,
+            height: frame.height,
+            timestamp_us: frame.timestamp_us,
+        });
+    }
+}
+
+/// Write frames as a video, frames of another size than the first are skipped.
+/// Returns the frame count of the file.
+pub fn save_video(
+    frames: &[Arc<ReplayFrame>],
+    file: File,
+    format: VideoFormat,
+    fps: u32,
+) -> io::Result<u64> {
+    let Some(first) = frames.first() else {
+        return Ok(0);
+    };
+    let mut video = video::create(format, file, first.width, first.height, fps)?;
+    let mut rate = ConstantRate::new(fps);
+    for frame in frames {
+        if (frame.width, frame.height) != (first.width, first.height) {
+            continue;
+        }
+        let rgba = frame.pixels.to_rgba().map_err(io::Error::other)?;
+        for _ in 0..rate.repeats(frame.timestamp_us) {
+            video.write_frame(&rgba)?;
+        }
+    }
+    video.finish()?;
+    Ok(rate.frames())
+}
+
+/// Write each frame to `directory`, named after its index and capture timestamp.
+pub fn save_images(
+    frames: &[Arc<ReplayFrame>],
+    directory: &Path,
+    format: StillFormat,
+) -> io::Result<u64> {
+    std::fs::create_dir_all(directory)?;
+    let extension = format.extension();
+    for (index, frame) in frames.iter().enumerate() {
+        let data = match (&frame.pixels, format) {
+            // Already compressed, no need to go through RGBA.
+            (ReplayPixels::Jpeg(jpeg), StillFormat::Jpeg { .. }) => jpeg.clone(),
+            (pixels, format) => {
+                let rgba = pixels.to_rgba().map_err(io::Error::other)?;
+                encode_rgba(&rgba, frame.width, frame.height, format).map_err(io::Error::other)?
+            }
+        };
+        let name = format!("{index:05}_{}.{extension}", frame.timestamp_us);
+        std::fs::write(directory.join(name), data)?;
+    }
+    Ok(frames.len() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::*;
+
+    fn settings(max_bytes: usize, jpeg_quality: Option<u8>) -> ReplaySettings {
+        ReplaySettings {
+            duration_us: 1_000_000,
+            max_bytes,
+            jpeg_quality,
+        }
+    }
+
+    fn timestamps(frames: &[Arc<ReplayFrame>]) -> Vec<u64> {
+        frames.iter().map(|frame| frame.timestamp_us).collect()
+    }
+
+    #[test]
+    fn keeps_the_last_seconds() {
+        let replay = ReplayBuffer::new(settings(usize::MAX, None));
+        for timestamp_us in (0..=2_000_000).step_by(500_000) {
+            replay.push(&[0; 16], 2, 2, timestamp_us);
+        }
+        assert_eq!(
+            timestamps(&replay.last(u64::MAX)),
+            [1_000_000, 1_500_000, 2_000_000]
+        );
+        assert_eq!(timestamps(&replay.last(500_000)), [1_500_000, 2_000_000]);
+    }
+
+    #[test]
+    fn drops_frames_over_the_memory_limit() {
+        let replay = ReplayBuffer::new(settings(32, None));
+        for timestamp_us in 0..4 {
+            replay.push(&[0; 16], 2, 2, timestamp_us);
+        }
+        assert_eq!(timestamps(&replay.last(u64::MAX)), [2, 3]);
+    }
+
+    #[test]
+    fn compresses_off_the_calling_thread() {
+        let replay = ReplayBuffer::new(settings(usize::MAX, Some(90)));
+        let rgba: Vec<u8> = [200, 100, 50, 255].repeat(16 * 16);
+        replay.push(&rgba, 16, 16, 0);
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let frames = loop {
+            let frames = replay.last(u64::MAX);
+            if !frames.is_empty() || Instant::now() > deadline {
+                break frames;
+            }
+            thread::sleep(Duration::from_millis(5));
+        };
+        let frame = &frames[0];
+        assert!(matches!(frame.pixels, ReplayPixels::Jpeg(_)));
+        let decoded = frame.pixels.to_rgba().unwrap();
+        assert_eq!(decoded.len(), rgba.len());
+        assert!(decoded.iter().zip(&rgba).all(|(&a, &b)| a.abs_diff(b) <= 8));
+    }
+
+    #[test]
+    fn saved_video_fills_in_skipped_frames() {
+        let frame = |timestamp_us| {
+            Arc::new(ReplayFrame {
+                pixels: ReplayPixels::Rgba(vec![0; 16]),
+                width: 2,
+                height: 2,
+                timestamp_us,
+            })
+        };
+        let path = std::env::temp_dir().join(format!("replay-{}.y4m", std::process::id()));
+        let frames = [frame(0), frame(100_000)];
+        let written = save_video(&frames, File::create(&path).unwrap(), VideoFormat::Y4m, 10);
+        std::fs::remove_file(path).unwrap();
+        assert_eq!(written.unwrap(), 2);
+    }
+}
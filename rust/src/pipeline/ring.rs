@@ -0,0 +1,208 @@
+// Frames shared with Dart without going through the bridge.
+// Dart reads each slot in place through its address. A slot is only written while free,
+// and only becomes free again once Dart releases it, so it is never written while read.
+// Rings are registered by generation and stay alive after their stream until Dart has
+// released every slot, so a slot received from an old stream can't point to freed memory.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicU8, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+
+use super::pool::PooledFrame;
+
+const FREE: u8 = 0;
+const WRITING: u8 = 1;
+const PUBLISHED: u8 = 2;
+
+struct Slot {
+    state: AtomicU8,
+    // Not resized while published, so the address handed to Dart stays valid.
+    data: Mutex<Vec<u8>>,
+}
+
+pub struct FrameRing {
+    generation: u64,
+    slots: Vec<Slot>,
+    next: AtomicUsize,
+    // Set once the stream stopped publishing.
+    retired: AtomicBool,
+}
+
+pub struct PublishedSlot {
+    pub generation: u64,
+    pub index: usize,
+    pub address: usize,
+    pub len: usize,
+}
+
+static NEXT_GENERATION: AtomicU64 = AtomicU64::new(1);
+static RINGS: OnceLock<Mutex<HashMap<u64, Arc<FrameRing>>>> = OnceLock::new();
+
+fn rings() -> &'static Mutex<HashMap<u64, Arc<FrameRing>>> {
+    RINGS.get_or_init(Default::default)
+}
+
+/// A new registered ring, see `FrameRing::retire`.
+pub fn create(slot_count: usize) -> Arc<FrameRing> {
+    let ring = Arc::new(FrameRing::new(slot_count));
+    rings()
+        .lock()
+        .unwrap()
+        .insert(ring.generation, ring.clone());
+    ring
+}
+
+pub fn get(generation: u64) -> Option<Arc<FrameRing>> {
+    rings().lock().unwrap().get(&generation).cloned()
+}
+
+impl FrameRing {
+    pub fn new(slot_count: usize) -> FrameRing {
+        FrameRing {
+            generation: NEXT_GENERATION.fetch_add(1, Ordering::Relaxed),
+            slots: (0..slot_count.max(1))
+                .map(|_| Slot {
+                    state: AtomicU8::new(FREE),
+                    data: Mutex::new(Vec::new()),
+                })
+                .collect(),
+            next: AtomicUsize::new(0),
+            retired: AtomicBool::new(false),
+        }
+    }
+
+    /// Move `frame` in a free slot and publish it, without copying. `frame` gets the
+    /// previous buffer of the slot, to go back to its pool.
+    /// Returns `None` when Dart holds every slot.
+    pub fn publish(&self, frame: &mut PooledFrame) -> Option<PublishedSlot> {
+        let count = self.slots.len();
+        let start = self.next.fetch_add(1, Ordering::Relaxed);
+        for i in 0..count {
+            let index = (start + i) % count;
+            let slot = &self.slots[index];
+            if slot
+                .state
+                .compare_exchange(FREE, WRITING, Ordering::Acquire, Ordering::Relaxed)
+                .is_err()
+            {
+                continue;
+            }
+
+            let mut data = slot.data.lock().unwrap();
+            std::mem::swap(&mut *data, frame.vec_mut());
+            let (address, len) = (data.as_ptr() as usize, data.len());
+            drop(data);
+
+            slot.state.store(PUBLISHED, Ordering::Release);
+            return Some(PublishedSlot {
+                generation: self.generation,
+                index,
+                address,
+                len,
+            });
+        }
+        None
+    }
+
+    /// Give a published slot back, Dart must not read it anymore.
+    pub fn release(&self, index: usize) {
+        if let Some(slot) = self.slots.get(index) {
+            let _ =
+                slot.state
+                    .compare_exchange(PUBLISHED, FREE, Ordering::AcqRel, Ordering::Relaxed);
+        }
+        self.unregister_if_unused();
+    }
+
+    /// Called once the stream stopped publishing, the ring is dropped with its last slot.
+    pub fn retire(&self) {
+        self.retired.store(true, Ordering::Release);
+        self.unregister_if_unused();
+    }
+
+    fn unregister_if_unused(&self) {
+        if !self.retired.load(Ordering::Acquire) {
+            return;
+        }
+        let published = self
+            .slots
+            .iter()
+            .any(|slot| slot.state.load(Ordering::Acquire) != FREE);
+        if !published {
+            rings().lock().unwrap().remove(&self.generation);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pipeline::pool::FramePool;
+
+    fn frame(pool: &Arc<FramePool>, value: u8) -> PooledFrame {
+        let mut frame = pool.get(16);
+        frame.fill(value);
+        frame
+    }
+
+    #[test]
+    fn publish_moves_the_frame() {
+        let pool = FramePool::new();
+        let ring = FrameRing::new(2);
+        let mut first = frame(&pool, 7);
+        let address = first.as_ptr() as usize;
+        let slot = ring.publish(&mut first).unwrap();
+        assert_eq!((slot.address, slot.len), (address, 16));
+        // The slot had no buffer yet.
+        assert!(first.is_empty());
+    }
+
+    #[test]
+    fn full_ring_drops_until_released() {
+        let pool = FramePool::new();
+        let ring = FrameRing::new(2);
+        let a = ring.publish(&mut frame(&pool, 1)).unwrap();
+        let b = ring.publish(&mut frame(&pool, 2)).unwrap();
+        assert_ne!(a.index, b.index);
+        assert!(ring.publish(&mut frame(&pool, 3)).is_none());
+
+        ring.release(a.index);
+        let c = ring.publish(&mut frame(&pool, 3)).unwrap();
+        assert_eq!(c.index, a.index);
+    }
+
+    #[test]
+    fn buffers_go_back_to_the_pool() {
+        let pool = FramePool::new();
+        let ring = FrameRing::new(2);
+        for _ in 0..10 {
+            let slot = ring.publish(&mut frame(&pool, 0)).unwrap();
+            ring.release(slot.index);
+            pool.frame_done();
+        }
+        // One buffer in each slot, one in flight.
+        assert_eq!(pool.stats().allocations, 3);
+        assert_eq!(pool.stats().last_frame_allocations, 0);
+    }
+
+    #[test]
+    fn retired_ring_lives_until_released() {
+        let pool = FramePool::new();
+        let ring = create(2);
+        let generation = ring.generation;
+        let slot = ring.publish(&mut frame(&pool, 5)).unwrap();
+        ring.retire();
+        drop(ring);
+
+        let ring = get(generation).expect("Ring kept while a slot is published");
+        let pixels = unsafe { std::slice::from_raw_parts(slot.address as *const u8, slot.len) };
+        assert!(pixels.iter().all(|&v| v == 5));
+        ring.release(slot.index);
+        assert!(get(generation).is_none());
+    }
+
+    #[test]
+    fn generations_are_unique() {
+        assert_ne!(FrameRing::new(1).generation, FrameRing::new(1).generation);
+    }
+}
@@ -0,0 +1,90 @@
+// Running streams, by camera id.
+// A session outlives its stream until the next stream on the same camera replaces it,
+// so its stats stay readable once the stream stopped.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+
+use super::metrics::PipelineMetrics;
+use super::pool::FramePool;
+use super::replay::ReplayBuffer;
+use super::snapshot::SnapshotRequest;
+use super::tap::FrameTaps;
+
+pub struct StreamSession {
+    pub running: AtomicBool,
+    pub metrics: PipelineMetrics,
+    /// Buffers shared by capture, processing and the sink.
+    pub pool: Arc<FramePool>,
+    /// Last seconds of processed frames, when enabled.
+    pub replay: Mutex<Option<ReplayBuffer>>,
+    pub taps: FrameTaps,
+    snapshots: Mutex<Vec<SnapshotRequest>>,
+    // Set until the capture thread takes a full resolution still.
+    still_requested: AtomicBool,
+}
+
+impl StreamSession {
+    pub fn new(pool: Arc<FramePool>) -> StreamSession {
+        StreamSession {
+            running: AtomicBool::new(true),
+            metrics: PipelineMetrics::default(),
+            pool,
+            replay: Mutex::new(None),
+            taps: FrameTaps::default(),
+            snapshots: Mutex::new(Vec::new()),
+            still_requested: AtomicBool::new(false),
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.running.load(Ordering::Relaxed)
+    }
+
+    pub fn stop(&self) {
+        self.running.store(false, Ordering::Relaxed);
+    }
+
+    pub fn request_snapshot(&self, request: SnapshotRequest) {
+        if request.full_resolution {
+            self.still_requested.store(true, Ordering::Relaxed);
+        }
+        self.snapshots.lock().unwrap().push(request);
+    }
+
+    /// Whether the capture thread should take a full resolution still, clears the request.
+    pub fn take_still_request(&self) -> bool {
+        self.still_requested.swap(false, Ordering::Relaxed)
+    }
+
+    /// Snapshot requests a frame can answer, full resolution ones only get stills.
+    pub fn take_snapshots(&self, still: bool) -> Vec<SnapshotRequest> {
+        let mut snapshots = self.snapshots.lock().unwrap();
+        if snapshots.is_empty() {
+            return Vec::new();
+        }
+        let (ready, waiting) = snapshots
+            .drain(..)
+            .partition(|request| still || !request.full_resolution);
+        *snapshots = waiting;
+        ready
+    }
+}
+
+static SESSIONS: OnceLock<Mutex<HashMap<u32, Arc<StreamSession>>>> = OnceLock::new();
+
+fn sessions() -> &'static Mutex<HashMap<u32, Arc<StreamSession>>> {
+    SESSIONS.get_or_init(Default::default)
+}
+
+/// Register the session of a new stream, stopping the previous one on the same camera.
+pub fn register(id: u32, session: Arc<StreamSession>) {
+    if let Some(previous) = sessions().lock().unwrap().insert(id, session) {
+        previous.stop();
+    }
+}
+
+pub fn get(id: u32) -> Option<Arc<StreamSession>> {
+    sessions().lock().unwrap().get(&id).cloned()
+}
@@ -0,0 +1,63 @@
+// Snapshots requested from Dart, taken by the processing thread of a stream.
+
+use std::sync::mpsc::Sender;
+
+use image::ImageResult;
+
+use crate::media::still::{encode_mask, encode_rgba, StillFormat};
+
+use super::processor::AppliedEffects;
+
+pub struct SnapshotRequest {
+    pub format: StillFormat,
+    pub raw: bool,
+    pub mask: bool,
+    /// Wait for a frame at the highest resolution of the camera.
+    pub full_resolution: bool,
+    pub reply: Sender<ImageResult<SnapshotImages>>,
+}
+
+/// Encoded images of a snapshot, `raw` and `mask` only when requested.
+pub struct SnapshotImages {
+    pub width: u32,
+    pub height: u32,
+    pub image: Vec<u8>,
+    pub raw: Option<Vec<u8>>,
+    pub mask: Option<Vec<u8>>,
+    pub effects: AppliedEffects,
+}
+
+/// A processed frame with what went into it.
+pub struct SnapshotFrame<'a> {
+    pub processed: &'a [u8],
+    pub raw: &'a [u8],
+    pub mask: Option<&'a [f32]>,
+    pub width: u32,
+    pub height: u32,
+    pub effects: AppliedEffects,
+}
+
+impl SnapshotRequest {
+    /// Encode the frame and send it to the waiting caller.
+    pub fn fulfill(self, frame: &SnapshotFrame) {
+        let (width, height) = (frame.width, frame.height);
+        let images = (|| {
+            Ok(SnapshotImages {
+                width,
+                height,
+                image: encode_rgba(frame.processed, width, height, self.format)?,
+                raw: self
+                    .raw
+                    .then(|| encode_rgba(frame.raw, width, height, self.format))
+                    .transpose()?,
+                mask: match frame.mask {
+                    Some(mask) if self.mask => Some(encode_mask(mask, width, height, self.format)?),
+                    _ => None,
+                },
+                effects: frame.effects,
+            })
+        })();
+        // The caller may have given up waiting.
+        let _ = self.reply.send(images);
+    }
+}
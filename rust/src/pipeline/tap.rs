@@ -0,0 +1,321 @@
+// Copies of the processed frames for consumers living outside the stream threads,
+// like clip exports. A slow consumer misses frames, it never holds the stream.
+
+use std::collections::VecDeque;
+use std::sync::mpsc::{self, Receiver, SyncSender, TrySendError};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::media::video::ConstantRate;
+use crate::ml::roi::MaskStats;
+
+/// A processed RGBA frame, before the output format conversion.
+pub struct TappedFrame {
+    pub pixels: Vec<u8>,
+    pub width: u32,
+    pub height: u32,
+    /// Microseconds since the Unix epoch.
+    pub timestamp_us: u64,
+    pub captured_at: Instant,
+    /// Computed when a tap or the history asked for it.
+    pub mask: Option<MaskStats>,
+}
+
+/// Which frames a tap receives, the others are not copied for it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pace {
+    Every,
+    /// The frames that fall on the slots of this frame rate, by capture time.
+    Fps(u32),
+    /// A frame, then none until this much later by capture time.
+    Interval(Duration),
+    /// One frame out of this many.
+    Frames(u32),
+}
+
+#[derive(Default)]
+struct PacerState {
+    // With `Pace::Fps`, and when it started.
+    rate: Option<(ConstantRate, Instant)>,
+    next: Option<Instant>,
+    frames_until_due: u32,
+}
+
+/// Picks the frames of a tap, shared with its consumer so it can ask for another one.
+pub struct Pacer {
+    pace: Pace,
+    state: Mutex<PacerState>,
+}
+
+impl Pacer {
+    pub fn new(pace: Pace) -> Arc<Pacer> {
+        Arc::new(Pacer {
+            pace,
+            state: Mutex::new(PacerState::default()),
+        })
+    }
+
+    fn take(&self, captured_at: Instant) -> bool {
+        let mut state = self.state.lock().unwrap();
+        match self.pace {
+            Pace::Every => true,
+            Pace::Fps(fps) => {
+                let (rate, start) = state
+                    .rate
+                    .get_or_insert_with(|| (ConstantRate::new(fps), captured_at));
+                let time_us = captured_at.saturating_duration_since(*start).as_micros() as u64;
+                rate.repeats(time_us) > 0
+            }
+            Pace::Interval(interval) => {
+                let due = state.next.is_none_or(|next| captured_at >= next);
+                if due {
+                    state.next = Some(captured_at + interval);
+                }
+                due
+            }
+            Pace::Frames(count) => {
+                if state.frames_until_due > 0 {
+                    state.frames_until_due -= 1;
+                    return false;
+                }
+                state.frames_until_due = count.saturating_sub(1);
+                true
+            }
+        }
+    }
+
+    /// The last frame taken was not used, take the next one whatever the interval.
+    /// Frame rates are kept.
+    pub fn retry(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.next = None;
+        state.frames_until_due = 0;
+    }
+}
+
+struct Tap {
+    sender: SyncSender<Arc<TappedFrame>>,
+    mask: bool,
+    pacer: Arc<Pacer>,
+    // Whether it wants the frame being fed.
+    due: bool,
+}
+
+#[derive(Default)]
+struct History {
+    length: usize,
+    frames: VecDeque<Arc<TappedFrame>>,
+}
+
+#[derive(Default)]
+pub struct FrameTaps {
+    taps: Mutex<Vec<Tap>>,
+    // The last frames, so a consumer can start before it was added. They come with mask stats.
+    history: Mutex<History>,
+}
+
+impl FrameTaps {
+    /// Receive the next frames picked by `pacer`, up to `depth` waiting. Dropping the
+    /// receiver removes the tap. With `mask`, frames come with mask stats even when no
+    /// effect needs a mask.
+    pub fn add(&self, depth: usize, mask: bool, pacer: Arc<Pacer>) -> Receiver<Arc<TappedFrame>> {
+        let (sender, receiver) = mpsc::sync_channel(depth.max(1));
+        self.taps.lock().unwrap().push(Tap {
+            sender,
+            mask,
+            pacer,
+            due: false,
+        });
+        receiver
+    }
+
+    /// Keep the last `length` frames, 0 to stop keeping them.
+    pub fn set_history(&self, length: usize) {
+        let mut history = self.history.lock().unwrap();
+        history.length = length;
+        let excess = history.frames.len().saturating_sub(length);
+        history.frames.drain(..excess);
+    }
+
+    /// Same as `add`, also returning up to `count` of the last frames, oldest first.
+    /// No frame is both in them and received.
+    pub fn add_with_history(
+        &self,
+        depth: usize,
+        mask: bool,
+        count: usize,
+    ) -> (Vec<Arc<TappedFrame>>, Receiver<Arc<TappedFrame>>) {
+        let mut taps = self.taps.lock().unwrap();
+        let history = self.history.lock().unwrap();
+        let skip = history.frames.len().saturating_sub(count);
+        let recent = history.frames.iter().skip(skip).cloned().collect();
+        let (sender, receiver) = mpsc::sync_channel(depth.max(1));
+        taps.push(Tap {
+            sender,
+            mask,
+            pacer: Pacer::new(Pace::Every),
+            due: false,
+        });
+        (recent, receiver)
+    }
+
+    /// Send a frame to the taps wanting it, copied once and only if there is one.
+    /// `mask` is only called when one of them asks for it.
+    pub fn feed(
+        &self,
+        rgba_data: &[u8],
+        width: u32,
+        height: u32,
+        timestamp_us: u64,
+        captured_at: Instant,
+        mask: impl FnOnce() -> MaskStats,
+    ) {
+        let mut taps = self.taps.lock().unwrap();
+        let mut history = self.history.lock().unwrap();
+        for tap in taps.iter_mut() {
+            tap.due = tap.pacer.take(captured_at);
+        }
+        if !taps.iter().any(|tap| tap.due) && history.length == 0 {
+            return;
+        }
+        let wants_mask = history.length > 0 || taps.iter().any(|tap| tap.due && tap.mask);
+        let frame = Arc::new(TappedFrame {
+            pixels: rgba_data.to_vec(),
+            width,
+            height,
+            timestamp_us,
+            captured_at,
+            mask: wants_mask.then(mask),
+        });
+        taps.retain(|tap| {
+            if !tap.due {
+                return true;
+            }
+            match tap.sender.try_send(frame.clone()) {
+                Ok(()) => true,
+                // Missed, so the next frame is taken instead.
+                Err(TrySendError::Full(_)) => {
+                    tap.pacer.retry();
+                    true
+                }
+                Err(TrySendError::Disconnected(_)) => false,
+            }
+        });
+        if history.length > 0 {
+            if history.frames.len() == history.length {
+                history.frames.pop_front();
+            }
+            history.frames.push_back(frame);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn feed(taps: &FrameTaps, at: Instant) {
+        taps.feed(&[0; 4], 1, 1, 0, at, MaskStats::default);
+    }
+
+    #[test]
+    fn paced_tap_only_gets_the_frames_of_its_rate() {
+        let taps = FrameTaps::default();
+        let every = taps.add(100, false, Pacer::new(Pace::Every));
+        let paced = taps.add(100, false, Pacer::new(Pace::Fps(10)));
+        let start = Instant::now();
+        // A second of 30 fps, both ends included.
+        for i in 0..=30 {
+            feed(&taps, start + Duration::from_micros(i * 33_333));
+        }
+        assert_eq!(every.try_iter().count(), 31);
+        assert_eq!(paced.try_iter().count(), 11);
+    }
+
+    #[test]
+    fn nothing_is_copied_without_a_due_tap() {
+        let taps = FrameTaps::default();
+        let _paced = taps.add(100, true, Pacer::new(Pace::Fps(1)));
+        let start = Instant::now();
+        feed(&taps, start);
+        let mut masked = false;
+        taps.feed(&[0; 4], 1, 1, 0, start + Duration::from_millis(100), || {
+            masked = true;
+            MaskStats::default()
+        });
+        assert!(!masked);
+    }
+
+    #[test]
+    fn mask_only_for_taps_asking_for_it() {
+        let taps = FrameTaps::default();
+        let plain = taps.add(1, false, Pacer::new(Pace::Every));
+        feed(&taps, Instant::now());
+        assert!(plain.recv().unwrap().mask.is_none());
+
+        let masked = taps.add(1, true, Pacer::new(Pace::Every));
+        feed(&taps, Instant::now());
+        assert!(masked.recv().unwrap().mask.is_some());
+    }
+
+    #[test]
+    fn dropped_receivers_are_removed() {
+        let taps = FrameTaps::default();
+        drop(taps.add(1, false, Pacer::new(Pace::Every)));
+        feed(&taps, Instant::now());
+        assert!(taps.taps.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn history_comes_before_the_next_frames() {
+        let taps = FrameTaps::default();
+        taps.set_history(2);
+        let start = Instant::now();
+        for i in 0..3 {
+            taps.feed(&[0; 4], 1, 1, i, start, MaskStats::default);
+        }
+        let (recent, next) = taps.add_with_history(1, true, 5);
+        taps.feed(&[0; 4], 1, 1, 3, start, MaskStats::default);
+        let timestamps: Vec<u64> = recent.iter().map(|frame| frame.timestamp_us).collect();
+        assert_eq!(timestamps, [1, 2]);
+        assert!(recent.iter().all(|frame| frame.mask.is_some()));
+        assert_eq!(next.recv().unwrap().timestamp_us, 3);
+    }
+
+    #[test]
+    fn interval_is_on_capture_time() {
+        let pacer = Pacer::new(Pace::Interval(Duration::from_millis(100)));
+        let start = Instant::now();
+        let taken: Vec<u64> = (0..10)
+            .map(|i| i * 40)
+            .filter(|&ms| pacer.take(start + Duration::from_millis(ms)))
+            .collect();
+        assert_eq!(taken, [0, 120, 240, 360]);
+    }
+
+    #[test]
+    fn retry_takes_the_next_frame() {
+        let pacer = Pacer::new(Pace::Frames(3));
+        let start = Instant::now();
+        let taken: Vec<bool> = (0..4).map(|_| pacer.take(start)).collect();
+        assert_eq!(taken, [true, false, false, true]);
+        pacer.retry();
+        assert!(pacer.take(start));
+        assert!(!pacer.take(start));
+    }
+
+    #[test]
+    fn full_tap_takes_the_next_frame() {
+        let taps = FrameTaps::default();
+        let hour = Duration::from_secs(3600);
+        let frames = taps.add(1, false, Pacer::new(Pace::Interval(hour)));
+        let start = Instant::now();
+        taps.feed(&[0; 4], 1, 1, 0, start, MaskStats::default);
+        // Due but the tap is full.
+        taps.feed(&[0; 4], 1, 1, 1, start + hour, MaskStats::default);
+        assert_eq!(frames.recv().unwrap().timestamp_us, 0);
+        let later = start + hour + Duration::from_millis(1);
+        taps.feed(&[0; 4], 1, 1, 2, later, MaskStats::default);
+        assert_eq!(frames.try_recv().unwrap().timestamp_us, 2);
+    }
+}
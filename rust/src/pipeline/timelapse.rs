@@ -0,0 +1,376 @@
+// Time-lapse capture: one processed frame saved every interval to a directory,
+// with a JSON Lines manifest appended after each frame so it is valid even if the app is killed.
+// The tap pacer picks the frames, so the others are neither copied nor segmented.
+
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{Receiver, RecvTimeoutError};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::media::still::{encode_rgba, StillFormat};
+
+use super::frame::unix_micros;
+use super::tap::{Pace, Pacer, TappedFrame};
+
+const MANIFEST: &str = "manifest.jsonl";
+// How often the thread checks whether it was stopped while no frame comes.
+const POLL: Duration = Duration::from_millis(250);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Interval {
+    /// By capture time, in milliseconds.
+    Milliseconds(u64),
+    /// By stream frames.
+    Frames(u32),
+}
+
+impl Interval {
+    /// For the tap feeding the time-lapse.
+    pub fn pace(self) -> Pace {
+        match self {
+            Interval::Milliseconds(ms) => Pace::Interval(Duration::from_millis(ms)),
+            Interval::Frames(count) => Pace::Frames(count),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TimelapseSettings {
+    pub directory: PathBuf,
+    pub interval: Interval,
+    pub format: StillFormat,
+    /// Frames without a person are skipped and don't count toward the interval.
+    pub require_person: bool,
+    /// Minimum fraction of the frame covered by the person for it to count as present.
+    pub min_coverage: f32,
+    pub max_frames: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TimelapseSummary {
+    pub frames_saved: u32,
+    /// Frames due to be saved but skipped because nobody was in them.
+    pub frames_without_person: u64,
+}
+
+// First line of the manifest, followed by one `ManifestFrame` line per frame.
+// A time-lapse restarted in the same directory appends another header and its frames.
+#[derive(Serialize)]
+struct ManifestHeader {
+    camera: u32,
+    started_us: u64,
+    interval: Interval,
+    format: &'static str,
+    require_person: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ManifestFrame {
+    index: u32,
+    file: String,
+    timestamp_us: u64,
+    width: u32,
+    height: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    person_coverage: Option<f32>,
+}
+
+pub struct Timelapse {
+    stop: Arc<AtomicBool>,
+    thread: Mutex<Option<JoinHandle<io::Result<TimelapseSummary>>>>,
+}
+
+impl Timelapse {
+    /// Create the directory and save frames from the tap until stopped,
+    /// the stream ends or `max_frames` are saved. Frames already in the directory
+    /// are kept, the new ones are numbered after them. The tap uses `pacer`, made
+    /// from `settings.interval.pace()`, and needs mask stats with `require_person`.
+    pub fn start(
+        camera: u32,
+        frames: Receiver<Arc<TappedFrame>>,
+        pacer: Arc<Pacer>,
+        settings: TimelapseSettings,
+    ) -> io::Result<Timelapse> {
+        std::fs::create_dir_all(&settings.directory)?;
+        let (manifest, first_index) = open_manifest(&settings.directory.join(MANIFEST))?;
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = stop.clone();
+        let thread = thread::Builder::new()
+            .name("timelapse".into())
+            .spawn(move || {
+                save_frames(
+                    camera,
+                    frames,
+                    &pacer,
+                    manifest,
+                    first_index,
+                    settings,
+                    &thread_stop,
+                )
+            })?;
+        Ok(Timelapse {
+            stop,
+            thread: Mutex::new(Some(thread)),
+        })
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.thread
+            .lock()
+            .unwrap()
+            .as_ref()
+            .is_none_or(|thread| thread.is_finished())
+    }
+
+    /// Stop saving frames and wait for the last one to be written.
+    pub fn stop(&self) -> io::Result<TimelapseSummary> {
+        self.stop.store(true, Ordering::Relaxed);
+        let Some(thread) = self.thread.lock().unwrap().take() else {
+            return Err(io::Error::other("Time-lapse already stopped"));
+        };
+        thread
+            .join()
+            .map_err(|_| io::Error::other("Time-lapse thread panicked"))?
+    }
+}
+
+fn save_frames(
+    camera: u32,
+    frames: Receiver<Arc<TappedFrame>>,
+    pacer: &Pacer,
+    mut manifest: File,
+    first_index: u32,
+    settings: TimelapseSettings,
+    stop: &AtomicBool,
+) -> io::Result<TimelapseSummary> {
+    let mut summary = TimelapseSummary::default();
+    let header = ManifestHeader {
+        camera,
+        started_us: unix_micros(),
+        interval: settings.interval,
+        format: settings.format.extension(),
+        require_person: settings.require_person,
+    };
+    append_line(&mut manifest, &header)?;
+
+    while !stop.load(Ordering::Relaxed) {
+        if settings
+            .max_frames
+            .is_some_and(|max| summary.frames_saved >= max)
+        {
+            break;
+        }
+        let frame = match frames.recv_timeout(POLL) {
+            Ok(frame) => frame,
+            Err(RecvTimeoutError::Timeout) => continue,
+            Err(RecvTimeoutError::Disconnected) => break,
+        };
+
+        // From the mask of the stream, a frame without one has no person found in it.
+        let person_coverage = settings
+            .require_person
+            .then(|| frame.mask.map_or(0.0, |mask| mask.coverage));
+        if person_coverage.is_some_and(|coverage| coverage < settings.min_coverage) {
+            // Doesn't count toward the interval, the next frame is due.
+            summary.frames_without_person += 1;
+            pacer.retry();
+            continue;
+        }
+
+        let index = first_index + summary.frames_saved;
+        let file = format!("{index:06}.{}", settings.format.extension());
+        let data = encode_rgba(&frame.pixels, frame.width, frame.height, settings.format)
+            .map_err(io::Error::other)?;
+        std::fs::write(settings.directory.join(&file), data)?;
+        let entry = ManifestFrame {
+            index,
+            file,
+            timestamp_us: frame.timestamp_us,
+            width: frame.width,
+            height: frame.height,
+            person_coverage,
+        };
+        append_line(&mut manifest, &entry)?;
+        summary.frames_saved += 1;
+    }
+    log::info!(
+        "Camera {camera} time-lapse ended, {} frames in {}",
+        summary.frames_saved,
+        settings.directory.display()
+    );
+    Ok(summary)
+}
+
+// Open the manifest for appending, with the index following the frames it already lists.
+fn open_manifest(path: &Path) -> io::Result<(File, u32)> {
+    let existing = match std::fs::read(path) {
+        Ok(existing) => existing,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Vec::new(),
+        Err(e) => return Err(e),
+    };
+    // Headers don't parse as frames, and neither does a line cut by a killed app.
+    let next_index = existing
+        .split(|&b| b == b'\n')
+        .filter_map(|line| serde_json::from_slice::<ManifestFrame>(line).ok())
+        .map(|frame| frame.index + 1)
+        .max()
+        .unwrap_or(0);
+    let mut manifest = OpenOptions::new().append(true).create(true).open(path)?;
+    if existing.last().is_some_and(|&b| b != b'\n') {
+        // Ends the cut line, so the next header starts on its own.
+        manifest.write_all(b"\n")?;
+    }
+    Ok((manifest, next_index))
+}
+
+// In a single write, a killed app leaves at most an incomplete last line.
+fn append_line(manifest: &mut File, value: &impl Serialize) -> io::Result<()> {
+    let mut line = serde_json::to_vec(value).map_err(io::Error::other)?;
+    line.push(b'\n');
+    manifest.write_all(&line)
+}
+
+static TIMELAPSES: OnceLock<Mutex<HashMap<u32, Arc<Timelapse>>>> = OnceLock::new();
+
+fn timelapses() -> &'static Mutex<HashMap<u32, Arc<Timelapse>>> {
+    TIMELAPSES.get_or_init(Default::default)
+}
+
+/// Start a time-lapse with `start` unless the camera already has one running, in which
+/// case `start` isn't called and its directory is left alone. One that ended by itself
+/// is replaced.
+pub fn register_with(
+    id: u32,
+    start: impl FnOnce() -> io::Result<Timelapse>,
+) -> io::Result<Arc<Timelapse>> {
+    let mut timelapses = timelapses().lock().unwrap();
+    if timelapses.get(&id).is_some_and(|t| !t.is_finished()) {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            "Camera already has a time-lapse",
+        ));
+    }
+    let timelapse = Arc::new(start()?);
+    timelapses.insert(id, timelapse.clone());
+    Ok(timelapse)
+}
+
+pub fn remove(id: u32) -> Option<Arc<Timelapse>> {
+    timelapses().lock().unwrap().remove(&id)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+    use std::time::Instant;
+
+    use serde_json::Value;
+
+    use super::*;
+    use crate::ml::roi::MaskStats;
+
+    fn frame(timestamp_us: u64, coverage: f32) -> Arc<TappedFrame> {
+        Arc::new(TappedFrame {
+            pixels: [0, 0, 0, 255].repeat(4),
+            width: 2,
+            height: 2,
+            timestamp_us,
+            captured_at: Instant::now(),
+            mask: Some(MaskStats {
+                coverage,
+                confidence: 1.0,
+            }),
+        })
+    }
+
+    // Feed the frames to `save_frames` until they run out.
+    fn run(
+        name: &str,
+        require_person: bool,
+        frames: Vec<Arc<TappedFrame>>,
+    ) -> (TimelapseSummary, Vec<Value>, PathBuf) {
+        let directory =
+            std::env::temp_dir().join(format!("timelapse-{}-{name}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let (sender, receiver) = mpsc::channel();
+        for frame in frames {
+            sender.send(frame).unwrap();
+        }
+        drop(sender);
+        let settings = TimelapseSettings {
+            directory: directory.clone(),
+            interval: Interval::Frames(1),
+            format: StillFormat::Png,
+            require_person,
+            min_coverage: 0.5,
+            max_frames: Some(3),
+        };
+        let pacer = Pacer::new(settings.interval.pace());
+        let (manifest, first_index) = open_manifest(&directory.join(MANIFEST)).unwrap();
+        let stop = AtomicBool::new(false);
+        let summary =
+            save_frames(7, receiver, &pacer, manifest, first_index, settings, &stop).unwrap();
+        let lines = std::fs::read_to_string(directory.join(MANIFEST))
+            .unwrap()
+            .lines()
+            // Leaves out a line cut by a killed app.
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect();
+        (summary, lines, directory)
+    }
+
+    #[test]
+    fn manifest_has_a_line_per_frame() {
+        let frames = (0..5).map(|i| frame(i, 0.0)).collect();
+        let (summary, lines, directory) = run("manifest", false, frames);
+        assert_eq!(summary.frames_saved, 3);
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0]["camera"], 7);
+        assert_eq!(lines[0]["format"], "png");
+        assert_eq!(lines[3]["file"], "000002.png");
+        assert_eq!(lines[3]["timestamp_us"], 2);
+        assert!(lines[3].get("person_coverage").is_none());
+        assert!(directory.join("000002.png").exists());
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn frames_without_person_are_skipped() {
+        let frames = vec![frame(0, 1.0), frame(1, 0.0), frame(2, 0.0), frame(3, 0.8)];
+        let (summary, lines, directory) = run("person", true, frames);
+        assert_eq!(summary.frames_saved, 2);
+        assert_eq!(summary.frames_without_person, 2);
+        let timestamps: Vec<&Value> = lines[1..].iter().map(|l| &l["timestamp_us"]).collect();
+        assert_eq!(timestamps, [0, 3]);
+        assert_eq!(lines[2]["index"], 1);
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn restarts_continue_the_numbering() {
+        let frames = || (0..2).map(|i| frame(i, 0.0)).collect();
+        let (_, _, directory) = run("restart", false, frames());
+        // As left by a killed app.
+        let mut manifest = OpenOptions::new()
+            .append(true)
+            .open(directory.join(MANIFEST))
+            .unwrap();
+        manifest.write_all(b"{\"index\":").unwrap();
+
+        let (summary, lines, directory) = run("restart", false, frames());
+        assert_eq!(summary.frames_saved, 2);
+        let indices: Vec<&Value> = lines.iter().filter_map(|l| l.get("index")).collect();
+        assert_eq!(indices, [0, 1, 2, 3]);
+        assert_eq!(lines[5]["file"], "000003.png");
+        assert!(directory.join("000000.png").exists());
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
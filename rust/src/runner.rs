@@ -0,0 +1,462 @@
+// Offline runs of the stream processing, for the `run_pipeline` command line tool.
+// Frames from a Y4M file or an image sequence go through the same `FrameProcessor` as
+// `stream_camera`, so a recorded clip gives the masks and effects the app would have shown.
+// Session archives also bring the settings and processing state of each frame, and replay
+// exactly what the stream did.
+
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Instant;
+
+use serde::Serialize;
+
+use crate::batch::find_inputs;
+use crate::media::still::{encode_rgba, open_oriented, StillFormat};
+use crate::media::video::{self, VideoFormat, VideoWriter};
+use crate::media::y4m::Y4mReader;
+use crate::ml::roi::mask_stats;
+use crate::pipeline::archive::{ArchiveEvent, ArchiveReader, FrameState};
+use crate::pipeline::pool::FramePool;
+use crate::pipeline::processor::{EffectSettings, FrameProcessor};
+use crate::pipeline::quality::LEVELS;
+
+// Frame rate of image sequences, and of Y4M files that don't give one.
+const DEFAULT_FPS: u32 = 30;
+
+/// Ignored when replaying a session archive, it has its own.
+#[derive(Debug, Clone, Default)]
+pub struct RunSettings {
+    pub blur: bool,
+    pub background: Option<PathBuf>,
+    /// Show the mask over the frames instead of the other effects.
+    pub debug: bool,
+    pub roi: bool,
+    /// Smooth the mask edges.
+    pub refine: bool,
+    /// Average the mask over the last frames.
+    pub temporal: bool,
+    /// Index in the adaptive quality levels, 0 is full quality.
+    pub quality_level: usize,
+    /// Like the low power mode, reuse the mask while the scene doesn't move and run the
+    /// lite model when there is one.
+    pub low_power: bool,
+    /// Overrides the frame rate of the input, 0 to keep it.
+    pub fps: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FrameReport {
+    pub index: u64,
+    /// Capture sequence number of archived frames.
+    pub sequence: Option<u64>,
+    pub timestamp_us: u64,
+    pub width: u32,
+    pub height: u32,
+    /// Fraction of the frame covered by the person, when an effect used the mask.
+    pub coverage: Option<f32>,
+    /// Fraction of the mask close to 0 or 1.
+    pub confidence: Option<f32>,
+    /// Whether the effects ran the segmentation on this frame rather than reusing the last
+    /// mask, always false without effects.
+    pub inference: bool,
+    pub process_ms: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RunReport {
+    /// Camera of a session archive.
+    pub camera: Option<u32>,
+    pub fps: u32,
+    pub total_ms: u64,
+    pub frames: Vec<FrameReport>,
+}
+
+enum Source {
+    Y4m(Y4mReader<BufReader<File>>),
+    Images(std::vec::IntoIter<PathBuf>),
+    Archive {
+        reader: ArchiveReader<BufReader<File>>,
+        settings: EffectSettings,
+        reset: bool,
+    },
+}
+
+struct SourceFrame {
+    width: u32,
+    height: u32,
+    replayed: Option<Replayed>,
+}
+
+// How an archived frame was processed by the stream.
+struct Replayed {
+    state: FrameState,
+    settings: EffectSettings,
+    // The processor was reset before this frame.
+    reset: bool,
+}
+
+impl Source {
+    fn open(input: &str) -> io::Result<Source> {
+        if input.ends_with(".camsession") {
+            let file = BufReader::new(File::open(input)?);
+            return Ok(Source::Archive {
+                reader: ArchiveReader::new(file)?,
+                settings: EffectSettings::default(),
+                reset: false,
+            });
+        }
+        if input.ends_with(".y4m") {
+            let file = BufReader::new(File::open(input)?);
+            return Ok(Source::Y4m(Y4mReader::new(file)?));
+        }
+        let images = find_inputs(input)?;
+        if images.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("No image found for {input}"),
+            ));
+        }
+        Ok(Source::Images(images.into_iter()))
+    }
+
+    fn fps(&self) -> Option<u32> {
+        match self {
+            Source::Y4m(reader) => match reader.fps() {
+                (num, den) if num > 0 && den > 0 => Some((num + den / 2) / den),
+                _ => None,
+            },
+            Source::Images(_) | Source::Archive { .. } => None,
+        }
+    }
+
+    fn camera(&self) -> Option<u32> {
+        match self {
+            Source::Archive { reader, .. } => Some(reader.header().camera),
+            _ => None,
+        }
+    }
+
+    /// Read the next RGBA frame into `out`, `None` at the end.
+    fn next_frame(&mut self, out: &mut Vec<u8>) -> io::Result<Option<SourceFrame>> {
+        match self {
+            Source::Y4m(reader) => Ok(reader.read_frame(out)?.then(|| SourceFrame {
+                width: reader.width(),
+                height: reader.height(),
+                replayed: None,
+            })),
+            Source::Images(paths) => {
+                let Some(path) = paths.next() else {
+                    return Ok(None);
+                };
+                let image = open_oriented(&path)
+                    .map_err(|e| {
+                        io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            format!("{}: {e}", path.display()),
+                        )
+                    })?
+                    .to_rgba8();
+                let (width, height) = image.dimensions();
+                *out = image.into_raw();
+                Ok(Some(SourceFrame {
+                    width,
+                    height,
+                    replayed: None,
+                }))
+            }
+            Source::Archive {
+                reader,
+                settings,
+                reset,
+            } => loop {
+                match reader.next_event()? {
+                    None => return Ok(None),
+                    Some(ArchiveEvent::Reset) => *reset = true,
+                    Some(ArchiveEvent::Settings(changed)) => *settings = changed,
+                    Some(ArchiveEvent::Frame {
+                        state,
+                        pixels,
+                        width,
+                        height,
+                    }) => {
+                        *out = pixels;
+                        return Ok(Some(SourceFrame {
+                            width,
+                            height,
+                            replayed: Some(Replayed {
+                                state,
+                                settings: settings.clone(),
+                                reset: std::mem::take(reset),
+                            }),
+                        }));
+                    }
+                }
+            },
+        }
+    }
+}
+
+enum Sink {
+    Y4m {
+        file: Option<File>,
+        writer: Option<(Box<dyn VideoWriter>, u32, u32)>,
+    },
+    Png(PathBuf),
+}
+
+impl Sink {
+    /// A `.y4m` file, or a directory of PNG files otherwise.
+    fn create(output: &Path) -> io::Result<Sink> {
+        if output
+            .extension()
+            .is_some_and(|extension| extension == "y4m")
+        {
+            return Ok(Sink::Y4m {
+                file: Some(File::create(output)?),
+                writer: None,
+            });
+        }
+        std::fs::create_dir_all(output)?;
+        Ok(Sink::Png(output.to_path_buf()))
+    }
+
+    fn write(
+        &mut self,
+        index: u64,
+        rgba_data: &[u8],
+        width: u32,
+        height: u32,
+        fps: u32,
+    ) -> io::Result<()> {
+        match self {
+            Sink::Y4m { file, writer } => {
+                if writer.is_none() {
+                    let file = file.take().expect("Y4M file kept until the first frame");
+                    let created = video::create(VideoFormat::Y4m, file, width, height, fps)?;
+                    *writer = Some((created, width, height));
+                }
+                let (writer, w, h) = writer.as_mut().unwrap();
+                if (*w, *h) != (width, height) {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("Frame {index} is {width}x{height}, the video is {w}x{h}"),
+                    ));
+                }
+                writer.write_frame(rgba_data)
+            }
+            Sink::Png(directory) => {
+                let data = encode_rgba(rgba_data, width, height, StillFormat::Png)
+                    .map_err(io::Error::other)?;
+                std::fs::write(directory.join(format!("{index:06}.png")), data)
+            }
+        }
+    }
+
+    fn finish(self) -> io::Result<()> {
+        match self {
+            Sink::Y4m {
+                writer: Some((writer, _, _)),
+                ..
+            } => writer.finish(),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Process every frame of `input`, a `.y4m` file, a `.camsession` archive, an image directory
+/// or a glob, into `output`, a `.y4m` file or a directory of PNG files.
+/// `progress` is called with the number of frames done.
+pub fn run(
+    input: &str,
+    output: &Path,
+    settings: &RunSettings,
+    mut progress: impl FnMut(u64),
+) -> io::Result<RunReport> {
+    let mut source = Source::open(input)?;
+    let mut sink = Sink::create(output)?;
+    let fps = match settings.fps {
+        0 => source.fps().unwrap_or(DEFAULT_FPS),
+        fps => fps,
+    };
+    let background = match &settings.background {
+        Some(path) => Some(Arc::new(
+            open_oriented(path)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?
+                .to_rgba8(),
+        )),
+        None => None,
+    };
+    let effects = EffectSettings {
+        blur: settings.blur,
+        background,
+        debug: settings.debug,
+        roi: settings.roi,
+        refine: settings.refine,
+        temporal: settings.temporal,
+    };
+
+    let pool = FramePool::new();
+    let mut processor = FrameProcessor::new(pool.clone());
+    processor.set_quality(LEVELS[settings.quality_level.min(LEVELS.len() - 1)]);
+    processor.set_low_power(settings.low_power);
+
+    let start = Instant::now();
+    let mut frames = Vec::new();
+    let mut pixels = Vec::new();
+    while let Some(source_frame) = source.next_frame(&mut pixels)? {
+        let SourceFrame {
+            width,
+            height,
+            replayed,
+        } = source_frame;
+        let index = frames.len() as u64;
+        let mut frame = pool.get(pixels.len());
+        frame.copy_from_slice(&pixels);
+
+        let frame_start = Instant::now();
+        let settings = match &replayed {
+            Some(replayed) => {
+                if replayed.reset {
+                    processor.reset();
+                }
+                let level = (replayed.state.quality_level as usize).min(LEVELS.len() - 1);
+                processor.set_quality(LEVELS[level]);
+                processor.set_low_power(replayed.state.low_power);
+                &replayed.settings
+            }
+            None => &effects,
+        };
+        let (output, _) = processor.process(frame, width, height, settings);
+        let inference = processor.timings().mask.is_some();
+        // The stream computed the mask for a snapshot or a tap, later frames may reuse it.
+        if replayed.as_ref().is_some_and(|r| r.state.extra_mask) {
+            processor.mask(&output, width, height);
+        }
+        let stats = processor.last_mask(width, height).map(mask_stats);
+        let (output, out_width, out_height) = processor.scale(output, width, height);
+        let process_ms = frame_start.elapsed().as_secs_f64() * 1000.0;
+
+        sink.write(index, &output, out_width, out_height, fps)?;
+        frames.push(FrameReport {
+            index,
+            sequence: replayed.as_ref().map(|r| r.state.sequence),
+            timestamp_us: match &replayed {
+                Some(replayed) => replayed.state.timestamp_us,
+                None => index * 1_000_000 / fps as u64,
+            },
+            width: out_width,
+            height: out_height,
+            coverage: stats.map(|stats| stats.coverage),
+            confidence: stats.map(|stats| stats.confidence),
+            inference,
+            process_ms,
+        });
+        progress(index + 1);
+    }
+    sink.finish()?;
+
+    Ok(RunReport {
+        camera: source.camera(),
+        fps,
+        total_ms: start.elapsed().as_millis() as u64,
+        frames,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("runner-{}-{name}", std::process::id()))
+    }
+
+    fn write_y4m(path: &Path, header: &str, frames: usize) {
+        let mut data = format!("YUV4MPEG2 {header}\n").into_bytes();
+        for _ in 0..frames {
+            data.extend_from_slice(b"FRAME\n");
+            // 4x2 luma and two 2x1 chroma planes.
+            data.extend_from_slice(&[128; 8 + 2 * 2]);
+        }
+        std::fs::write(path, data).unwrap();
+    }
+
+    #[test]
+    fn y4m_source_gives_its_frame_rate() {
+        let path = temp_path("rate.y4m");
+        write_y4m(&path, "W4 H2 F30000:1001", 2);
+        let mut source = Source::open(path.to_str().unwrap()).unwrap();
+        assert_eq!(source.fps(), Some(30));
+        assert_eq!(source.camera(), None);
+
+        let mut pixels = Vec::new();
+        let mut frames = 0;
+        while let Some(frame) = source.next_frame(&mut pixels).unwrap() {
+            assert_eq!((frame.width, frame.height), (4, 2));
+            assert!(frame.replayed.is_none());
+            frames += 1;
+        }
+        assert_eq!(frames, 2);
+
+        write_y4m(&path, "W4 H2", 1);
+        assert_eq!(Source::open(path.to_str().unwrap()).unwrap().fps(), None);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn image_source_reads_in_order() {
+        let directory = temp_path("images");
+        std::fs::create_dir_all(&directory).unwrap();
+        for (name, width) in [("b.png", 3), ("a.png", 2)] {
+            let data = encode_rgba(&vec![0; width * 4], width as u32, 1, StillFormat::Png);
+            std::fs::write(directory.join(name), data.unwrap()).unwrap();
+        }
+        let mut source = Source::open(directory.to_str().unwrap()).unwrap();
+        let mut pixels = Vec::new();
+        let first = source.next_frame(&mut pixels).unwrap().unwrap();
+        assert_eq!((first.width, pixels.len()), (2, 8));
+        let second = source.next_frame(&mut pixels).unwrap().unwrap();
+        assert_eq!(second.width, 3);
+        assert!(source.next_frame(&mut pixels).unwrap().is_none());
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn empty_image_source_is_an_error() {
+        let directory = temp_path("empty");
+        std::fs::create_dir_all(&directory).unwrap();
+        let error = Source::open(directory.to_str().unwrap()).err().unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn y4m_sink_rejects_another_size() {
+        let path = temp_path("sink.y4m");
+        let mut sink = Sink::create(&path).unwrap();
+        sink.write(0, &[0; 32], 4, 2, 30).unwrap();
+        let error = sink.write(1, &[0; 16], 2, 2, 30).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        sink.finish().unwrap();
+
+        let mut reader = Y4mReader::new(BufReader::new(File::open(&path).unwrap())).unwrap();
+        assert_eq!(
+            (reader.width(), reader.height(), reader.fps()),
+            (4, 2, (30, 1))
+        );
+        assert!(reader.read_frame(&mut Vec::new()).unwrap());
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn png_sink_names_frames_by_index() {
+        let directory = temp_path("sink");
+        let mut sink = Sink::create(&directory).unwrap();
+        sink.write(7, &[0; 16], 2, 2, 30).unwrap();
+        sink.finish().unwrap();
+        assert!(directory.join("000007.png").exists());
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}